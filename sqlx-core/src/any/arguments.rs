@@ -1,5 +1,5 @@
 use crate::any::value::AnyValueKind;
-use crate::any::Any;
+use crate::any::{Any, AnyTypeInfoKind};
 use crate::arguments::Arguments;
 use crate::encode::Encode;
 use crate::types::Type;
@@ -39,7 +39,14 @@ impl<'q> AnyArguments<'q> {
     pub fn convert_to<'a, A: Arguments<'a>>(&'a self) -> A
     where
         'q: 'a,
+        Option<bool>: Type<A::Database> + Encode<'a, A::Database>,
+        Option<i16>: Type<A::Database> + Encode<'a, A::Database>,
         Option<i32>: Type<A::Database> + Encode<'a, A::Database>,
+        Option<i64>: Type<A::Database> + Encode<'a, A::Database>,
+        Option<f32>: Type<A::Database> + Encode<'a, A::Database>,
+        Option<f64>: Type<A::Database> + Encode<'a, A::Database>,
+        Option<&'a str>: Type<A::Database> + Encode<'a, A::Database>,
+        Option<&'a [u8]>: Type<A::Database> + Encode<'a, A::Database>,
         bool: Type<A::Database> + Encode<'a, A::Database>,
         i16: Type<A::Database> + Encode<'a, A::Database>,
         i32: Type<A::Database> + Encode<'a, A::Database>,
@@ -53,7 +60,18 @@ impl<'q> AnyArguments<'q> {
 
         for arg in &self.values.0 {
             match arg {
-                AnyValueKind::Null => out.add(Option::<i32>::None),
+                AnyValueKind::Null(AnyTypeInfoKind::Bool) => out.add(Option::<bool>::None),
+                AnyValueKind::Null(AnyTypeInfoKind::SmallInt) => out.add(Option::<i16>::None),
+                AnyValueKind::Null(AnyTypeInfoKind::Integer) => out.add(Option::<i32>::None),
+                AnyValueKind::Null(AnyTypeInfoKind::BigInt) => out.add(Option::<i64>::None),
+                AnyValueKind::Null(AnyTypeInfoKind::Real) => out.add(Option::<f32>::None),
+                AnyValueKind::Null(AnyTypeInfoKind::Double) => out.add(Option::<f64>::None),
+                AnyValueKind::Null(AnyTypeInfoKind::Text) => out.add(Option::<&str>::None),
+                AnyValueKind::Null(AnyTypeInfoKind::Blob) => out.add(Option::<&[u8]>::None),
+                // the type doesn't matter for most databases; Postgres binds these as `unknown`
+                AnyValueKind::Null(AnyTypeInfoKind::Null | AnyTypeInfoKind::Other(_)) => {
+                    out.add(Option::<i32>::None)
+                }
                 AnyValueKind::Bool(b) => out.add(b),
                 AnyValueKind::SmallInt(i) => out.add(i),
                 AnyValueKind::Integer(i) => out.add(i),
@@ -1,5 +1,6 @@
 use crate::any::{Any, AnyArguments, AnyQueryResult, AnyRow, AnyStatement, AnyTypeInfo};
 use crate::describe::Describe;
+use crate::transaction::{TransactionOptions, TransactionStatus};
 use either::Either;
 use futures_core::future::BoxFuture;
 use futures_core::stream::BoxStream;
@@ -30,12 +31,37 @@ pub trait AnyConnectionBackend: std::any::Any + Debug + Send + 'static {
     /// Returns a [`Transaction`] for controlling and tracking the new transaction.
     fn begin(&mut self) -> BoxFuture<'_, crate::Result<()>>;
 
+    /// Begin a new transaction with the given options.
+    ///
+    /// The default implementation only supports the default options, which it forwards to
+    /// [`begin()`][Self::begin()].
+    fn begin_with(&mut self, options: TransactionOptions) -> BoxFuture<'_, crate::Result<()>> {
+        if !options.is_default() {
+            return Box::pin(async { Err(crate::transaction::unsupported_transaction_options()) });
+        }
+
+        self.begin()
+    }
+
     fn commit(&mut self) -> BoxFuture<'_, crate::Result<()>>;
 
     fn rollback(&mut self) -> BoxFuture<'_, crate::Result<()>>;
 
     fn start_rollback(&mut self);
 
+    /// The status of the active transaction, as far as the driver can tell.
+    ///
+    /// The default implementation can't tell, and always returns [`TransactionStatus::Active`].
+    fn transaction_status(&self) -> TransactionStatus {
+        TransactionStatus::Active
+    }
+
+    /// The number of statements run on this connection that may have written to the database,
+    /// or `None` if the driver doesn't keep count.
+    fn write_count(&self) -> Option<u64> {
+        None
+    }
+
     /// The number of statements currently cached in the connection.
     fn cached_statements_size(&self) -> usize {
         0
@@ -48,6 +48,10 @@ impl Migrate for AnyConnection {
         Box::pin(async { self.get_migrate()?.ensure_migrations_table().await })
     }
 
+    fn migrations_table_exists(&mut self) -> BoxFuture<'_, Result<bool, MigrateError>> {
+        Box::pin(async { self.get_migrate()?.migrations_table_exists().await })
+    }
+
     fn dirty_version(&mut self) -> BoxFuture<'_, Result<Option<i64>, MigrateError>> {
         Box::pin(async { self.get_migrate()?.dirty_version().await })
     }
@@ -66,6 +70,18 @@ impl Migrate for AnyConnection {
         Box::pin(async { self.get_migrate()?.unlock().await })
     }
 
+    fn lock_with(
+        &mut self,
+        key: Option<i64>,
+        timeout: Option<Duration>,
+    ) -> BoxFuture<'_, Result<(), MigrateError>> {
+        Box::pin(async move { self.get_migrate()?.lock_with(key, timeout).await })
+    }
+
+    fn unlock_with(&mut self, key: Option<i64>) -> BoxFuture<'_, Result<(), MigrateError>> {
+        Box::pin(async move { self.get_migrate()?.unlock_with(key).await })
+    }
+
     fn apply<'e: 'm, 'm>(
         &'e mut self,
         migration: &'m Migration,
@@ -79,4 +95,11 @@ impl Migrate for AnyConnection {
     ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
         Box::pin(async { self.get_migrate()?.revert(migration).await })
     }
+
+    fn update_checksum<'e: 'm, 'm>(
+        &'e mut self,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<(), MigrateError>> {
+        Box::pin(async { self.get_migrate()?.update_checksum(migration).await })
+    }
 }
@@ -31,6 +31,7 @@ pub use connection::AnyConnection;
 // Used internally in `sqlx-macros`
 
 use crate::encode::Encode;
+use crate::types::Type;
 pub use connection::AnyConnectionBackend;
 pub use database::Any;
 pub use kind::AnyKind;
@@ -65,13 +66,14 @@ impl_column_index_for_statement!(AnyStatement);
 // required because some databases have a different handling of NULL
 impl<'q, T> Encode<'q, Any> for Option<T>
 where
-    T: Encode<'q, Any> + 'q,
+    T: Encode<'q, Any> + Type<Any> + 'q,
 {
     fn encode_by_ref(&self, buf: &mut AnyArgumentBuffer<'q>) -> crate::encode::IsNull {
         if let Some(value) = self {
             value.encode_by_ref(buf)
         } else {
-            buf.0.push(AnyValueKind::Null);
+            // keep the type so drivers can bind a typed `NULL`
+            buf.0.push(AnyValueKind::Null(T::type_info().kind));
             crate::encode::IsNull::Yes
         }
     }
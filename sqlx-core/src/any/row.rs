@@ -117,8 +117,8 @@ impl AnyRow {
                 })?;
 
             let value_kind = match type_info.kind {
-                _ if value.is_null() => AnyValueKind::Null,
-                AnyTypeInfoKind::Null => AnyValueKind::Null,
+                kind if value.is_null() => AnyValueKind::Null(kind),
+                AnyTypeInfoKind::Null => AnyValueKind::Null(AnyTypeInfoKind::Null),
                 AnyTypeInfoKind::Bool => AnyValueKind::Bool(decode(value)?),
                 AnyTypeInfoKind::SmallInt => AnyValueKind::SmallInt(decode(value)?),
                 AnyTypeInfoKind::Integer => AnyValueKind::Integer(decode(value)?),
@@ -127,6 +127,13 @@ impl AnyRow {
                 AnyTypeInfoKind::Double => AnyValueKind::Double(decode(value)?),
                 AnyTypeInfoKind::Blob => AnyValueKind::Blob(decode::<_, Vec<u8>>(value)?.into()),
                 AnyTypeInfoKind::Text => AnyValueKind::Text(decode::<_, String>(value)?.into()),
+                AnyTypeInfoKind::Other(name) => {
+                    return Err(Error::ColumnDecode {
+                        index: col.ordinal().to_string(),
+                        source: format!("Any driver does not support decoding the type {name}")
+                            .into(),
+                    })
+                }
             };
 
             row_out.columns.push(any_col);
@@ -2,7 +2,7 @@ use futures_util::future::BoxFuture;
 
 use crate::any::{Any, AnyConnection};
 use crate::error::Error;
-use crate::transaction::TransactionManager;
+use crate::transaction::{TransactionManager, TransactionOptions, TransactionStatus};
 
 pub struct AnyTransactionManager;
 
@@ -13,6 +13,13 @@ impl TransactionManager for AnyTransactionManager {
         conn.backend.begin()
     }
 
+    fn begin_with(
+        conn: &mut AnyConnection,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<(), Error>> {
+        conn.backend.begin_with(options)
+    }
+
     fn commit(conn: &mut AnyConnection) -> BoxFuture<'_, Result<(), Error>> {
         conn.backend.commit()
     }
@@ -24,4 +31,12 @@ impl TransactionManager for AnyTransactionManager {
     fn start_rollback(conn: &mut AnyConnection) {
         conn.backend.start_rollback()
     }
+
+    fn transaction_status(conn: &AnyConnection) -> TransactionStatus {
+        conn.backend.transaction_status()
+    }
+
+    fn write_count(conn: &AnyConnection) -> Option<u64> {
+        conn.backend.write_count()
+    }
 }
@@ -12,11 +12,38 @@ pub struct AnyTypeInfo {
 
 impl AnyTypeInfo {
     pub fn kind(&self) -> AnyTypeInfoKind {
-        self.kind
+        self.kind.clone()
+    }
+
+    /// Like [`kind()`][Self::kind], without cloning the name of an
+    /// [`Other`][AnyTypeInfoKind::Other] type.
+    pub fn kind_ref(&self) -> &AnyTypeInfoKind {
+        &self.kind
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The portable kind of a type, mapped from the type info of the driver in use.
+///
+/// | Kind | PostgreSQL | MySQL | SQLite |
+/// |---|---|---|---|
+/// | `Null` | `VOID` | `NULL` | `NULL` |
+/// | `Bool` | `BOOLEAN` | | `BOOLEAN` |
+/// | `SmallInt` | `SMALLINT` | `TINYINT`[^signed], `SMALLINT` | |
+/// | `Integer` | `INTEGER` | `MEDIUMINT`[^signed], `INT` | `INT4` |
+/// | `BigInt` | `BIGINT` | `BIGINT` | `INTEGER`, `INT8`, `BIGINT` |
+/// | `Real` | `REAL` | `FLOAT` | |
+/// | `Double` | `DOUBLE PRECISION` | `DOUBLE` | `REAL` |
+/// | `Text` | `TEXT`, `VARCHAR`, `CHAR(N)`, `NAME`, `CITEXT` | `CHAR`, `VARCHAR` | `TEXT` |
+/// | `Blob` | `BYTEA` | `BLOB` | `BLOB` |
+///
+/// Any other type is reported as [`Other`][Self::Other] with the name the driver uses for it.
+/// Columns of such types can be described, but not fetched into an [`AnyRow`][super::AnyRow].
+///
+/// MySQL has no distinct boolean type; `BOOLEAN` columns are `TINYINT(1)` and so are
+/// reported as `SmallInt`.
+///
+/// [^signed]: `UNSIGNED` columns of these types are reported as `Other`.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AnyTypeInfoKind {
     Null,
     Bool,
@@ -27,6 +54,7 @@ pub enum AnyTypeInfoKind {
     Double,
     Text,
     Blob,
+    Other(String),
 }
 
 impl TypeInfo for AnyTypeInfo {
@@ -37,7 +65,7 @@ impl TypeInfo for AnyTypeInfo {
     fn name(&self) -> &str {
         use AnyTypeInfoKind::*;
 
-        match self.kind {
+        match &self.kind {
             Bool => "BOOLEAN",
             SmallInt => "SMALLINT",
             Integer => "INTEGER",
@@ -47,6 +75,7 @@ impl TypeInfo for AnyTypeInfo {
             Text => "TEXT",
             Blob => "BLOB",
             Null => "NULL",
+            Other(name) => name,
         }
     }
 }
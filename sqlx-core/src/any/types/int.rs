@@ -13,7 +13,7 @@ impl Type<Any> for i16 {
     }
 
     fn compatible(ty: &AnyTypeInfo) -> bool {
-        ty.kind().is_integer()
+        ty.kind_ref().is_integer()
     }
 }
 
@@ -38,7 +38,7 @@ impl Type<Any> for i32 {
     }
 
     fn compatible(ty: &AnyTypeInfo) -> bool {
-        ty.kind().is_integer()
+        ty.kind_ref().is_integer()
     }
 }
 
@@ -63,7 +63,7 @@ impl Type<Any> for i64 {
     }
 
     fn compatible(ty: &AnyTypeInfo) -> bool {
-        ty.kind().is_integer()
+        ty.kind_ref().is_integer()
     }
 }
 
@@ -16,6 +16,10 @@
 //!
 //! In addition, `Option<T>` is supported where `T` implements `Type`. An `Option<T>` represents
 //! a potentially `NULL` value from SQL.
+//!
+//! Binding `None` sends a `NULL` of the SQL type of `T`, so databases that need to know the
+//! type of a parameter can still infer it. See [`AnyValue::null_of()`][crate::any::AnyValue::null_of]
+//! for when the type is only known at runtime.
 
 mod blob;
 mod bool;
@@ -1,7 +1,8 @@
 use std::borrow::Cow;
 
-use crate::any::{Any, AnyTypeInfo, AnyTypeInfoKind};
+use crate::any::{Any, AnyArgumentBuffer, AnyTypeInfo, AnyTypeInfoKind};
 use crate::database::Database;
+use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
 use crate::types::Type;
 use crate::value::{Value, ValueRef};
@@ -9,7 +10,11 @@ use crate::value::{Value, ValueRef};
 #[derive(Clone, Debug)]
 #[non_exhaustive]
 pub enum AnyValueKind<'a> {
-    Null,
+    /// A `NULL` of the given kind, or of kind [`Null`][AnyTypeInfoKind::Null] if it's unknown.
+    ///
+    /// This used to be a unit variant, so matches on `AnyValueKind::Null` have to become
+    /// `AnyValueKind::Null(_)`.
+    Null(AnyTypeInfoKind),
     Bool(bool),
     SmallInt(i16),
     Integer(i32),
@@ -24,7 +29,7 @@ impl AnyValueKind<'_> {
     fn type_info(&self) -> AnyTypeInfo {
         AnyTypeInfo {
             kind: match self {
-                AnyValueKind::Null => AnyTypeInfoKind::Null,
+                AnyValueKind::Null(_) => AnyTypeInfoKind::Null,
                 AnyValueKind::Bool(_) => AnyTypeInfoKind::Bool,
                 AnyValueKind::SmallInt(_) => AnyTypeInfoKind::SmallInt,
                 AnyValueKind::Integer(_) => AnyTypeInfoKind::Integer,
@@ -63,6 +68,19 @@ pub struct AnyValue {
     pub kind: AnyValueKind<'static>,
 }
 
+impl AnyValue {
+    /// A `NULL` of the given kind, for when the database cannot infer the type of a parameter,
+    /// e.g. `INSERT INTO foo (bar) VALUES ($1)` on Postgres when the type of `bar` is ambiguous.
+    ///
+    /// Binding `Option::<T>::None` already sends a `NULL` of the kind of `T`, so this is only
+    /// needed when the kind is only known at runtime.
+    pub fn null_of(kind: AnyTypeInfoKind) -> Self {
+        AnyValue {
+            kind: AnyValueKind::Null(kind),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct AnyValueRef<'a> {
     pub(crate) kind: AnyValueKind<'a>,
@@ -74,7 +92,7 @@ impl Value for AnyValue {
     fn as_ref(&self) -> <Self::Database as Database>::ValueRef<'_> {
         AnyValueRef {
             kind: match &self.kind {
-                AnyValueKind::Null => AnyValueKind::Null,
+                AnyValueKind::Null(k) => AnyValueKind::Null(k.clone()),
                 AnyValueKind::Bool(b) => AnyValueKind::Bool(*b),
                 AnyValueKind::SmallInt(i) => AnyValueKind::SmallInt(*i),
                 AnyValueKind::Integer(i) => AnyValueKind::Integer(*i),
@@ -102,7 +120,7 @@ impl<'a> ValueRef<'a> for AnyValueRef<'a> {
     fn to_owned(&self) -> <Self::Database as Database>::Value {
         AnyValue {
             kind: match &self.kind {
-                AnyValueKind::Null => AnyValueKind::Null,
+                AnyValueKind::Null(k) => AnyValueKind::Null(k.clone()),
                 AnyValueKind::Bool(b) => AnyValueKind::Bool(*b),
                 AnyValueKind::SmallInt(i) => AnyValueKind::SmallInt(*i),
                 AnyValueKind::Integer(i) => AnyValueKind::Integer(*i),
@@ -123,3 +141,33 @@ impl<'a> ValueRef<'a> for AnyValueRef<'a> {
         false
     }
 }
+
+impl Type<Any> for AnyValue {
+    fn type_info() -> AnyTypeInfo {
+        AnyTypeInfo {
+            kind: AnyTypeInfoKind::Null,
+        }
+    }
+
+    fn compatible(_ty: &AnyTypeInfo) -> bool {
+        true
+    }
+}
+
+impl<'q> Encode<'q, Any> for AnyValue {
+    fn encode_by_ref(&self, buf: &mut AnyArgumentBuffer<'q>) -> IsNull {
+        buf.0.push(self.kind.clone());
+
+        match self.kind {
+            AnyValueKind::Null(_) => IsNull::Yes,
+            _ => IsNull::No,
+        }
+    }
+
+    fn produces(&self) -> Option<AnyTypeInfo> {
+        Some(match &self.kind {
+            AnyValueKind::Null(kind) => AnyTypeInfo { kind: kind.clone() },
+            kind => kind.type_info(),
+        })
+    }
+}
@@ -1,11 +1,17 @@
 use crate::database::{Database, HasStatementCache};
 use crate::error::Error;
+use crate::logger::QueryExecuted;
 
-use crate::transaction::Transaction;
+use crate::transaction::{Transaction, TransactionOptions};
 use futures_core::future::BoxFuture;
 use log::LevelFilter;
-use std::fmt::Debug;
+use std::borrow::Cow;
+use std::fmt::{self, Debug, Formatter};
+use std::future::Future;
+use std::io;
+use std::net::SocketAddr;
 use std::str::FromStr;
+use std::sync::Arc;
 use std::time::Duration;
 use url::Url;
 
@@ -42,6 +48,25 @@ pub trait Connection: Send {
     /// Checks if a connection to the database is still valid.
     fn ping(&mut self) -> BoxFuture<'_, Result<(), Error>>;
 
+    /// Checks if a connection to the database is still valid, failing with
+    /// [`io::ErrorKind::TimedOut`] if the database doesn't respond within `timeout`.
+    ///
+    /// This is suitable for application-level liveness or readiness probes.
+    ///
+    /// If the ping times out, the connection should be closed.
+    fn ping_timeout(&mut self, timeout: Duration) -> BoxFuture<'_, Result<(), Error>> {
+        Box::pin(async move {
+            crate::rt::timeout(timeout, self.ping())
+                .await
+                .map_err(|_| {
+                    Error::Io(io::Error::new(
+                        io::ErrorKind::TimedOut,
+                        "timed out while pinging the database",
+                    ))
+                })?
+        })
+    }
+
     /// Begin a new transaction or establish a savepoint within the active transaction.
     ///
     /// Returns a [`Transaction`] for controlling and tracking the new transaction.
@@ -49,6 +74,21 @@ pub trait Connection: Send {
     where
         Self: Sized;
 
+    /// Begin a new transaction with the given isolation level and access mode.
+    ///
+    /// Returns an error, before anything is sent to the database, if the database doesn't
+    /// support the options or if they would apply to a savepoint inside another transaction.
+    /// See [`TransactionOptions`] for what each database supports.
+    fn begin_with(
+        &mut self,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<Transaction<'_, Self::Database>, Error>>
+    where
+        Self: Sized,
+    {
+        Transaction::begin_with(self, options)
+    }
+
     /// Execute the function inside a transaction.
     ///
     /// If the function returns an error, the transaction will be rolled back. If it does not
@@ -155,12 +195,25 @@ pub trait Connection: Send {
     }
 }
 
-#[derive(Clone, Debug)]
+/// A callback which may rewrite the formatted SQL of a statement before it is logged.
+pub type StatementRedactor = Arc<dyn Fn(&str) -> Cow<'_, str> + Send + Sync>;
+
+/// A callback which is invoked once for every executed statement.
+pub type QueryExecutedCallback = Arc<dyn Fn(&QueryExecuted<'_>) + Send + Sync>;
+
+#[derive(Clone)]
 #[non_exhaustive]
 pub struct LogSettings {
     pub statements_level: LevelFilter,
     pub slow_statements_level: LevelFilter,
     pub slow_statements_duration: Duration,
+    /// The level of traces of how statements were executed, i.e. SQLite query plans.
+    /// `None` logs them at `statements_level`.
+    pub statements_trace_level: Option<LevelFilter>,
+    pub summary_word_count: usize,
+    pub max_statement_length: Option<usize>,
+    pub redactor: Option<StatementRedactor>,
+    pub on_query_executed: Option<QueryExecutedCallback>,
 }
 
 impl Default for LogSettings {
@@ -169,11 +222,40 @@ impl Default for LogSettings {
             statements_level: LevelFilter::Debug,
             slow_statements_level: LevelFilter::Warn,
             slow_statements_duration: Duration::from_secs(1),
+            statements_trace_level: None,
+            summary_word_count: 4,
+            max_statement_length: None,
+            redactor: None,
+            on_query_executed: None,
         }
     }
 }
 
+impl Debug for LogSettings {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LogSettings")
+            .field("statements_level", &self.statements_level)
+            .field("slow_statements_level", &self.slow_statements_level)
+            .field("slow_statements_duration", &self.slow_statements_duration)
+            .field("statements_trace_level", &self.statements_trace_level)
+            .field("summary_word_count", &self.summary_word_count)
+            .field("max_statement_length", &self.max_statement_length)
+            .field("redactor", &self.redactor.as_ref().map(|_| "<function>"))
+            .field(
+                "on_query_executed",
+                &self.on_query_executed.as_ref().map(|_| "<function>"),
+            )
+            .finish()
+    }
+}
+
 impl LogSettings {
+    /// Start building a `LogSettings` from the defaults, e.g. to construct a
+    /// [`QueryLogger`][crate::logger::QueryLogger] directly.
+    pub fn builder() -> LogSettingsBuilder {
+        LogSettingsBuilder::default()
+    }
+
     pub fn log_statements(&mut self, level: LevelFilter) {
         self.statements_level = level;
     }
@@ -181,8 +263,114 @@ impl LogSettings {
         self.slow_statements_level = level;
         self.slow_statements_duration = duration;
     }
+    pub fn log_statements_trace(&mut self, level: LevelFilter) {
+        self.statements_trace_level = Some(level);
+    }
+    pub fn log_summary_word_count(&mut self, count: usize) {
+        self.summary_word_count = count;
+    }
+    pub fn log_max_statement_length(&mut self, len: usize) {
+        self.max_statement_length = Some(len);
+    }
+    pub fn redact_statements<F>(&mut self, redactor: F)
+    where
+        F: Fn(&str) -> Cow<'_, str> + Send + Sync + 'static,
+    {
+        self.redactor = Some(Arc::new(redactor));
+    }
+    pub fn on_query_executed<F>(&mut self, callback: F)
+    where
+        F: Fn(&QueryExecuted<'_>) + Send + Sync + 'static,
+    {
+        self.on_query_executed = Some(Arc::new(callback));
+    }
+
+    /// The level at which traces of statements are logged, see
+    /// [`statements_trace_level`][Self::statements_trace_level].
+    pub fn trace_level(&self) -> LevelFilter {
+        self.statements_trace_level.unwrap_or(self.statements_level)
+    }
+
+    /// Run the configured redactor, if any, over `sql`.
+    pub fn redact<'a>(&self, sql: &'a str) -> Cow<'a, str> {
+        match &self.redactor {
+            Some(redactor) => redactor(sql),
+            None => Cow::Borrowed(sql),
+        }
+    }
 }
 
+/// Builder for [`LogSettings`]. Returned by [`LogSettings::builder()`].
+#[derive(Clone, Debug, Default)]
+pub struct LogSettingsBuilder {
+    settings: LogSettings,
+}
+
+impl LogSettingsBuilder {
+    /// Set the level at which executed statements are logged.
+    pub fn statements_level(mut self, level: LevelFilter) -> Self {
+        self.settings.statements_level = level;
+        self
+    }
+
+    /// Set the level at which slow statements are logged.
+    pub fn slow_statements_level(mut self, level: LevelFilter) -> Self {
+        self.settings.slow_statements_level = level;
+        self
+    }
+
+    /// Set the duration above which a statement is considered slow.
+    pub fn slow_statements_duration(mut self, duration: Duration) -> Self {
+        self.settings.slow_statements_duration = duration;
+        self
+    }
+
+    /// Set the level at which traces of how statements were executed are logged, i.e. SQLite
+    /// query plans. Defaults to the level of executed statements.
+    pub fn statements_trace_level(mut self, level: LevelFilter) -> Self {
+        self.settings.statements_trace_level = Some(level);
+        self
+    }
+
+    /// Set the number of words used for the summary of each statement.
+    pub fn summary_word_count(mut self, count: usize) -> Self {
+        self.settings.summary_word_count = count;
+        self
+    }
+
+    /// Set the maximum length of logged statements.
+    pub fn max_statement_length(mut self, len: usize) -> Self {
+        self.settings.max_statement_length = Some(len);
+        self
+    }
+
+    /// Set a callback to redact the SQL of logged statements.
+    pub fn redactor<F>(mut self, redactor: F) -> Self
+    where
+        F: Fn(&str) -> Cow<'_, str> + Send + Sync + 'static,
+    {
+        self.settings.redact_statements(redactor);
+        self
+    }
+
+    /// Set a callback to invoke for every executed statement.
+    pub fn on_query_executed<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&QueryExecuted<'_>) + Send + Sync + 'static,
+    {
+        self.settings.on_query_executed(callback);
+        self
+    }
+
+    pub fn build(self) -> LogSettings {
+        self.settings
+    }
+}
+
+/// Options for connecting to a database.
+///
+/// Apart from the logging levels, the settings here have default implementations which leave the
+/// options unchanged, for drivers which don't support them.
 pub trait ConnectOptions: 'static + Send + Sync + FromStr<Err = Error> + Debug + Clone {
     type Connection: Connection<Options = Self> + ?Sized;
 
@@ -218,6 +406,21 @@ pub trait ConnectOptions: 'static + Send + Sync + FromStr<Err = Error> + Debug +
         unimplemented!()
     }
 
+    /// Get the same URL as [`to_url_lossy()`][Self::to_url_lossy], but with the password
+    /// replaced by `****` so that it may be safely logged or included in error messages.
+    ///
+    /// ### Panics
+    /// If [`to_url_lossy()`][Self::to_url_lossy] is not implemented for this driver.
+    fn to_url_redacted(&self) -> Url {
+        let mut url = self.to_url_lossy();
+
+        if url.password().is_some() {
+            let _ = url.set_password(Some("****"));
+        }
+
+        url
+    }
+
     /// Establish a new database connection with the options specified by `self`.
     fn connect(&self) -> BoxFuture<'_, Result<Self::Connection, Error>>
     where
@@ -230,9 +433,128 @@ pub trait ConnectOptions: 'static + Send + Sync + FromStr<Err = Error> + Debug +
     /// at the specified `level`.
     fn log_slow_statements(self, level: LevelFilter, duration: Duration) -> Self;
 
+    /// Log traces of how statements were executed, i.e. SQLite query plans under the
+    /// `sqlx::explain` target, at the specified `level`.
+    ///
+    /// Defaults to the level of [`log_statements()`][Self::log_statements].
+    fn log_statements_trace(self, _level: LevelFilter) -> Self {
+        self
+    }
+
+    /// Set the number of words from the start of each statement to use as the summary
+    /// in log output. Defaults to 4.
+    fn log_summary_word_count(self, _count: usize) -> Self {
+        self
+    }
+
+    /// Truncate the SQL of logged statements to at most `len` characters.
+    ///
+    /// Truncated statements are marked as such along with their total length in bytes.
+    /// The summary is still taken from the full statement. By default, statements are not truncated.
+    fn log_max_statement_length(self, _len: usize) -> Self {
+        self
+    }
+
+    /// Set a callback which is run over the formatted SQL of each statement before it is logged
+    /// or passed to [`on_query_executed`][Self::on_query_executed], e.g. to scrub sensitive
+    /// literals.
+    ///
+    /// Returning [`Cow::Borrowed`] leaves the statement unchanged without allocating.
+    fn redact_statements<F>(self, _redactor: F) -> Self
+    where
+        F: Fn(&str) -> Cow<'_, str> + Send + Sync + 'static,
+    {
+        self
+    }
+
+    /// Set a callback which is invoked exactly once for every executed statement with
+    /// information about its execution, e.g. to record metrics.
+    ///
+    /// The callback is invoked regardless of the configured log levels.
+    fn on_query_executed<F>(self, _callback: F) -> Self
+    where
+        F: Fn(&QueryExecuted<'_>) + Send + Sync + 'static,
+    {
+        self
+    }
+
+    /// Set the maximum amount of time to spend establishing a new connection.
+    ///
+    /// This covers the whole sequence of opening the socket, the handshake, authentication and
+    /// any initialization statements. If it elapses, connecting fails with an [`Error::Io`] of
+    /// kind [`TimedOut`][std::io::ErrorKind::TimedOut].
+    ///
+    /// This applies to [`Connection::connect_with()`] as well as connections opened by a pool,
+    /// where it is bounded further by [`PoolOptions::acquire_timeout`][crate::pool::PoolOptions::acquire_timeout].
+    /// By default, there is no timeout.
+    fn connect_timeout(self, _timeout: Duration) -> Self {
+        self
+    }
+
+    /// Enable TCP keepalive on the connection's socket, sending the first probe once the
+    /// connection has been idle for `time`.
+    ///
+    /// This lets the OS notice a peer which has silently gone away, e.g. behind a NAT or load
+    /// balancer, instead of the next query failing. Has no effect on non-TCP connections.
+    fn tcp_keepalive(self, _time: Duration) -> Self {
+        self
+    }
+
+    /// Set `TCP_USER_TIMEOUT` on the connection's socket: the maximum time transmitted data may
+    /// remain unacknowledged before the OS closes the connection.
+    ///
+    /// Only supported on Linux, Android and Fuchsia; ignored elsewhere and for non-TCP connections.
+    fn tcp_user_timeout(self, _timeout: Duration) -> Self {
+        self
+    }
+
+    /// Resolve the database hostname with `resolver` instead of the system resolver, e.g. to look
+    /// up SRV records or ask a service-mesh sidecar.
+    ///
+    /// The addresses returned are tried in order. Any with a port of `0` are given the configured
+    /// port instead. Has no effect on non-TCP connections.
+    fn resolver<F>(self, _resolver: F) -> Self
+    where
+        F: Fn(&str) -> BoxFuture<'static, io::Result<Vec<SocketAddr>>> + Send + Sync + 'static,
+    {
+        self
+    }
+
+    /// Add a statement to execute on every new connection, right after it is established.
+    ///
+    /// Statements are executed in the order they were added, after any initialization the driver
+    /// performs itself. If one of them fails, so does the connection attempt.
+    ///
+    /// This is a portable alternative to driver-specific settings, e.g. `SET application_name`
+    /// or SQLite pragmas, and to [`PoolOptions::after_connect`][crate::pool::PoolOptions::after_connect]
+    /// for statements which don't need to be written in Rust.
+    fn after_connect_sql(self, _sql: impl Into<String>) -> Self {
+        self
+    }
+
     /// Entirely disables statement logging (both slow and regular).
     fn disable_statement_logging(self) -> Self {
         self.log_statements(LevelFilter::Off)
             .log_slow_statements(LevelFilter::Off, Duration::default())
     }
 }
+
+/// Await `connect`, failing if it takes longer than `timeout`.
+///
+/// Used by drivers to implement [`ConnectOptions::connect_timeout()`].
+#[doc(hidden)]
+pub async fn connect_with_timeout<C>(
+    timeout: Option<Duration>,
+    connect: impl Future<Output = Result<C, Error>>,
+) -> Result<C, Error> {
+    let Some(timeout) = timeout else {
+        return connect.await;
+    };
+
+    crate::rt::timeout(timeout, connect).await.map_err(|_| {
+        Error::Io(io::Error::new(
+            io::ErrorKind::TimedOut,
+            "timed out while connecting to the database",
+        ))
+    })?
+}
@@ -58,6 +58,7 @@ use std::fmt::Debug;
 use crate::arguments::Arguments;
 use crate::column::Column;
 use crate::connection::Connection;
+use crate::raw_sql::SqlDialect;
 use crate::row::Row;
 
 use crate::statement::Statement;
@@ -108,6 +109,10 @@ pub trait Database: 'static + Sized + Send + Debug {
 
     /// The schemes for database URLs that should match this driver.
     const URL_SCHEMES: &'static [&'static str];
+
+    /// The SQL syntax of this database, used to split scripts into statements.
+    #[doc(hidden)]
+    const SQL_DIALECT: SqlDialect = SqlDialect::Generic;
 }
 
 /// A [`Database`] that maintains a client-side cache of prepared statements.
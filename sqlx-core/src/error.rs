@@ -100,6 +100,39 @@ pub enum Error {
     #[error("attempted to acquire a connection on a closed pool")]
     PoolClosed,
 
+    /// [`Pool::drain`] was called before or while we were waiting in [`Pool::acquire`].
+    ///
+    /// [`Pool::acquire`]: crate::pool::Pool::acquire
+    /// [`Pool::drain`]: crate::pool::Pool::drain
+    #[error("attempted to acquire a connection on a draining pool")]
+    PoolDraining,
+
+    /// A [`Pool::acquire`] was rejected because too many tasks were already waiting for a
+    /// connection.
+    ///
+    /// See [`PoolOptions::max_acquire_queue`].
+    ///
+    /// [`Pool::acquire`]: crate::pool::Pool::acquire
+    /// [`PoolOptions::max_acquire_queue`]: crate::pool::PoolOptions::max_acquire_queue
+    #[error("too many tasks waiting for a connection from the pool")]
+    PoolQueueFull,
+
+    /// None of the hosts configured for failover accepted a connection.
+    ///
+    /// Contains the error returned for each `host:port`, in the order they were attempted.
+    #[error(
+        "failed to connect to any of the configured hosts: {}",
+        display_host_errors(.0)
+    )]
+    AllHostsFailed(Vec<(String, Error)>),
+
+    /// A name passed to [`Identifier::new`] was not a valid SQL identifier, or was not
+    /// one of those allowed.
+    ///
+    /// [`Identifier::new`]: crate::identifier::Identifier::new
+    #[error("invalid SQL identifier: {0:?}")]
+    InvalidIdentifier(String),
+
     /// A background worker has crashed.
     #[error("attempted to communicate with a crashed background worker")]
     WorkerCrashed,
@@ -111,6 +144,14 @@ pub enum Error {
 
 impl StdError for Box<dyn DatabaseError> {}
 
+fn display_host_errors(errors: &[(String, Error)]) -> String {
+    errors
+        .iter()
+        .map(|(host, e)| format!("{host}: {e}"))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
 impl Error {
     pub fn into_database_error(self) -> Option<Box<dyn DatabaseError + 'static>> {
         match self {
@@ -126,6 +167,36 @@ impl Error {
         }
     }
 
+    /// Returns the kind of the error, mapping database errors with [`DatabaseError::kind()`].
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::Database(err) => err.kind(),
+            Error::Io(_) | Error::AllHostsFailed(_) | Error::WorkerCrashed => ErrorKind::Connection,
+            // most TLS errors are misconfiguration (e.g. an invalid certificate) and will not
+            // go away on retry; only I/O errors during the handshake are connection errors
+            Error::Tls(err) if err.downcast_ref::<io::Error>().is_some() => ErrorKind::Connection,
+            Error::PoolTimedOut => ErrorKind::Timeout,
+            _ => ErrorKind::Other,
+        }
+    }
+
+    /// Returns whether the operation that failed may succeed if it is retried, i.e. the error
+    /// is of kind [`Connection`][ErrorKind::Connection], [`Timeout`][ErrorKind::Timeout],
+    /// [`Serialization`][ErrorKind::Serialization] or [`Deadlock`][ErrorKind::Deadlock].
+    ///
+    /// For serialization failures and deadlocks, the whole transaction has to be retried. A
+    /// connection lost during a statement may have already applied it, so only retry statements
+    /// that are safe to apply more than once.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self.kind(),
+            ErrorKind::Connection
+                | ErrorKind::Timeout
+                | ErrorKind::Serialization
+                | ErrorKind::Deadlock
+        )
+    }
+
     #[doc(hidden)]
     #[inline]
     pub fn protocol(err: impl Display) -> Self {
@@ -162,11 +233,19 @@ pub fn mismatched_types<DB: Database, T: Type<DB>>(ty: &DB::TypeInfo) -> BoxDynE
 
 /// The error kind.
 ///
-/// This enum is to be used to identify frequent errors that can be handled by the program.
-/// Although it currently only supports constraint violations, the type may grow in the future.
-#[derive(Debug, PartialEq, Eq)]
+/// This enum is to be used to identify frequent errors that can be handled by the program,
+/// independently of the database in use. The type may grow in the future.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum ErrorKind {
+    /// The connection to the database was lost or could not be established.
+    Connection,
+    /// Waiting for a lock, a statement or a connection from the pool timed out.
+    Timeout,
+    /// The transaction could not be serialized with concurrent transactions.
+    Serialization,
+    /// The transaction was rolled back to resolve a deadlock.
+    Deadlock,
     /// Unique/primary key constraint violation.
     UniqueViolation,
     /// Foreign key constraint violation.
@@ -207,7 +286,9 @@ pub trait DatabaseError: 'static + Send + Sync + StdError {
     /// If the error was caused by a conflict of a unique index, this will be the index name.
     ///
     /// ### Note
-    /// Currently only populated by the Postgres driver.
+    /// Postgres reports this in a separate field. MySQL and SQLite only include it in the
+    /// message, which is parsed for constraint violations. SQLite does not name the constraint
+    /// for most of them.
     fn constraint(&self) -> Option<&str> {
         None
     }
@@ -215,11 +296,22 @@ pub trait DatabaseError: 'static + Send + Sync + StdError {
     /// Returns the name of the table that was affected by the error, if applicable.
     ///
     /// ### Note
-    /// Currently only populated by the Postgres driver.
+    /// Postgres reports this in a separate field. MySQL and SQLite only include it in the
+    /// message, which is parsed for constraint violations.
     fn table(&self) -> Option<&str> {
         None
     }
 
+    /// Returns the name of the column that was affected by the error, if applicable.
+    /// If more than one column was affected, this is the first of them.
+    ///
+    /// ### Note
+    /// Postgres reports this in a separate field. MySQL and SQLite only include it in the
+    /// message, which is parsed for constraint violations.
+    fn column(&self) -> Option<&str> {
+        None
+    }
+
     /// Returns the kind of the error, if supported.
     ///
     /// ### Note
@@ -316,3 +408,19 @@ macro_rules! err_protocol {
         $crate::error::Error::Protocol(format!($fmt, $($arg)*))
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_does_not_retry_tls_configuration_errors() {
+        let err = Error::tls("server does not support TLS");
+        assert_eq!(err.kind(), ErrorKind::Other);
+        assert!(!err.is_retryable());
+
+        let err = Error::tls(io::Error::from(io::ErrorKind::ConnectionReset));
+        assert_eq!(err.kind(), ErrorKind::Connection);
+        assert!(err.is_retryable());
+    }
+}
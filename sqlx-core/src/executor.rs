@@ -7,6 +7,7 @@ use futures_core::future::BoxFuture;
 use futures_core::stream::BoxStream;
 use futures_util::{future, FutureExt, StreamExt, TryFutureExt, TryStreamExt};
 use std::fmt::Debug;
+use std::time::Duration;
 
 /// A type that contains or can provide a database
 /// connection to use for executing queries against the database.
@@ -206,6 +207,24 @@ pub trait Execute<'q, DB: Database>: Send + Sized {
 
     /// Returns `true` if the statement should be cached.
     fn persistent(&self) -> bool;
+
+    /// Returns the duration above which this statement is logged as slow, overriding the
+    /// connection's [`LogSettings::slow_statements_duration`][crate::connection::LogSettings].
+    ///
+    /// Returning `None` (the default) uses the connection's setting.
+    #[inline]
+    fn slow_statements_duration(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Returns `true` if the results should be sent in the database's text format instead of
+    /// its binary format.
+    ///
+    /// Only supported by Postgres; see [`Query::text_results`][crate::query::Query::text_results].
+    #[inline]
+    fn text_results(&self) -> bool {
+        false
+    }
 }
 
 // NOTE: `Execute` is explicitly not implemented for String and &String to make it slightly more
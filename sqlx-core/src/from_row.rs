@@ -111,6 +111,30 @@ use crate::{error::Error, row::Row};
 /// different placeholder values, if applicable.
 ///
 /// This is similar to how `#[serde(default)]` behaves.
+///
+/// #### `default_on_null`
+///
+/// `default` only applies when a column is missing from the result. If a column is present but
+/// `NULL`, use `default_on_null` to assign the default value instead of wrapping the field in
+/// an `Option`:
+///
+/// ```rust,ignore
+/// #[derive(sqlx::FromRow)]
+/// struct User {
+///     id: i32,
+///     #[sqlx(default_on_null)]
+///     nickname: String,
+///     // Missing *or* `NULL`.
+///     #[sqlx(default, default_on_null)]
+///     karma: i64,
+/// }
+/// ```
+///
+/// `default_on_null` may also be used at the struct level to apply it to every field.
+/// If the struct is also marked `#[sqlx(default)]`, the value is taken from the `Default`
+/// implementation for the struct.
+///
+/// This attribute is not compatible with `flatten` or `try_from`.
 /// ### `flatten`
 ///
 /// If you want to handle a field that implements [`FromRow`],
@@ -271,6 +295,29 @@ use crate::{error::Error, row::Row};
 ///     }
 /// }
 /// ```
+///
+/// ### Enums
+///
+/// Enums can also be derived, if they are marked with `#[sqlx(discriminant = "<column>")]`.
+/// The value of that column, decoded as a [`String`], selects which variant to build; the
+/// fields of that variant are then read like those of a struct:
+///
+/// ```rust,ignore
+/// #[derive(sqlx::FromRow)]
+/// #[sqlx(discriminant = "kind", rename_all = "snake_case")]
+/// enum Shape {
+///     Circle { radius: f64 },
+///     #[sqlx(rename = "rect")]
+///     Rectangle { width: f64, height: f64 },
+///     Point,
+/// }
+/// ```
+///
+/// Variants are matched by name, which can be changed with `rename` on the variant or
+/// `rename_all` on the enum. Tuple variants are not supported.
+///
+/// If the discriminant does not match any variant, an [`Error::ColumnDecode`] is returned
+/// naming the column and the unexpected value.
 pub trait FromRow<'r, R: Row>: Sized {
     fn from_row(row: &'r R) -> Result<Self, Error>;
 }
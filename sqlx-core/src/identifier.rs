@@ -0,0 +1,118 @@
+//! Validated SQL identifiers for use in dynamically built queries.
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::error::Error;
+
+/// A SQL identifier, such as a table or column name, chosen at runtime.
+///
+/// Identifiers can't be passed as bind parameters, so the only way to use one chosen at runtime
+/// is to write it into the SQL itself. `Identifier` makes that safe by only accepting names
+/// matching `[A-Za-z_][A-Za-z0-9_]*`, which can't contain quotes, whitespace or other syntax.
+/// It can then be interpolated with [`format!()`] or [`QueryBuilder::push()`][crate::query_builder::QueryBuilder::push].
+///
+/// The query macros can't check such queries at compile time, as their text is not known until
+/// runtime; use [`query()`][crate::query::query] and friends instead.
+///
+/// ```rust
+/// # use sqlx_core::identifier::Identifier;
+/// # fn example(partition: &str) -> sqlx_core::Result<()> {
+/// let table = Identifier::new(format!("events_{partition}"))?;
+///
+/// let sql = format!("SELECT * FROM {table} WHERE user_id = $1");
+/// // sqlx::query(&sql).bind(user_id).fetch_all(&pool).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Where the set of valid names is known up front, [`Identifier::allowed()`] additionally checks
+/// the name against a whitelist.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Identifier(String);
+
+impl Identifier {
+    /// Validate `name` as an identifier.
+    ///
+    /// Returns [`Error::InvalidIdentifier`] if it does not match `[A-Za-z_][A-Za-z0-9_]*`.
+    pub fn new(name: impl Into<String>) -> Result<Self, Error> {
+        let name = name.into();
+
+        let mut chars = name.chars();
+
+        let valid = chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+        if !valid {
+            return Err(Error::InvalidIdentifier(name));
+        }
+
+        Ok(Self(name))
+    }
+
+    /// Validate `name` as an identifier, and check that it is one of `allowed`.
+    ///
+    /// Returns [`Error::InvalidIdentifier`] if either check fails.
+    pub fn allowed(name: impl Into<String>, allowed: &[&str]) -> Result<Self, Error> {
+        let ident = Self::new(name)?;
+
+        if !allowed.contains(&ident.as_str()) {
+            return Err(Error::InvalidIdentifier(ident.0));
+        }
+
+        Ok(ident)
+    }
+
+    /// Get the identifier as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Identifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for Identifier {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_accepts_valid_identifiers() {
+        for name in ["users", "_tmp", "events_2024_01", "A1"] {
+            assert_eq!(Identifier::new(name).unwrap().as_str(), name);
+        }
+    }
+
+    #[test]
+    fn it_rejects_invalid_identifiers() {
+        for name in [
+            "",
+            "1users",
+            "users; DROP TABLE users",
+            "\"users\"",
+            "a b",
+            "tablé",
+        ] {
+            assert!(matches!(
+                Identifier::new(name),
+                Err(Error::InvalidIdentifier(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn it_checks_the_whitelist() {
+        assert!(Identifier::allowed("events_a", &["events_a", "events_b"]).is_ok());
+        assert!(Identifier::allowed("events_c", &["events_a", "events_b"]).is_err());
+    }
+}
@@ -68,6 +68,7 @@ pub mod describe;
 pub mod executor;
 pub mod from_row;
 pub mod fs;
+pub mod identifier;
 pub mod io;
 pub mod logger;
 pub mod net;
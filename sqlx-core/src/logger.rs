@@ -1,5 +1,6 @@
 use crate::connection::LogSettings;
-use std::time::Instant;
+use std::borrow::Cow;
+use std::time::{Duration, Instant};
 
 // Yes these look silly. `tracing` doesn't currently support dynamic levels
 // https://github.com/tokio-rs/tracing/issues/372
@@ -62,10 +63,34 @@ pub(crate) fn private_level_filter_to_trace_level(
 
 pub use sqlformat;
 
+/// Information about a statement which finished executing, passed to the callback registered
+/// with [`ConnectOptions::on_query_executed`][crate::connection::ConnectOptions::on_query_executed].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct QueryExecuted<'a> {
+    /// The first few words of the statement, as used in log output.
+    pub summary: String,
+    /// The full SQL of the statement.
+    ///
+    /// If a redactor is set, this is the formatted statement after redaction.
+    pub sql: &'a str,
+    pub elapsed: Duration,
+    pub rows_affected: u64,
+    pub rows_returned: u64,
+    /// `true` if `elapsed` exceeded the slow statement threshold.
+    pub was_slow: bool,
+    /// Whether the statement was found in the prepared statement cache, or `None` if the
+    /// statement was not persistent or the driver has no cache.
+    pub cache_hit: Option<bool>,
+}
+
 pub struct QueryLogger<'q> {
     sql: &'q str,
     rows_returned: u64,
     rows_affected: u64,
+    cache_hit: Option<bool>,
+    round_trips: u64,
+    param_count: usize,
     start: Instant,
     settings: LogSettings,
 }
@@ -76,6 +101,9 @@ impl<'q> QueryLogger<'q> {
             sql,
             rows_returned: 0,
             rows_affected: 0,
+            cache_hit: None,
+            round_trips: 0,
+            param_count: 0,
             start: Instant::now(),
             settings,
         }
@@ -89,11 +117,52 @@ impl<'q> QueryLogger<'q> {
         self.rows_affected += n;
     }
 
+    /// Use `duration` as the slow statement threshold for this query, if set,
+    /// in place of [`LogSettings::slow_statements_duration`].
+    pub fn override_slow_statements_duration(&mut self, duration: Option<Duration>) {
+        if let Some(duration) = duration {
+            self.settings.slow_statements_duration = duration;
+        }
+    }
+
+    /// Record whether the statement was found in the driver's prepared statement cache.
+    ///
+    /// Drivers which do not cache statements should not call this, so the `cache_hit` field
+    /// is omitted from the log event entirely.
+    pub fn set_cache_hit(&mut self, cache_hit: bool) {
+        self.cache_hit = Some(cache_hit);
+    }
+
+    /// Record a network round-trip to the database server made while executing the statement.
+    pub fn increment_round_trips(&mut self) {
+        self.round_trips += 1;
+    }
+
+    /// Record the number of parameters bound to the statement.
+    pub fn set_param_count(&mut self, count: usize) {
+        self.param_count = count;
+    }
+
     pub fn finish(&self) {
         let elapsed = self.start.elapsed();
 
         let was_slow = elapsed >= self.settings.slow_statements_duration;
 
+        if let Some(callback) = &self.settings.on_query_executed {
+            let redacted = redact_formatted(self.sql, &self.settings);
+            let sql = redacted.as_deref().unwrap_or(self.sql);
+
+            callback(&QueryExecuted {
+                summary: parse_query_summary(sql, self.settings.summary_word_count),
+                sql,
+                elapsed,
+                rows_affected: self.rows_affected,
+                rows_returned: self.rows_returned,
+                was_slow,
+                cache_hit: self.cache_hit,
+            });
+        }
+
         let lvl = if was_slow {
             self.settings.slow_statements_level
         } else {
@@ -106,21 +175,7 @@ impl<'q> QueryLogger<'q> {
             let log_is_enabled = log::log_enabled!(target: "sqlx::query", log_level)
                 || private_tracing_dynamic_enabled!(target: "sqlx::query", tracing_level);
             if log_is_enabled {
-                let mut summary = parse_query_summary(&self.sql);
-
-                let sql = if summary != self.sql {
-                    summary.push_str(" …");
-                    format!(
-                        "\n\n{}\n",
-                        sqlformat::format(
-                            &self.sql,
-                            &sqlformat::QueryParams::None,
-                            sqlformat::FormatOptions::default()
-                        )
-                    )
-                } else {
-                    String::new()
-                };
+                let (summary, sql) = summarize_statement(self.sql, &self.settings);
 
                 if was_slow {
                     private_tracing_dynamic_event!(
@@ -130,6 +185,9 @@ impl<'q> QueryLogger<'q> {
                         db.statement = sql,
                         rows_affected = self.rows_affected,
                         rows_returned = self.rows_returned,
+                        cache_hit = self.cache_hit,
+                        round_trips = self.round_trips,
+                        params = self.param_count,
                         // Human-friendly - includes units (usually ms). Also kept for backward compatibility
                         ?elapsed,
                         // Search friendly - numeric
@@ -148,6 +206,9 @@ impl<'q> QueryLogger<'q> {
                         db.statement = sql,
                         rows_affected = self.rows_affected,
                         rows_returned = self.rows_returned,
+                        cache_hit = self.cache_hit,
+                        round_trips = self.round_trips,
+                        params = self.param_count,
                         // Human-friendly - includes units (usually ms). Also kept for backward compatibility
                         ?elapsed,
                         // Search friendly - numeric
@@ -165,10 +226,282 @@ impl<'q> Drop for QueryLogger<'q> {
     }
 }
 
-pub fn parse_query_summary(sql: &str) -> String {
-    // For now, just take the first 4 words
+pub fn parse_query_summary(sql: &str, word_count: usize) -> String {
+    // Skip any leading comments so the summary starts at the statement keyword.
+    // If the query is nothing but comments, fall back to the raw text.
+    let stripped = strip_leading_comments(sql);
+    let sql = if stripped.is_empty() { sql } else { stripped };
+
     sql.split_whitespace()
-        .take(4)
+        .take(word_count)
         .collect::<Vec<&str>>()
         .join(" ")
 }
+
+/// Returns the summary of `sql` and, if the summary does not already include all of it, the
+/// formatted statement to log alongside it.
+///
+/// The redactor runs over the whole formatted statement first, so that neither the summary nor
+/// a truncated statement can contain anything it would have removed.
+pub fn summarize_statement(sql: &str, settings: &LogSettings) -> (String, String) {
+    let redacted = redact_formatted(sql, settings);
+    let text = redacted.as_deref().unwrap_or(sql);
+
+    let mut summary = parse_query_summary(text, settings.summary_word_count);
+
+    if summary_is_complete(&summary, text) {
+        return (summary, String::new());
+    }
+
+    summary.push_str(" …");
+
+    let statement = match redacted {
+        Some(redacted) => truncate_statement(redacted, sql.len(), false, settings),
+        None => format_truncated(sql, settings),
+    };
+
+    (summary, format!("\n\n{statement}\n"))
+}
+
+/// Format `sql` for log output, applying the redactor and length limit from `settings`.
+pub fn format_statement(sql: &str, settings: &LogSettings) -> String {
+    match redact_formatted(sql, settings) {
+        Some(redacted) => truncate_statement(redacted, sql.len(), false, settings),
+        None => format_truncated(sql, settings),
+    }
+}
+
+/// Format the whole statement and run the redactor over it, so the redactor sees stable
+/// formatting. Returns `None` if there is no redactor.
+fn redact_formatted(sql: &str, settings: &LogSettings) -> Option<String> {
+    settings.redactor.as_ref()?;
+
+    let formatted = format(sql);
+
+    let redacted = match settings.redact(&formatted) {
+        Cow::Owned(redacted) => Some(redacted),
+        Cow::Borrowed(_) => None,
+    };
+
+    Some(redacted.unwrap_or(formatted))
+}
+
+fn format_truncated(sql: &str, settings: &LogSettings) -> String {
+    // Only format as much of the statement as we're going to keep; this avoids running
+    // huge statements (e.g. bulk inserts) through the formatter in their entirety.
+    let prefix = match settings.max_statement_length {
+        Some(max_len) => truncate_chars(sql, max_len),
+        None => sql,
+    };
+
+    truncate_statement(
+        format(prefix),
+        sql.len(),
+        prefix.len() < sql.len(),
+        settings,
+    )
+}
+
+fn truncate_statement(
+    formatted: String,
+    total_len: usize,
+    cut: bool,
+    settings: &LogSettings,
+) -> String {
+    match settings.max_statement_length {
+        Some(max_len) if cut || formatted.chars().count() > max_len => {
+            format!(
+                "{} … (truncated, {} bytes total)",
+                truncate_chars(&formatted, max_len),
+                total_len
+            )
+        }
+        _ => formatted,
+    }
+}
+
+fn format(sql: &str) -> String {
+    sqlformat::format(
+        sql,
+        &sqlformat::QueryParams::None,
+        sqlformat::FormatOptions::default(),
+    )
+}
+
+fn truncate_chars(s: &str, max_chars: usize) -> &str {
+    match s.char_indices().nth(max_chars) {
+        Some((idx, _)) => &s[..idx],
+        None => s,
+    }
+}
+
+/// Returns `true` if `summary` contains every word of `sql`, in which case there is no need
+/// to log the formatted statement alongside it.
+pub fn summary_is_complete(summary: &str, sql: &str) -> bool {
+    summary.split_whitespace().eq(sql.split_whitespace())
+}
+
+/// Returns `sql` with any leading whitespace, `-- line` comments and `/* block */` comments removed.
+pub(crate) fn strip_leading_comments(mut sql: &str) -> &str {
+    loop {
+        sql = sql.trim_start();
+
+        if let Some(rest) = sql.strip_prefix("--") {
+            sql = rest.split_once('\n').map_or("", |(_, rest)| rest);
+        } else if let Some(rest) = sql.strip_prefix("/*") {
+            sql = rest.split_once("*/").map_or("", |(_, rest)| rest);
+        } else {
+            return sql;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        format_statement, parse_query_summary, summarize_statement, summary_is_complete,
+        QueryLogger,
+    };
+    use crate::connection::LogSettings;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn summary_skips_leading_comments() {
+        assert_eq!(
+            parse_query_summary(
+                "-- fetch active users\nSELECT id, name FROM users WHERE active",
+                4
+            ),
+            "SELECT id, name FROM"
+        );
+        assert_eq!(
+            parse_query_summary("/* tag: users\n   multi-line */\n-- another\nSELECT 1", 4),
+            "SELECT 1"
+        );
+        assert_eq!(
+            parse_query_summary("/* tag */SELECT * FROM users", 4),
+            "SELECT * FROM users"
+        );
+    }
+
+    #[test]
+    fn summary_of_comment_only_query_uses_raw_text() {
+        assert_eq!(
+            parse_query_summary("-- nothing to see here", 4),
+            "-- nothing to see"
+        );
+        assert_eq!(parse_query_summary("/* unterminated", 4), "/* unterminated");
+    }
+
+    #[test]
+    fn format_statement_truncates_long_statements() {
+        let mut settings = LogSettings::default();
+        settings.log_max_statement_length(12);
+
+        let sql = "INSERT INTO foo VALUES (1), (2), (3)";
+        assert_eq!(
+            format_statement(sql, &settings),
+            "INSERT INTO … (truncated, 36 bytes total)"
+        );
+
+        settings.log_max_statement_length(100);
+        assert!(!format_statement(sql, &settings).contains("truncated"));
+    }
+
+    #[test]
+    fn summary_word_count_is_configurable() {
+        let sql = "INSERT INTO long_table_name (a, b, c)\nVALUES ($1, $2, $3)";
+
+        assert_eq!(parse_query_summary(sql, 2), "INSERT INTO");
+        assert!(!summary_is_complete(&parse_query_summary(sql, 4), sql));
+        assert!(summary_is_complete(&parse_query_summary(sql, 10), sql));
+    }
+
+    #[test]
+    fn trace_level_follows_statements_level_by_default() {
+        use log::LevelFilter;
+
+        let settings = LogSettings::builder()
+            .statements_level(LevelFilter::Info)
+            .build();
+        assert_eq!(settings.trace_level(), LevelFilter::Info);
+
+        let settings = LogSettings::builder()
+            .statements_level(LevelFilter::Info)
+            .statements_trace_level(LevelFilter::Trace)
+            .build();
+        assert_eq!(settings.statements_level, LevelFilter::Info);
+        assert_eq!(settings.trace_level(), LevelFilter::Trace);
+    }
+
+    fn redact_password(sql: &str) -> std::borrow::Cow<'_, str> {
+        sql.replace("'hunter2'", "'***'").into()
+    }
+
+    #[test]
+    fn summary_of_short_statement_is_redacted() {
+        let mut settings = LogSettings::default();
+        settings.redact_statements(redact_password);
+
+        let (summary, sql) = summarize_statement("SET password = 'hunter2'", &settings);
+
+        assert_eq!(summary, "SET password = '***'");
+        assert_eq!(sql, "");
+    }
+
+    #[test]
+    fn statement_is_redacted_before_truncating() {
+        let mut settings = LogSettings::default();
+        settings.redact_statements(redact_password);
+        // cuts the secret in half: `... password = 'hunt`
+        settings.log_max_statement_length(34);
+
+        let formatted = format_statement(
+            "UPDATE users SET password = 'hunter2' WHERE id = 1",
+            &settings,
+        );
+
+        assert!(!formatted.contains("hunt"), "{formatted}");
+    }
+
+    #[test]
+    fn redactor_sees_formatted_statement() {
+        let mut settings = LogSettings::default();
+        // matches the formatted statement, which has spaces around `=`
+        settings.redact_statements(|sql| {
+            sql.replace("password = 'hunter2'", "password = '***'")
+                .into()
+        });
+
+        let formatted = format_statement(
+            "UPDATE users SET password='hunter2' WHERE id = 1",
+            &settings,
+        );
+
+        assert!(!formatted.contains("hunter2"), "{formatted}");
+        assert!(formatted.contains("password = '***'"), "{formatted}");
+    }
+
+    #[test]
+    fn query_executed_callback_receives_redacted_sql() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+
+        let mut settings = LogSettings::default();
+        settings.redact_statements(redact_password);
+        settings.on_query_executed({
+            let seen = seen.clone();
+            move |executed| {
+                seen.lock()
+                    .unwrap()
+                    .push((executed.summary.clone(), executed.sql.to_string()))
+            }
+        });
+
+        drop(QueryLogger::new("SET password = 'hunter2'", settings));
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 1);
+        assert_eq!(seen[0].0, "SET password = '***'");
+        assert!(!seen[0].1.contains("hunter2"), "{}", seen[0].1);
+    }
+}
@@ -9,6 +9,9 @@ pub enum MigrateError {
     #[error("while executing migration {1}: {0}")]
     ExecuteMigration(#[source] Error, i64),
 
+    #[error("while executing migration {1}: {0}")]
+    ExecuteCode(#[source] BoxDynError, i64),
+
     #[error("while resolving migrations: {0}")]
     Source(#[source] BoxDynError),
 
@@ -30,6 +33,9 @@ pub enum MigrateError {
     #[error("database driver does not support force-dropping a database (Only PostgreSQL)")]
     ForceNotSupported,
 
+    #[error("database driver does not support updating the checksum of an applied migration")]
+    UpdateChecksumNotSupported,
+
     #[deprecated = "migration types are now inferred"]
     #[error("cannot mix reversible migrations with simple migrations. All migrations should be reversible or simple migrations")]
     InvalidMixReversibleAndSimple,
@@ -39,4 +45,7 @@ pub enum MigrateError {
         "migration {0} is partially applied; fix and remove row from `_sqlx_migrations` table"
     )]
     Dirty(i64),
+
+    #[error("timed out waiting for the migration lock")]
+    LockTimeout,
 }
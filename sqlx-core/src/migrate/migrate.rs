@@ -29,6 +29,17 @@ pub trait Migrate {
     // will create or migrate it if needed
     fn ensure_migrations_table(&mut self) -> BoxFuture<'_, Result<(), MigrateError>>;
 
+    // check whether the migrations table exists, without creating it
+    // drivers that can't check fall back to creating it, after which it always exists
+    fn migrations_table_exists(&mut self) -> BoxFuture<'_, Result<bool, MigrateError>> {
+        let ensure = self.ensure_migrations_table();
+
+        Box::pin(async move {
+            ensure.await?;
+            Ok(true)
+        })
+    }
+
     // Return the version on which the database is dirty or None otherwise.
     // "dirty" means there is a partially applied migration that failed.
     fn dirty_version(&mut self) -> BoxFuture<'_, Result<Option<i64>, MigrateError>>;
@@ -47,6 +58,22 @@ pub trait Migrate {
     // migrations have been run.
     fn unlock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>>;
 
+    // Like `lock`, but with the given key instead of the default one, giving up with
+    // `MigrateError::LockTimeout` after `timeout`.
+    // Drivers that don't support these can leave this as `lock`.
+    fn lock_with(
+        &mut self,
+        _key: Option<i64>,
+        _timeout: Option<Duration>,
+    ) -> BoxFuture<'_, Result<(), MigrateError>> {
+        self.lock()
+    }
+
+    // Release the lock taken by `lock_with` with the same `key`.
+    fn unlock_with(&mut self, _key: Option<i64>) -> BoxFuture<'_, Result<(), MigrateError>> {
+        self.unlock()
+    }
+
     // run SQL from migration in a DDL transaction
     // insert new row to [_migrations] table on completion (success or failure)
     // returns the time taking to run the migration SQL
@@ -62,4 +89,13 @@ pub trait Migrate {
         &'e mut self,
         migration: &'m Migration,
     ) -> BoxFuture<'m, Result<Duration, MigrateError>>;
+
+    // overwrite the checksum in the [_migrations] row of an applied migration
+    // with the one from `migration`, without running it
+    fn update_checksum<'e: 'm, 'm>(
+        &'e mut self,
+        _migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<(), MigrateError>> {
+        Box::pin(async { Err(MigrateError::UpdateChecksumNotSupported) })
+    }
 }
@@ -1,8 +1,19 @@
+use std::any::Any;
 use std::borrow::Cow;
+use std::fmt::{self, Debug, Formatter};
+use std::sync::Arc;
+use std::time::Duration;
 
+use futures_core::future::BoxFuture;
 use sha2::{Digest, Sha384};
 
-use super::MigrationType;
+use crate::connection::Connection;
+use crate::database::Database;
+use crate::error::BoxDynError;
+use crate::executor::Executor;
+use crate::raw_sql::split_statements;
+
+use super::{MigrateError, MigrationType};
 
 #[derive(Debug, Clone)]
 pub struct Migration {
@@ -11,9 +22,45 @@ pub struct Migration {
     pub migration_type: MigrationType,
     pub sql: Cow<'static, str>,
     pub checksum: Cow<'static, [u8]>,
+    // Whether to run this migration outside of a transaction, see `Migration::new()`.
+    no_tx: bool,
+    // The code to run instead of `sql`, for migrations created with `Migration::from_fn()`.
+    code: Option<MigrationCode>,
+}
+
+type MigrationFn =
+    dyn for<'c> Fn(&'c mut dyn Any) -> BoxFuture<'c, Result<(), BoxDynError>> + Send + Sync;
+
+/// The body of a migration written in Rust. See [`Migration::from_fn()`].
+#[derive(Clone)]
+pub struct MigrationCode(Arc<MigrationFn>);
+
+impl Debug for MigrationCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("MigrationCode(..)")
+    }
 }
 
 impl Migration {
+    /// Create a migration from its SQL.
+    ///
+    /// Like every other migration, it is run in a transaction, together with recording it in
+    /// `_sqlx_migrations`, unless the SQL starts with this line:
+    ///
+    /// ```sql
+    /// -- sqlx:no-transaction
+    /// ```
+    ///
+    /// This is needed for statements that can't be run in a transaction, such as
+    /// `CREATE INDEX CONCURRENTLY` on Postgres or `VACUUM` on SQLite. The statements of such a
+    /// migration are split as with [`RawSql::execute_all()`][crate::raw_sql::RawSql::execute_all]
+    /// and executed one at a time, because Postgres implicitly wraps a string of several
+    /// statements in a transaction.
+    ///
+    /// Such a migration is not atomic: if a statement fails, the ones before it are not rolled
+    /// back and the migration is not recorded as applied, so it will run again from the start
+    /// next time. Keep these migrations to a single statement, or make them idempotent
+    /// (e.g. `IF NOT EXISTS`).
     pub fn new(
         version: i64,
         description: Cow<'static, str>,
@@ -21,6 +68,10 @@ impl Migration {
         sql: Cow<'static, str>,
     ) -> Self {
         let checksum = Cow::Owned(Vec::from(Sha384::digest(sql.as_bytes()).as_slice()));
+        let no_tx = sql
+            .lines()
+            .next()
+            .is_some_and(|line| line.trim() == "-- sqlx:no-transaction");
 
         Migration {
             version,
@@ -28,12 +79,210 @@ impl Migration {
             migration_type,
             sql,
             checksum,
+            no_tx,
+            code: None,
+        }
+    }
+
+    // Used by `migrate!()`, which computes the checksum at compile time and has to build the
+    // migrations in a const context.
+    #[doc(hidden)]
+    pub const fn from_macro(
+        version: i64,
+        description: &'static str,
+        migration_type: MigrationType,
+        sql: &'static str,
+        checksum: &'static [u8],
+        no_tx: bool,
+    ) -> Self {
+        Migration {
+            version,
+            description: Cow::Borrowed(description),
+            migration_type,
+            sql: Cow::Borrowed(sql),
+            checksum: Cow::Borrowed(checksum),
+            no_tx,
+            code: None,
+        }
+    }
+
+    /// Create a migration that runs Rust code instead of SQL, e.g. to transform data in ways
+    /// that can't be expressed in SQL. Add it with [`Migrator::add_migration()`][super::Migrator::add_migration].
+    ///
+    /// It is run in version order with the other migrations and recorded the same way, in a
+    /// transaction if the database supports it. As there is no SQL to hash, `checksum` is stored
+    /// as is; change it whenever the code changes in a way that should count as a modification.
+    ///
+    /// `f` is called with the connection (or transaction) of database `DB`; running it
+    /// on any other database returns an error.
+    ///
+    /// ```rust,ignore
+    /// use sqlx::migrate::{Migration, Migrator};
+    /// use sqlx::{PgConnection, Postgres};
+    ///
+    /// async fn reencrypt(conn: &mut PgConnection) -> Result<(), sqlx::error::BoxDynError> {
+    ///     // ...
+    ///     Ok(())
+    /// }
+    ///
+    /// let mut migrator = sqlx::migrate!();
+    /// migrator.add_migration(Migration::from_fn::<Postgres, _>(
+    ///     20240101000000,
+    ///     "reencrypt secrets",
+    ///     "v1",
+    ///     |conn| Box::pin(reencrypt(conn)),
+    /// ));
+    /// ```
+    pub fn from_fn<DB, F>(
+        version: i64,
+        description: impl Into<Cow<'static, str>>,
+        checksum: impl AsRef<[u8]>,
+        f: F,
+    ) -> Self
+    where
+        DB: Database,
+        DB::Connection: 'static,
+        F: for<'c> Fn(&'c mut DB::Connection) -> BoxFuture<'c, Result<(), BoxDynError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        let code: Arc<MigrationFn> =
+            Arc::new(move |conn| match conn.downcast_mut::<DB::Connection>() {
+                Some(conn) => f(conn),
+                None => Box::pin(async {
+                    Err(format!("migration can only be run on a {} connection", DB::NAME).into())
+                }),
+            });
+
+        Migration {
+            version,
+            description: description.into(),
+            migration_type: MigrationType::Simple,
+            sql: Cow::Borrowed(""),
+            checksum: Cow::Owned(checksum.as_ref().to_vec()),
+            no_tx: false,
+            code: Some(MigrationCode(code)),
+        }
+    }
+
+    /// Returns `true` if this migration is run outside of a transaction,
+    /// see [`Migration::new()`].
+    pub fn no_tx(&self) -> bool {
+        self.no_tx
+    }
+
+    // Run the SQL or code of this migration on `conn`; used by the drivers' `Migrate` impls.
+    #[doc(hidden)]
+    pub fn execute<'c, C>(&'c self, conn: &'c mut C) -> BoxFuture<'c, Result<(), MigrateError>>
+    where
+        C: Connection + Any,
+        for<'e> &'e mut C: Executor<'e, Database = C::Database>,
+    {
+        match &self.code {
+            Some(code) => {
+                let fut = (code.0)(conn);
+
+                Box::pin(async move {
+                    fut.await
+                        .map_err(|e| MigrateError::ExecuteCode(e, self.version))
+                })
+            }
+            None if self.no_tx => Box::pin(async move {
+                for statement in split_statements(&self.sql, C::Database::SQL_DIALECT) {
+                    let _ = conn
+                        .execute(statement)
+                        .await
+                        .map_err(|e| MigrateError::ExecuteMigration(e, self.version))?;
+                }
+
+                Ok(())
+            }),
+            None => Box::pin(async move {
+                let _ = conn
+                    .execute(&*self.sql)
+                    .await
+                    .map_err(|e| MigrateError::ExecuteMigration(e, self.version))?;
+
+                Ok(())
+            }),
         }
     }
 }
 
+/// A migration as recorded in the `_sqlx_migrations` table.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct AppliedMigration {
     pub version: i64,
     pub checksum: Cow<'static, [u8]>,
+    pub description: Cow<'static, str>,
+    /// How long the migration took to run, if it was recorded.
+    pub execution_time: Option<Duration>,
+    /// `false` if the migration failed part-way on a database without transactional DDL,
+    /// see [`MigrateError::Dirty`].
+    pub success: bool,
+    // microseconds since the Unix epoch
+    installed_on: Option<i64>,
+}
+
+impl AppliedMigration {
+    /// Creates a successfully applied migration, for implementations of
+    /// [`Migrate`][crate::migrate::Migrate] which only record the version and checksum.
+    pub fn new(version: i64, checksum: impl Into<Cow<'static, [u8]>>) -> Self {
+        AppliedMigration {
+            version,
+            checksum: checksum.into(),
+            description: Cow::Borrowed(""),
+            execution_time: None,
+            success: true,
+            installed_on: None,
+        }
+    }
+
+    // Build from the columns of `_sqlx_migrations`; used by the drivers' `Migrate` impls.
+    #[doc(hidden)]
+    pub fn from_row(
+        version: i64,
+        checksum: Vec<u8>,
+        description: String,
+        installed_on_micros: i64,
+        execution_time_nanos: i64,
+        success: bool,
+    ) -> Self {
+        AppliedMigration {
+            version,
+            checksum: checksum.into(),
+            description: description.into(),
+            // -1 until the execution time has been recorded
+            execution_time: u64::try_from(execution_time_nanos)
+                .ok()
+                .map(Duration::from_nanos),
+            success,
+            installed_on: Some(installed_on_micros),
+        }
+    }
+
+    /// When the migration was applied, if it was recorded.
+    ///
+    /// MySQL and SQLite only record it to the second.
+    #[cfg(feature = "time")]
+    pub fn installed_on(&self) -> Option<time::OffsetDateTime> {
+        let nanos = i128::from(self.installed_on?) * 1000;
+        time::OffsetDateTime::from_unix_timestamp_nanos(nanos).ok()
+    }
+
+    /// When the migration was applied, if it was recorded.
+    ///
+    /// MySQL and SQLite only record it to the second.
+    #[cfg(feature = "chrono")]
+    pub fn installed_on_chrono(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        use chrono::TimeZone;
+
+        let micros = self.installed_on?;
+        let nanos = micros.rem_euclid(1_000_000) as u32 * 1000;
+        chrono::Utc
+            .timestamp_opt(micros.div_euclid(1_000_000), nanos)
+            .single()
+    }
 }
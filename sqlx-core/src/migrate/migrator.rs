@@ -4,6 +4,7 @@ use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
 use std::slice;
+use std::time::Duration;
 
 /// A resolved set of migrations, ready to be run.
 ///
@@ -21,6 +22,28 @@ pub struct Migrator {
     pub ignore_missing: bool,
     #[doc(hidden)]
     pub locking: bool,
+    #[doc(hidden)]
+    pub lock_key: Option<i64>,
+    #[doc(hidden)]
+    pub lock_timeout: Option<Duration>,
+    #[doc(hidden)]
+    pub checksum_policy: ChecksumPolicy,
+}
+
+/// What [`Migrator::run()`] does when a previously applied migration has been modified
+/// since it was applied, i.e. its checksum no longer matches the one stored in the database.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum ChecksumPolicy {
+    /// Return [`MigrateError::VersionMismatch`]. This is the default.
+    #[default]
+    Strict,
+    /// Log a warning and carry on; the stored checksum is left as it is.
+    WarnAndContinue,
+    /// Store the new checksum without running the migration again, and log that it was updated.
+    ///
+    /// Intended for edits that don't change what the migration does,
+    /// e.g. to whitespace or comments.
+    Rehash,
 }
 
 fn validate_applied_migrations(
@@ -48,6 +71,9 @@ impl Migrator {
         migrations: Cow::Borrowed(&[]),
         ignore_missing: false,
         locking: true,
+        lock_key: None,
+        lock_timeout: None,
+        checksum_policy: ChecksumPolicy::Strict,
     };
 
     /// Creates a new instance with the given source.
@@ -97,6 +123,47 @@ impl Migrator {
         self
     }
 
+    /// Specify the key of the lock taken during migration. By default, it is derived from the
+    /// name of the database.
+    ///
+    /// On Postgres this is the key of an advisory lock; on MySQL, the name of a named lock
+    /// (`GET_LOCK()`) is this key in hexadecimal. SQLite does not take a lock.
+    pub fn set_lock_key(&mut self, key: i64) -> &Self {
+        self.lock_key = Some(key);
+        self
+    }
+
+    /// Specify how long to wait for the lock before giving up with [`MigrateError::LockTimeout`].
+    /// By default, the migrator waits indefinitely.
+    ///
+    /// MySQL only supports waiting for whole seconds, so this is rounded up.
+    pub fn set_lock_timeout(&mut self, timeout: Duration) -> &Self {
+        self.lock_timeout = Some(timeout);
+        self
+    }
+
+    /// Specify what to do when a previously applied migration has been modified.
+    /// Defaults to [`ChecksumPolicy::Strict`].
+    ///
+    /// ### Warning
+    /// [`ChecksumPolicy::Rehash`] accepts any change, including ones that alter the schema the
+    /// migration creates. Only use it for changes known to be harmless.
+    pub fn set_checksum_policy(&mut self, policy: ChecksumPolicy) -> &Self {
+        self.checksum_policy = policy;
+        self
+    }
+
+    /// Add a migration, e.g. one written in Rust with [`Migration::from_fn()`].
+    ///
+    /// It is inserted in order of version, after any existing migrations with the same version.
+    pub fn add_migration(&mut self, migration: Migration) -> &Self {
+        let migrations = self.migrations.to_mut();
+        let index = migrations.partition_point(|m| m.version <= migration.version);
+
+        migrations.insert(index, migration);
+        self
+    }
+
     /// Get an iterator over all known migrations.
     pub fn iter(&self) -> slice::Iter<'_, Migration> {
         self.migrations.iter()
@@ -110,6 +177,18 @@ impl Migrator {
     /// Run any pending migrations against the database; and, validate previously applied migrations
     /// against the current migration source to detect accidental changes in previously-applied migrations.
     ///
+    /// ### Note: Out-of-order migrations
+    /// A migration is pending if its version is not recorded in `_sqlx_migrations`, regardless of
+    /// whether newer versions have already been applied. This lets migrations from branches that
+    /// were merged in a different order than they were created still be applied; they are run in
+    /// ascending order of version.
+    ///
+    /// This means a migration may run against a schema its author never tested it with,
+    /// e.g. one where a column it expects has since been renamed. And reverting with
+    /// [`undo()`][Self::undo] to a version in between will revert the late migration as well,
+    /// since it has a higher version. Use timestamps for versions (as `sqlx migrate add` does)
+    /// so that collisions are unlikely, and review merged migrations for conflicts.
+    ///
     /// # Examples
     ///
     /// ```rust,no_run
@@ -142,7 +221,7 @@ impl Migrator {
     {
         // lock the database for exclusive access by the migrator
         if self.locking {
-            conn.lock().await?;
+            conn.lock_with(self.lock_key, self.lock_timeout).await?;
         }
 
         // creates [_migrations] table only if needed
@@ -170,7 +249,27 @@ impl Migrator {
             match applied_migrations.get(&migration.version) {
                 Some(applied_migration) => {
                     if migration.checksum != applied_migration.checksum {
-                        return Err(MigrateError::VersionMismatch(migration.version));
+                        match self.checksum_policy {
+                            ChecksumPolicy::Strict => {
+                                return Err(MigrateError::VersionMismatch(migration.version));
+                            }
+                            ChecksumPolicy::WarnAndContinue => {
+                                tracing::warn!(
+                                    version = migration.version,
+                                    description = %migration.description,
+                                    "migration was previously applied but has been modified"
+                                );
+                            }
+                            ChecksumPolicy::Rehash => {
+                                conn.update_checksum(migration).await?;
+
+                                tracing::info!(
+                                    version = migration.version,
+                                    description = %migration.description,
+                                    "updated checksum of modified migration"
+                                );
+                            }
+                        }
                     }
                 }
                 None => {
@@ -182,13 +281,140 @@ impl Migrator {
         // unlock the migrator to allow other migrators to run
         // but do nothing as we already migrated
         if self.locking {
-            conn.unlock().await?;
+            conn.unlock_with(self.lock_key).await?;
         }
 
         Ok(())
     }
 
-    /// Run down migrations against the database until a specific version.
+    /// List the migrations that [`run()`][Self::run] would apply, in the order it would apply
+    /// them, without making any changes to the database.
+    ///
+    /// Previously applied migrations are validated the same way as by `run()`, so this returns
+    /// the same errors, e.g. [`MigrateError::VersionMismatch`] if one has been modified
+    /// (depending on the [`ChecksumPolicy`]). If the migrations table does not exist yet,
+    /// every migration is pending.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use sqlx::migrate::MigrateError;
+    /// # fn main() -> Result<(), MigrateError> {
+    /// #     sqlx::__rt::test_block_on(async move {
+    /// use sqlx::migrate::Migrator;
+    /// use sqlx::sqlite::SqlitePoolOptions;
+    ///
+    /// let m = Migrator::new(std::path::Path::new("./migrations")).await?;
+    /// let pool = SqlitePoolOptions::new().connect("sqlite::memory:").await?;
+    ///
+    /// for migration in m.pending(&pool).await? {
+    ///     println!("{}/{}", migration.version, migration.description);
+    /// }
+    /// #     Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn pending<'a, A>(&self, migrator: A) -> Result<Vec<&Migration>, MigrateError>
+    where
+        A: Acquire<'a>,
+        <A::Connection as Deref>::Target: Migrate,
+    {
+        let mut conn = migrator.acquire().await?;
+        self.pending_direct(&mut *conn).await
+    }
+
+    // Getting around the annoying "implementation of `Acquire` is not general enough" error
+    #[doc(hidden)]
+    pub async fn pending_direct<C>(&self, conn: &mut C) -> Result<Vec<&Migration>, MigrateError>
+    where
+        C: Migrate,
+    {
+        let up_migrations = self
+            .iter()
+            .filter(|m| !m.migration_type.is_down_migration());
+
+        if !conn.migrations_table_exists().await? {
+            return Ok(up_migrations.collect());
+        }
+
+        let version = conn.dirty_version().await?;
+        if let Some(version) = version {
+            return Err(MigrateError::Dirty(version));
+        }
+
+        let applied_migrations = conn.list_applied_migrations().await?;
+        validate_applied_migrations(&applied_migrations, self)?;
+
+        let applied_migrations: HashMap<_, _> = applied_migrations
+            .into_iter()
+            .map(|m| (m.version, m))
+            .collect();
+
+        let mut pending = Vec::new();
+
+        for migration in up_migrations {
+            match applied_migrations.get(&migration.version) {
+                Some(applied_migration) => {
+                    if migration.checksum != applied_migration.checksum
+                        && self.checksum_policy == ChecksumPolicy::Strict
+                    {
+                        return Err(MigrateError::VersionMismatch(migration.version));
+                    }
+                }
+                None => pending.push(migration),
+            }
+        }
+
+        Ok(pending)
+    }
+
+    /// List the migrations recorded in the database as applied, in order of version,
+    /// e.g. to report migration status. This does not make any changes to the database;
+    /// if the migrations table does not exist yet, the list is empty.
+    ///
+    /// This includes migrations that aren't known to this migrator.
+    pub async fn applied<'a, A>(&self, migrator: A) -> Result<Vec<AppliedMigration>, MigrateError>
+    where
+        A: Acquire<'a>,
+        <A::Connection as Deref>::Target: Migrate,
+    {
+        let mut conn = migrator.acquire().await?;
+        self.applied_direct(&mut *conn).await
+    }
+
+    // Getting around the annoying "implementation of `Acquire` is not general enough" error
+    #[doc(hidden)]
+    pub async fn applied_direct<C>(
+        &self,
+        conn: &mut C,
+    ) -> Result<Vec<AppliedMigration>, MigrateError>
+    where
+        C: Migrate,
+    {
+        if !conn.migrations_table_exists().await? {
+            return Ok(Vec::new());
+        }
+
+        conn.list_applied_migrations().await
+    }
+
+    /// Revert applied migrations newer than `target`, newest first, by running their
+    /// `down` scripts.
+    ///
+    /// Only reversible migrations (`<VERSION>_<DESCRIPTION>.down.sql`) are reverted;
+    /// simple migrations have no `down` script and are skipped. Pass `0` to revert
+    /// everything.
+    ///
+    /// Each migration is reverted in its own transaction together with the removal of its row
+    /// from `_sqlx_migrations`, so if a `down` script fails, the migrations reverted before it
+    /// stay reverted, the failing one stays applied, and the error is returned without
+    /// attempting any older ones.
+    ///
+    /// ### Note: MySQL
+    /// MySQL implicitly commits the transaction on most DDL statements, so a `down` script that
+    /// fails part-way may leave some of its changes in place. The migration is then flagged as
+    /// failed in `_sqlx_migrations`, and both `run` and `undo` will return
+    /// [`MigrateError::Dirty`] until it is resolved by hand.
     ///
     /// # Examples
     ///
@@ -211,10 +437,18 @@ impl Migrator {
         <A::Connection as Deref>::Target: Migrate,
     {
         let mut conn = migrator.acquire().await?;
+        self.undo_direct(&mut *conn, target).await
+    }
 
+    // Getting around the annoying "implementation of `Acquire` is not general enough" error
+    #[doc(hidden)]
+    pub async fn undo_direct<C>(&self, conn: &mut C, target: i64) -> Result<(), MigrateError>
+    where
+        C: Migrate,
+    {
         // lock the database for exclusive access by the migrator
         if self.locking {
-            conn.lock().await?;
+            conn.lock_with(self.lock_key, self.lock_timeout).await?;
         }
 
         // creates [_migrations] table only if needed
@@ -247,7 +481,7 @@ impl Migrator {
         // unlock the migrator to allow other migrators to run
         // but do nothing as we already migrated
         if self.locking {
-            conn.unlock().await?;
+            conn.unlock_with(self.lock_key).await?;
         }
 
         Ok(())
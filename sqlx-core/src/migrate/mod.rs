@@ -8,9 +8,9 @@ mod source;
 
 pub use error::MigrateError;
 pub use migrate::{Migrate, MigrateDatabase};
-pub use migration::{AppliedMigration, Migration};
+pub use migration::{AppliedMigration, Migration, MigrationCode};
 pub use migration_type::MigrationType;
-pub use migrator::Migrator;
+pub use migrator::{ChecksumPolicy, Migrator};
 pub use source::MigrationSource;
 
 #[doc(hidden)]
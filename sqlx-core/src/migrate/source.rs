@@ -44,6 +44,82 @@ impl MigrationSource<'static> for PathBuf {
     }
 }
 
+/// Migrations embedded in the binary as `(file name, contents)` pairs.
+///
+/// File names are parsed exactly like those in a migration directory.
+impl<'s> MigrationSource<'s> for &'s [(&'s str, &'s str)] {
+    fn resolve(self) -> BoxFuture<'s, Result<Vec<Migration>, BoxDynError>> {
+        Box::pin(async move {
+            Ok(resolve_embedded(
+                self.iter().map(|(name, sql)| (*name, sql.as_bytes())),
+            )?)
+        })
+    }
+}
+
+/// Migrations embedded in the binary with [`include_dir!()`][include_dir::include_dir], e.g. to
+/// compose the migrations of several crates:
+///
+/// ```rust,ignore
+/// let migrator = Migrator::new(include_dir::include_dir!("$CARGO_MANIFEST_DIR/migrations")).await?;
+/// ```
+///
+/// Files are parsed exactly like those in a migration directory; subdirectories are ignored.
+#[cfg(feature = "include_dir")]
+impl MigrationSource<'static> for include_dir::Dir<'static> {
+    fn resolve(self) -> BoxFuture<'static, Result<Vec<Migration>, BoxDynError>> {
+        Box::pin(async move { Ok(resolve_include_dir(&self)?) })
+    }
+}
+
+#[cfg(feature = "include_dir")]
+impl<'s> MigrationSource<'s> for &'s include_dir::Dir<'_> {
+    fn resolve(self) -> BoxFuture<'s, Result<Vec<Migration>, BoxDynError>> {
+        Box::pin(async move { Ok(resolve_include_dir(self)?) })
+    }
+}
+
+#[cfg(feature = "include_dir")]
+fn resolve_include_dir(dir: &include_dir::Dir<'_>) -> Result<Vec<Migration>, ResolveError> {
+    resolve_embedded(dir.files().filter_map(|file| {
+        // like `resolve_blocking()`, only the file name matters
+        let file_name = file.path().file_name()?.to_string_lossy();
+
+        Some((file_name, file.contents()))
+    }))
+}
+
+fn resolve_embedded<'a, N: AsRef<str>>(
+    files: impl IntoIterator<Item = (N, &'a [u8])>,
+) -> Result<Vec<Migration>, ResolveError> {
+    let mut migrations = Vec::new();
+
+    for (file_name, contents) in files {
+        let file_name = file_name.as_ref();
+
+        let Some((version, description, migration_type)) = parse_file_name(file_name)? else {
+            continue;
+        };
+
+        let sql = std::str::from_utf8(contents).map_err(|e| ResolveError {
+            message: format!("error reading contents of migration {file_name}: {e}"),
+            source: None,
+        })?;
+
+        migrations.push(Migration::new(
+            version,
+            Cow::Owned(description),
+            migration_type,
+            Cow::Owned(sql.to_owned()),
+        ));
+    }
+
+    // Ensure that we are sorted by version in ascending order.
+    migrations.sort_by_key(|m| m.version);
+
+    Ok(migrations)
+}
+
 #[derive(thiserror::Error, Debug)]
 #[error("{message}")]
 pub struct ResolveError {
@@ -94,25 +170,9 @@ pub fn resolve_blocking(path: PathBuf) -> Result<Vec<(Migration, PathBuf)>, Reso
         // would be a breaking change.
         let file_name = file_name.to_string_lossy();
 
-        let parts = file_name.splitn(2, '_').collect::<Vec<_>>();
-
-        if parts.len() != 2 || !parts[1].ends_with(".sql") {
-            // not of the format: <VERSION>_<DESCRIPTION>.sql; ignore
+        let Some((version, description, migration_type)) = parse_file_name(&file_name)? else {
             continue;
-        }
-
-        let version: i64 = parts[0].parse()
-            .map_err(|_e| ResolveError {
-                message: format!("error parsing migration filename {file_name:?}; expected integer version prefix (e.g. `01_foo.sql`)"),
-                source: None,
-            })?;
-
-        let migration_type = MigrationType::from_filename(parts[1]);
-        // remove the `.sql` and replace `_` with ` `
-        let description = parts[1]
-            .trim_end_matches(migration_type.suffix())
-            .replace('_', " ")
-            .to_owned();
+        };
 
         let sql = fs::read_to_string(&entry_path).map_err(|e| ResolveError {
             message: format!(
@@ -138,3 +198,104 @@ pub fn resolve_blocking(path: PathBuf) -> Result<Vec<(Migration, PathBuf)>, Reso
 
     Ok(migrations)
 }
+
+/// Parse the version, description and type from a migration file name,
+/// returning `None` if it is not of the format `<VERSION>_<DESCRIPTION>.sql`.
+fn parse_file_name(file_name: &str) -> Result<Option<(i64, String, MigrationType)>, ResolveError> {
+    let parts = file_name.splitn(2, '_').collect::<Vec<_>>();
+
+    if parts.len() != 2 || !parts[1].ends_with(".sql") {
+        // not of the format: <VERSION>_<DESCRIPTION>.sql; ignore
+        return Ok(None);
+    }
+
+    let version: i64 = parts[0].parse()
+        .map_err(|_e| ResolveError {
+            message: format!("error parsing migration filename {file_name:?}; expected integer version prefix (e.g. `01_foo.sql`)"),
+            source: None,
+        })?;
+
+    let migration_type = MigrationType::from_filename(parts[1]);
+    // remove the `.sql` and replace `_` with ` `
+    let description = parts[1]
+        .trim_end_matches(migration_type.suffix())
+        .replace('_', " ")
+        .to_owned();
+
+    Ok(Some((version, description, migration_type)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_resolves_embedded_files_like_a_directory() {
+        for dir in ["migrations_simple", "migrations_reversible"] {
+            let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("../tests/migrate")
+                .join(dir);
+
+            let from_dir = resolve_blocking(path.clone()).unwrap();
+
+            let contents: Vec<(String, String)> = from_dir
+                .iter()
+                .map(|(_, path)| {
+                    (
+                        path.file_name().unwrap().to_str().unwrap().to_owned(),
+                        fs::read_to_string(path).unwrap(),
+                    )
+                })
+                .collect();
+
+            let mut files: Vec<(&str, &str)> = contents
+                .iter()
+                .map(|(name, sql)| (name.as_str(), sql.as_str()))
+                .collect();
+            files.push(("README.md", "not a migration"));
+
+            let embedded =
+                resolve_embedded(files.iter().map(|(name, sql)| (*name, sql.as_bytes()))).unwrap();
+
+            assert_eq!(embedded.len(), from_dir.len());
+
+            for (embedded, (from_dir, _)) in embedded.iter().zip(&from_dir) {
+                assert_eq!(embedded.version, from_dir.version);
+                assert_eq!(embedded.description, from_dir.description);
+                assert_eq!(embedded.migration_type, from_dir.migration_type);
+                assert_eq!(embedded.checksum, from_dir.checksum);
+            }
+        }
+    }
+
+    #[test]
+    fn it_rejects_invalid_versions() {
+        assert!(resolve_embedded([("one_init.sql", &b""[..])]).is_err());
+    }
+
+    #[cfg(feature = "include_dir")]
+    #[test]
+    fn it_resolves_include_dir_like_a_directory() {
+        static MIGRATIONS: include_dir::Dir<'static> =
+            include_dir::include_dir!("$CARGO_MANIFEST_DIR/../tests/migrate/migrations_reversible");
+
+        let path =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("../tests/migrate/migrations_reversible");
+
+        let mut from_dir = resolve_blocking(path).unwrap();
+        let mut embedded = resolve_include_dir(&MIGRATIONS).unwrap();
+
+        // the up and down migrations of a version can be listed in either order
+        from_dir.sort_by_key(|(m, _)| (m.version, m.migration_type.is_down_migration()));
+        embedded.sort_by_key(|m| (m.version, m.migration_type.is_down_migration()));
+
+        assert_eq!(embedded.len(), from_dir.len());
+
+        for (embedded, (from_dir, _)) in embedded.iter().zip(&from_dir) {
+            assert_eq!(embedded.version, from_dir.version);
+            assert_eq!(embedded.description, from_dir.description);
+            assert_eq!(embedded.migration_type, from_dir.migration_type);
+            assert_eq!(embedded.checksum, from_dir.checksum);
+        }
+    }
+}
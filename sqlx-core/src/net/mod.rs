@@ -1,6 +1,9 @@
+mod failover;
 mod socket;
 pub mod tls;
 
+pub use failover::{connect_failover, split_url_hosts};
 pub use socket::{
-    connect_tcp, connect_uds, BufferedSocket, Socket, SocketIntoBox, WithSocket, WriteBuffer,
+    connect_tcp, connect_uds, BufferedSocket, Resolver, Socket, SocketIntoBox, TcpOptions,
+    WithSocket, WriteBuffer,
 };
@@ -49,6 +49,10 @@ impl<S: Socket> BufferedSocket<S> {
         self.read_buf.read(len, &mut self.socket).await
     }
 
+    pub fn socket(&self) -> &S {
+        &self.socket
+    }
+
     pub fn write_buffer(&self) -> &WriteBuffer {
         &self.write_buf
     }
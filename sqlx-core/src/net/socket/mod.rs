@@ -1,10 +1,15 @@
+use std::fmt::{self, Debug, Formatter};
 use std::future::Future;
 use std::io;
+use std::net::SocketAddr;
 use std::path::Path;
 use std::pin::Pin;
+use std::sync::Arc;
 use std::task::{Context, Poll};
+use std::time::Duration;
 
 use bytes::BufMut;
+use futures_core::future::BoxFuture;
 use futures_core::ready;
 
 pub use buffered::{BufferedSocket, WriteBuffer};
@@ -29,6 +34,11 @@ pub trait Socket: Send + Sync + Unpin + 'static {
 
     fn poll_shutdown(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>>;
 
+    /// The DER-encoded certificate presented by the peer, if this is a TLS connection.
+    fn peer_certificate(&self) -> Option<Vec<u8>> {
+        None
+    }
+
     fn read<'a, B: ReadBuf>(&'a mut self, buf: &'a mut B) -> Read<'a, Self, B>
     where
         Self: Sized,
@@ -180,11 +190,86 @@ impl<S: Socket + ?Sized> Socket for Box<S> {
     fn poll_shutdown(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
         (**self).poll_shutdown(cx)
     }
+
+    fn peer_certificate(&self) -> Option<Vec<u8>> {
+        (**self).peer_certificate()
+    }
+}
+
+/// Socket options applied to TCP connections opened by [`connect_tcp`].
+#[derive(Clone, Default)]
+pub struct TcpOptions {
+    /// Enable `SO_KEEPALIVE`, sending the first probe after the connection is idle this long.
+    pub keepalive: Option<Duration>,
+
+    /// Set `TCP_USER_TIMEOUT`; ignored on platforms other than Linux, Android and Fuchsia.
+    pub user_timeout: Option<Duration>,
+
+    /// Resolve the host with this instead of the system resolver.
+    pub resolver: Option<Resolver>,
+}
+
+/// A user-supplied hostname resolver; see [`ConnectOptions::resolver()`][crate::connection::ConnectOptions::resolver].
+pub type Resolver =
+    Arc<dyn Fn(&str) -> BoxFuture<'static, io::Result<Vec<SocketAddr>>> + Send + Sync>;
+
+impl Debug for TcpOptions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TcpOptions")
+            .field("keepalive", &self.keepalive)
+            .field("user_timeout", &self.user_timeout)
+            .field(
+                "resolver",
+                &self.resolver.as_ref().map(|_| "<custom resolver>"),
+            )
+            .finish()
+    }
+}
+
+impl TcpOptions {
+    fn apply(&self, socket: socket2::SockRef<'_>) -> io::Result<()> {
+        if let Some(time) = self.keepalive {
+            socket.set_tcp_keepalive(&socket2::TcpKeepalive::new().with_time(time))?;
+        }
+
+        if let Some(timeout) = self.user_timeout {
+            #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
+            socket.set_tcp_user_timeout(Some(timeout))?;
+
+            #[cfg(not(any(target_os = "android", target_os = "fuchsia", target_os = "linux")))]
+            tracing::debug!(
+                ?timeout,
+                "TCP_USER_TIMEOUT is not supported on this platform; ignoring"
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Resolve `host` with the custom resolver, if one is set.
+    ///
+    /// Returned addresses with a port of `0` are given `port` instead.
+    async fn resolve(&self, host: &str, port: u16) -> io::Result<Option<Vec<SocketAddr>>> {
+        let Some(resolver) = &self.resolver else {
+            return Ok(None);
+        };
+
+        let mut addrs = resolver(host).await?;
+
+        for addr in &mut addrs {
+            if addr.port() == 0 {
+                addr.set_port(port);
+            }
+        }
+
+        Ok(Some(addrs))
+    }
 }
 
 pub async fn connect_tcp<Ws: WithSocket>(
     host: &str,
     port: u16,
+    options: &TcpOptions,
     with_socket: Ws,
 ) -> crate::Result<Ws::Output> {
     // IPv6 addresses in URLs will be wrapped in brackets and the `url` crate doesn't trim those.
@@ -194,8 +279,12 @@ pub async fn connect_tcp<Ws: WithSocket>(
     if crate::rt::rt_tokio::available() {
         use tokio::net::TcpStream;
 
-        let stream = TcpStream::connect((host, port)).await?;
+        let stream = match options.resolve(host, port).await? {
+            Some(addrs) => TcpStream::connect(&*addrs).await?,
+            None => TcpStream::connect((host, port)).await?,
+        };
         stream.set_nodelay(true)?;
+        options.apply(socket2::SockRef::from(&stream))?;
 
         return Ok(with_socket.with_socket(stream));
     }
@@ -208,12 +297,18 @@ pub async fn connect_tcp<Ws: WithSocket>(
 
         let mut last_err = None;
 
+        let socket_addrs = match options.resolve(host, port).await? {
+            Some(addrs) => addrs,
+            None => (host, port).to_socket_addrs().await?.collect(),
+        };
+
         // Loop through all the Socket Addresses that the hostname resolves to
-        for socket_addr in (host, port).to_socket_addrs().await? {
+        for socket_addr in socket_addrs {
             let stream = Async::<TcpStream>::connect(socket_addr)
                 .await
                 .and_then(|s| {
                     s.get_ref().set_nodelay(true)?;
+                    options.apply(socket2::SockRef::from(s.get_ref()))?;
                     Ok(s)
                 });
             match stream {
@@ -238,7 +333,7 @@ pub async fn connect_tcp<Ws: WithSocket>(
 
     #[cfg(not(feature = "_rt-async-std"))]
     {
-        crate::rt::missing_rt((host, port, with_socket))
+        crate::rt::missing_rt((host, port, options, with_socket))
     }
 }
 
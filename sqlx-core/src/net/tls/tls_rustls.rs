@@ -73,6 +73,11 @@ impl<S: Socket> Socket for RustlsSocket<S> {
         futures_util::ready!(self.poll_complete_io(cx))?;
         self.inner.socket.poll_shutdown(cx)
     }
+
+    fn peer_certificate(&self) -> Option<Vec<u8>> {
+        let cert = self.state.peer_certificates()?.first()?;
+        Some(cert.0.clone())
+    }
 }
 
 pub async fn handshake<S>(socket: S, tls_config: TlsConfig<'_>) -> Result<RustlsSocket<S>, Error>
@@ -3,6 +3,8 @@ use std::ops::{Deref, DerefMut};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+use rand::Rng;
+
 use crate::sync::AsyncSemaphoreReleaser;
 
 use crate::connection::Connection;
@@ -11,6 +13,7 @@ use crate::error::Error;
 
 use super::inner::{is_beyond_max_lifetime, DecrementSizeGuard, PoolInner};
 use crate::pool::options::PoolConnectionMetadata;
+use crate::pool::CloseReason;
 use std::future::Future;
 
 /// A connection managed by a [`Pool`][crate::pool::Pool].
@@ -24,6 +27,8 @@ pub struct PoolConnection<DB: Database> {
 pub(super) struct Live<DB: Database> {
     pub(super) raw: DB::Connection,
     pub(super) created_at: Instant,
+    /// Added to `max_lifetime` for this connection; see `PoolOptions::max_lifetime_jitter()`.
+    pub(super) lifetime_jitter: Duration,
 }
 
 pub(super) struct Idle<DB: Database> {
@@ -82,6 +87,7 @@ impl<DB: Database> PoolConnection<DB> {
     /// [`.close()`]: Connection::close
     pub async fn close(mut self) -> Result<(), Error> {
         let floating = self.take_live().float(self.pool.clone());
+        self.pool.record_connection_closed(CloseReason::Explicit);
         floating.inner.raw.close().await
     }
 
@@ -204,10 +210,19 @@ impl<DB: Database> DerefMut for Idle<DB> {
 
 impl<DB: Database> Floating<DB, Live<DB>> {
     pub fn new_live(conn: DB::Connection, guard: DecrementSizeGuard<DB>) -> Self {
+        let max_jitter = guard.pool.options.max_lifetime_jitter;
+
+        let lifetime_jitter = if max_jitter.is_zero() {
+            Duration::ZERO
+        } else {
+            rand::thread_rng().gen_range(Duration::ZERO..=max_jitter)
+        };
+
         Self {
             inner: Live {
                 raw: conn,
                 created_at: Instant::now(),
+                lifetime_jitter,
             },
             guard,
         }
@@ -234,15 +249,15 @@ impl<DB: Database> Floating<DB, Live<DB>> {
     /// Returns `true` if the connection was successfully returned, `false` if it was closed.
     async fn return_to_pool(mut self) -> bool {
         // Immediately close the connection.
-        if self.guard.pool.is_closed() {
-            self.close().await;
+        if self.guard.pool.is_closed() || self.guard.pool.is_draining() {
+            self.close(CloseReason::Explicit).await;
             return false;
         }
 
         // If the connection is beyond max lifetime, close the connection and
         // immediately create a new connection
         if is_beyond_max_lifetime(&self.inner, &self.guard.pool.options) {
-            self.close().await;
+            self.close(CloseReason::MaxLifetime).await;
             return false;
         }
 
@@ -251,14 +266,14 @@ impl<DB: Database> Floating<DB, Live<DB>> {
             match (test)(&mut self.inner.raw, meta).await {
                 Ok(true) => (),
                 Ok(false) => {
-                    self.close().await;
+                    self.close(CloseReason::FailedHealthCheck).await;
                     return false;
                 }
                 Err(error) => {
                     tracing::warn!(%error, "error from `after_release`");
                     // Connection is broken, don't try to gracefully close as
                     // something weird might happen.
-                    self.close_hard().await;
+                    self.close_hard(CloseReason::FailedHealthCheck).await;
                     return false;
                 }
             }
@@ -271,14 +286,19 @@ impl<DB: Database> Floating<DB, Live<DB>> {
         // returned to the pool; also of course, if it was dropped due to an error
         // this is simply a band-aid as SQLx-next connections should be able
         // to recover from cancellations
-        if let Err(error) = self.raw.ping().await {
+        let ping = match self.guard.pool.options.ping_timeout {
+            Some(timeout) => self.inner.raw.ping_timeout(timeout),
+            None => self.inner.raw.ping(),
+        };
+
+        if let Err(error) = ping.await {
             tracing::warn!(
                 %error,
                 "error occurred while testing the connection on-release",
             );
 
             // Connection is broken, don't try to gracefully close.
-            self.close_hard().await;
+            self.close_hard(CloseReason::FailedHealthCheck).await;
             false
         } else {
             // if the connection is still viable, release it to the pool
@@ -287,14 +307,16 @@ impl<DB: Database> Floating<DB, Live<DB>> {
         }
     }
 
-    pub async fn close(self) {
+    pub async fn close(self, reason: CloseReason) {
+        self.guard.pool.record_connection_closed(reason);
         // This isn't used anywhere that we care about the return value
         let _ = self.inner.raw.close().await;
 
         // `guard` is dropped as intended
     }
 
-    pub async fn close_hard(self) {
+    pub async fn close_hard(self, reason: CloseReason) {
+        self.guard.pool.record_connection_closed(reason);
         let _ = self.inner.raw.close_hard().await;
     }
 
@@ -330,7 +352,10 @@ impl<DB: Database> Floating<DB, Idle<DB>> {
     }
 
     pub async fn ping(&mut self) -> Result<(), Error> {
-        self.live.raw.ping().await
+        match self.guard.pool.options.ping_timeout {
+            Some(timeout) => self.live.raw.ping_timeout(timeout).await,
+            None => self.live.raw.ping().await,
+        }
     }
 
     pub fn into_live(self) -> Floating<DB, Live<DB>> {
@@ -340,14 +365,16 @@ impl<DB: Database> Floating<DB, Idle<DB>> {
         }
     }
 
-    pub async fn close(self) -> DecrementSizeGuard<DB> {
+    pub async fn close(self, reason: CloseReason) -> DecrementSizeGuard<DB> {
+        self.guard.pool.record_connection_closed(reason);
         if let Err(error) = self.inner.live.raw.close().await {
             tracing::debug!(%error, "error occurred while closing the pool connection");
         }
         self.guard
     }
 
-    pub async fn close_hard(self) -> DecrementSizeGuard<DB> {
+    pub async fn close_hard(self, reason: CloseReason) -> DecrementSizeGuard<DB> {
+        self.guard.pool.record_connection_closed(reason);
         let _ = self.inner.live.raw.close_hard().await;
 
         self.guard
@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+/// A lifecycle event of a [`Pool`][super::Pool], passed to the callback registered with
+/// [`PoolOptions::on_event`][super::PoolOptions::on_event].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PoolEvent {
+    /// A new connection was opened and added to the pool.
+    ConnectionEstablished,
+
+    /// A connection was closed by the pool.
+    ConnectionClosed {
+        /// Why the connection was closed.
+        reason: CloseReason,
+    },
+
+    /// A call to [`Pool::acquire()`][super::Pool::acquire] or similar started.
+    AcquireStarted,
+
+    /// A call to [`Pool::acquire()`][super::Pool::acquire] or similar returned a connection.
+    AcquireCompleted {
+        /// The time spent waiting for the connection, including opening it if necessary.
+        wait_duration: Duration,
+    },
+
+    /// A call to [`Pool::acquire()`][super::Pool::acquire] or similar failed with
+    /// [`Error::PoolTimedOut`][crate::error::Error::PoolTimedOut].
+    AcquireTimedOut,
+}
+
+/// The reason a connection was closed, as reported by [`PoolEvent::ConnectionClosed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CloseReason {
+    /// The connection sat in the idle queue longer than
+    /// [`idle_timeout`][super::PoolOptions::idle_timeout].
+    IdleTimeout,
+
+    /// The connection was open longer than [`max_lifetime`][super::PoolOptions::max_lifetime].
+    MaxLifetime,
+
+    /// The connection failed a health check, or was rejected by
+    /// [`before_acquire`][super::PoolOptions::before_acquire] or
+    /// [`after_release`][super::PoolOptions::after_release].
+    FailedHealthCheck,
+
+    /// The connection was closed with [`PoolConnection::close()`][super::PoolConnection::close],
+    /// or because the pool was closed or drained.
+    Explicit,
+}
@@ -3,15 +3,19 @@ use crate::connection::ConnectOptions;
 use crate::connection::Connection;
 use crate::database::Database;
 use crate::error::Error;
-use crate::pool::{deadline_as_timeout, CloseEvent, Pool, PoolOptions};
+use crate::pool::{
+    deadline_as_timeout, AcquirePriority, CloseEvent, CloseReason, Pool, PoolConnectionOrder,
+    PoolEvent, PoolOptions, PoolStatistics,
+};
 use crossbeam_queue::ArrayQueue;
 
 use crate::sync::{AsyncSemaphore, AsyncSemaphoreReleaser};
 
 use std::cmp;
+use std::collections::BTreeSet;
 use std::future::Future;
-use std::sync::atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering};
-use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
 use std::task::Poll;
 
 use crate::logger::private_level_filter_to_trace_level;
@@ -24,11 +28,26 @@ use tracing::Level;
 
 pub(crate) struct PoolInner<DB: Database> {
     pub(super) connect_options: RwLock<Arc<<DB::Connection as Connection>::Options>>,
-    pub(super) idle_conns: ArrayQueue<Idle<DB>>,
+    pub(super) idle_conns: IdleQueue<DB>,
     pub(super) semaphore: AsyncSemaphore,
     pub(super) size: AtomicU32,
     pub(super) num_idle: AtomicUsize,
+    pub(super) connections_created: AtomicU64,
+    pub(super) connections_closed: AtomicU64,
+    acquire_calls: AtomicU64,
+    acquire_timeouts: AtomicU64,
+    waiters: AtomicUsize,
+    /// Tasks waiting for a permit in `acquire()`, indexed by `AcquirePriority::index()`.
+    waiters_by_priority: [AtomicUsize; AcquirePriority::COUNT],
+    /// Tickets of the tasks which gave up a permit to a higher priority task in `acquire()`,
+    /// indexed by `AcquirePriority::index()`, so they can be served before later arrivals.
+    yielded_by_priority: [Mutex<BTreeSet<u64>>; AcquirePriority::COUNT],
+    /// Issues the tickets ordering the tasks waiting in `acquire()` by arrival.
+    next_waiter_ticket: AtomicU64,
+    /// Notified whenever a task stops waiting for a permit in `acquire()`.
+    on_waiter_done: event_listener::Event,
     is_closed: AtomicBool,
+    is_draining: AtomicBool,
     pub(super) on_closed: event_listener::Event,
     pub(super) options: PoolOptions<DB>,
     pub(crate) acquire_time_level: Option<Level>,
@@ -53,11 +72,21 @@ impl<DB: Database> PoolInner<DB> {
 
         let pool = Self {
             connect_options: RwLock::new(Arc::new(connect_options)),
-            idle_conns: ArrayQueue::new(capacity),
+            idle_conns: IdleQueue::new(options.connection_order, capacity),
             semaphore: AsyncSemaphore::new(options.fair, semaphore_capacity),
             size: AtomicU32::new(0),
             num_idle: AtomicUsize::new(0),
+            connections_created: AtomicU64::new(0),
+            connections_closed: AtomicU64::new(0),
+            acquire_calls: AtomicU64::new(0),
+            acquire_timeouts: AtomicU64::new(0),
+            waiters: AtomicUsize::new(0),
+            waiters_by_priority: Default::default(),
+            yielded_by_priority: Default::default(),
+            next_waiter_ticket: AtomicU64::new(0),
+            on_waiter_done: event_listener::Event::new(),
             is_closed: AtomicBool::new(false),
+            is_draining: AtomicBool::new(false),
             on_closed: event_listener::Event::new(),
             acquire_time_level: private_level_filter_to_trace_level(options.acquire_time_level),
             acquire_slow_level: private_level_filter_to_trace_level(options.acquire_slow_level),
@@ -84,10 +113,35 @@ impl<DB: Database> PoolInner<DB> {
         self.num_idle.load(Ordering::Acquire)
     }
 
+    pub(super) fn statistics(&self) -> PoolStatistics {
+        PoolStatistics {
+            connections_created: self.connections_created.load(Ordering::Relaxed),
+            connections_closed: self.connections_closed.load(Ordering::Relaxed),
+            acquire_calls: self.acquire_calls.load(Ordering::Relaxed),
+            acquire_timeouts: self.acquire_timeouts.load(Ordering::Relaxed),
+            waiters: self.waiters.load(Ordering::Relaxed),
+        }
+    }
+
+    pub(super) fn record_connection_closed(&self, reason: CloseReason) {
+        self.connections_closed.fetch_add(1, Ordering::Relaxed);
+        self.emit(PoolEvent::ConnectionClosed { reason });
+    }
+
+    fn emit(&self, event: PoolEvent) {
+        if let Some(callback) = &self.options.on_event {
+            callback(&event);
+        }
+    }
+
     pub(super) fn is_closed(&self) -> bool {
         self.is_closed.load(Ordering::Acquire)
     }
 
+    pub(super) fn is_draining(&self) -> bool {
+        self.is_draining.load(Ordering::Acquire)
+    }
+
     fn mark_closed(&self) {
         self.is_closed.store(true, Ordering::Release);
         self.on_closed.notify(usize::MAX);
@@ -96,20 +150,50 @@ impl<DB: Database> PoolInner<DB> {
     pub(super) fn close<'a>(self: &'a Arc<Self>) -> impl Future<Output = ()> + 'a {
         self.mark_closed();
 
+        self.close_connections()
+    }
+
+    pub(super) fn drain<'a>(self: &'a Arc<Self>) -> impl Future<Output = ()> + 'a {
+        self.is_draining.store(true, Ordering::Release);
+
         async move {
-            for permits in 1..=self.options.max_connections {
-                // Close any currently idle connections in the pool.
-                while let Some(idle) = self.idle_conns.pop() {
-                    let _ = idle.live.float((*self).clone()).close().await;
-                }
+            self.close_connections().await;
+            self.mark_closed();
+        }
+    }
 
-                if self.size() == 0 {
-                    break;
-                }
+    /// Drop all idle connections without closing them gracefully, returning how many there were.
+    pub(super) fn drop_idle(self: &Arc<Self>) -> u32 {
+        let mut dropped = 0;
+
+        while let Some(idle) = self.idle_conns.pop() {
+            self.num_idle.fetch_sub(1, Ordering::AcqRel);
+            self.record_connection_closed(CloseReason::Explicit);
+            drop(idle.live.float((*self).clone()));
+            dropped += 1;
+        }
+
+        dropped
+    }
+
+    /// Close idle connections as they become available until the pool is empty.
+    async fn close_connections(self: &Arc<Self>) {
+        for permits in 1..=self.options.max_connections {
+            // Close any currently idle connections in the pool.
+            while let Some(idle) = self.idle_conns.pop() {
+                let _ = idle
+                    .live
+                    .float((*self).clone())
+                    .close(CloseReason::Explicit)
+                    .await;
+            }
 
-                // Wait for all permits to be released.
-                let _permits = self.semaphore.acquire(permits).await;
+            if self.size() == 0 {
+                break;
             }
+
+            // Wait for all permits to be released.
+            let _permits = self.semaphore.acquire(permits).await;
         }
     }
 
@@ -182,7 +266,7 @@ impl<DB: Database> PoolInner<DB> {
 
     #[inline]
     pub(super) fn try_acquire(self: &Arc<Self>) -> Option<Floating<DB, Idle<DB>>> {
-        if self.is_closed() {
+        if self.is_closed() || self.is_draining() {
             return None;
         }
 
@@ -191,6 +275,17 @@ impl<DB: Database> PoolInner<DB> {
         self.pop_idle(permit).ok()
     }
 
+    /// Pop the least recently used idle connection matching `predicate`, if any.
+    fn pop_idle_where<'a>(
+        self: &'a Arc<Self>,
+        permit: AsyncSemaphoreReleaser<'a>,
+        predicate: impl FnMut(&Idle<DB>) -> bool,
+    ) -> Option<Floating<DB, Idle<DB>>> {
+        let idle = self.idle_conns.remove_oldest_where(predicate)?;
+        self.num_idle.fetch_sub(1, Ordering::AcqRel);
+        Some(Floating::from_idle(idle, (*self).clone(), permit))
+    }
+
     fn pop_idle<'a>(
         self: &'a Arc<Self>,
         permit: AsyncSemaphoreReleaser<'a>,
@@ -205,8 +300,12 @@ impl<DB: Database> PoolInner<DB> {
 
     pub(super) fn release(&self, floating: Floating<DB, Live<DB>>) {
         // `options.after_release` and other checks are in `PoolConnection::return_to_pool()`.
+        self.release_idle(floating.into_idle());
+    }
 
-        let Floating { inner: idle, guard } = floating.into_idle();
+    /// Put a connection back in the idle queue without resetting its `idle_since` timestamp.
+    fn release_idle(&self, floating: Floating<DB, Idle<DB>>) {
+        let Floating { inner: idle, guard } = floating;
 
         if !self.idle_conns.push(idle).is_ok() {
             panic!("BUG: connection queue overflow in release()");
@@ -226,37 +325,243 @@ impl<DB: Database> PoolInner<DB> {
         self: &'a Arc<Self>,
         permit: AsyncSemaphoreReleaser<'a>,
     ) -> Result<DecrementSizeGuard<DB>, AsyncSemaphoreReleaser<'a>> {
-        match self
-            .size
+        if self.increment_size() {
+            Ok(DecrementSizeGuard::from_permit((*self).clone(), permit))
+        } else {
+            Err(permit)
+        }
+    }
+
+    /// Returns `false` if the pool is at max capacity already or is closed.
+    fn increment_size(&self) -> bool {
+        self.size
             .fetch_update(Ordering::AcqRel, Ordering::Acquire, |size| {
-                if self.is_closed() {
+                if self.is_closed() || self.is_draining() {
                     return None;
                 }
 
                 size.checked_add(1)
                     .filter(|size| size <= &self.options.max_connections)
-            }) {
-            // we successfully incremented the size
-            Ok(_) => Ok(DecrementSizeGuard::from_permit((*self).clone(), permit)),
-            // the pool is at max capacity or is closed
-            Err(_) => Err(permit),
+            })
+            .is_ok()
+    }
+
+    /// Check out `n` connections at once, reserving all `n` permits before opening any.
+    pub(super) async fn acquire_many(
+        self: &Arc<Self>,
+        n: u32,
+        timeout: Duration,
+    ) -> Result<Vec<Floating<DB, Live<DB>>>, Error> {
+        if self.is_closed() {
+            return Err(Error::PoolClosed);
+        }
+
+        if self.is_draining() {
+            return Err(Error::PoolDraining);
+        }
+
+        if self.is_acquire_queue_full() {
+            return Err(Error::PoolQueueFull);
+        }
+
+        if n > self.options.max_connections {
+            return Err(Error::Configuration(
+                format!(
+                    "cannot acquire {n} connections from a pool with max_connections = {}",
+                    self.options.max_connections
+                )
+                .into(),
+            ));
+        }
+
+        // A child pool would have to steal all `n` permits from its parent,
+        // which `acquire_permit()` is not set up to do.
+        if self.parent().is_some() {
+            return Err(Error::Configuration(
+                "acquire_many() is not supported for pools with a parent".into(),
+            ));
+        }
+
+        self.acquire_calls.fetch_add(1, Ordering::Relaxed);
+        self.emit(PoolEvent::AcquireStarted);
+
+        let acquire_started_at = Instant::now();
+        let deadline = acquire_started_at + timeout;
+
+        let acquired = crate::rt::timeout(timeout, async {
+            let permits = {
+                let _waiting = WaiterGuard::new(&self.waiters);
+                self.close_event()
+                    .do_until(self.semaphore.acquire(n))
+                    .await?
+            };
+
+            if self.is_draining() {
+                return Err(Error::PoolDraining);
+            }
+
+            // From here on, `reserved` is responsible for the permits we haven't handed
+            // to a `DecrementSizeGuard` yet.
+            permits.disarm();
+            let mut reserved = ReservedPermits {
+                pool: self,
+                remaining: n,
+            };
+
+            let mut conns = Vec::with_capacity(n as usize);
+
+            while conns.len() < n as usize {
+                let guard = if let Some(idle) = self.idle_conns.pop() {
+                    self.num_idle.fetch_sub(1, Ordering::AcqRel);
+
+                    let conn = Floating {
+                        inner: idle,
+                        guard: reserved.take(),
+                    };
+
+                    match check_idle_conn(conn, &self.options).await {
+                        Ok(live) => {
+                            conns.push(live);
+                            continue;
+                        }
+                        Err(guard) => guard,
+                    }
+                } else if self.increment_size() {
+                    reserved.take()
+                } else if self.is_closed() {
+                    return Err(Error::PoolClosed);
+                } else if self.is_draining() {
+                    return Err(Error::PoolDraining);
+                } else {
+                    // Another task holding a permit may be about to release a connection
+                    // or decrement the size; see the same case in `acquire()`.
+                    crate::rt::yield_now().await;
+                    continue;
+                };
+
+                conns.push(self.connect(deadline, guard).await?);
+            }
+
+            Ok(conns)
+        })
+        .await
+        .map_err(|_| {
+            self.acquire_timeouts.fetch_add(1, Ordering::Relaxed);
+            self.emit(PoolEvent::AcquireTimedOut);
+            Error::PoolTimedOut
+        })??;
+
+        self.emit(PoolEvent::AcquireCompleted {
+            wait_duration: acquire_started_at.elapsed(),
+        });
+
+        Ok(acquired)
+    }
+
+    fn is_acquire_queue_full(&self) -> bool {
+        self.options
+            .max_acquire_queue
+            .map_or(false, |max| self.waiters.load(Ordering::Acquire) >= max)
+    }
+
+    /// Returns `true` if any tasks with a higher priority than `priority` are waiting for a permit.
+    fn has_higher_priority_waiters(&self, priority: AcquirePriority) -> bool {
+        self.waiters_by_priority[priority.index() + 1..]
+            .iter()
+            .any(|waiters| waiters.load(Ordering::Acquire) > 0)
+    }
+
+    /// Returns `true` if a task with `priority` and `ticket` should hand its permit on: either
+    /// a higher priority task is waiting, or a task with the same priority which arrived earlier
+    /// gave up its permit and is waiting for another one.
+    fn should_yield(&self, priority: AcquirePriority, ticket: u64) -> bool {
+        self.has_higher_priority_waiters(priority)
+            || self.yielded_by_priority[priority.index()]
+                .lock()
+                .unwrap()
+                .iter()
+                .next()
+                .is_some_and(|&first| first < ticket)
+    }
+
+    /// Wait until [`Self::should_yield()`] returns `false`, or until `deadline`.
+    async fn yield_permit(&self, priority: AcquirePriority, ticket: u64, deadline: Instant) {
+        loop {
+            let listener = self.on_waiter_done.listen();
+
+            // Check again after we start listening so we can't miss a notification.
+            if !self.should_yield(priority, ticket) {
+                return;
+            }
+
+            let Some(timeout) = deadline.checked_duration_since(Instant::now()) else {
+                return;
+            };
+
+            if crate::rt::timeout(timeout, listener).await.is_err() {
+                return;
+            }
         }
     }
 
-    pub(super) async fn acquire(self: &Arc<Self>) -> Result<Floating<DB, Live<DB>>, Error> {
+    pub(super) async fn acquire(
+        self: &Arc<Self>,
+        timeout: Duration,
+        priority: AcquirePriority,
+    ) -> Result<Floating<DB, Live<DB>>, Error> {
         if self.is_closed() {
             return Err(Error::PoolClosed);
         }
 
+        if self.is_draining() {
+            return Err(Error::PoolDraining);
+        }
+
+        if self.is_acquire_queue_full() {
+            return Err(Error::PoolQueueFull);
+        }
+
+        self.acquire_calls.fetch_add(1, Ordering::Relaxed);
+        self.emit(PoolEvent::AcquireStarted);
+
         let acquire_started_at = Instant::now();
-        let deadline = acquire_started_at + self.options.acquire_timeout;
+        let deadline = acquire_started_at + timeout;
+
+        // Once we've waited for half of our timeout, stop yielding to higher priority tasks
+        // so that we can't be starved entirely.
+        let aging_deadline = acquire_started_at + timeout / 2;
+
+        let ticket = self.next_waiter_ticket.fetch_add(1, Ordering::Relaxed);
+        let mut yielded = None;
 
         let acquired = crate::rt::timeout(
-            self.options.acquire_timeout,
+            timeout,
             async {
                 loop {
                     // Handles the close-event internally
-                    let permit = self.acquire_permit().await?;
+                    let permit = {
+                        let _waiting = WaiterGuard::new(&self.waiters);
+                        let _waiting_priority = PriorityWaiterGuard::new(self, priority);
+                        self.acquire_permit().await?
+                    };
+
+                    // We may have been woken by a connection being closed during a drain.
+                    if self.is_draining() {
+                        return Err(Error::PoolDraining);
+                    }
+
+                    // The semaphore is FIFO, so a higher priority task may be queued behind us;
+                    // hand the permit on to it instead. Until we get a permit we can keep, we hold
+                    // on to our ticket so that later tasks with our priority can't overtake us.
+                    if Instant::now() < aging_deadline && self.should_yield(priority, ticket) {
+                        let _waiting_priority = PriorityWaiterGuard::new(self, priority);
+                        drop(permit);
+                        yielded.get_or_insert_with(|| YieldedGuard::new(self, priority, ticket));
+                        self.yield_permit(priority, ticket, aging_deadline).await;
+                        continue;
+                    }
+
+                    drop(yielded.take());
 
 
                     // First attempt to pop a connection from the idle queue.
@@ -294,10 +599,18 @@ impl<DB: Database> PoolInner<DB> {
             }
         )
             .await
-            .map_err(|_| Error::PoolTimedOut)??;
+            .map_err(|_| {
+                self.acquire_timeouts.fetch_add(1, Ordering::Relaxed);
+                self.emit(PoolEvent::AcquireTimedOut);
+                Error::PoolTimedOut
+            })??;
 
         let acquired_after = acquire_started_at.elapsed();
 
+        self.emit(PoolEvent::AcquireCompleted {
+            wait_duration: acquired_after,
+        });
+
         let acquire_slow_level = self
             .acquire_slow_level
             .filter(|_| acquired_after > self.options.acquire_slow_threshold);
@@ -363,7 +676,11 @@ impl<DB: Database> PoolInner<DB> {
                     };
 
                     match res {
-                        Ok(()) => return Ok(Floating::new_live(raw, guard)),
+                        Ok(()) => {
+                            self.connections_created.fetch_add(1, Ordering::Relaxed);
+                            self.emit(PoolEvent::ConnectionEstablished);
+                            return Ok(Floating::new_live(raw, guard));
+                        }
                         Err(error) => {
                             tracing::error!(%error, "error returned from after_connect");
                             // The connection is broken, don't try to close nicely.
@@ -420,6 +737,49 @@ impl<DB: Database> PoolInner<DB> {
         Ok(())
     }
 
+    /// Concurrently open connections until there are `min_connections`, giving up at `deadline`.
+    ///
+    /// Unlike [`Self::try_min_connections()`], errors opening individual connections are logged
+    /// and the attempt retried rather than returned.
+    pub async fn warmup(self: &Arc<Self>, deadline: Instant) {
+        let missing = self.options.min_connections.saturating_sub(self.size());
+
+        future::join_all((0..missing).map(|_| self.warmup_connection(deadline))).await;
+    }
+
+    async fn warmup_connection(self: &Arc<Self>, deadline: Instant) {
+        loop {
+            let Some(permit) = self.semaphore.try_acquire(1) else {
+                return;
+            };
+
+            let Ok(guard) = self.try_increment_size(permit) else {
+                return;
+            };
+
+            match self.connect(deadline, guard).await {
+                // We skip `after_release` since the connection was never provided to user code
+                // besides `after_connect`, if they set it.
+                Ok(conn) => return self.release(conn),
+                Err(Error::PoolClosed | Error::PoolTimedOut) => return,
+                Err(error) => {
+                    tracing::warn!(%error, "error opening connection during pool warmup; retrying")
+                }
+            }
+
+            if Instant::now() >= deadline {
+                return;
+            }
+
+            // Don't retry in a tight loop if the error isn't going away.
+            crate::rt::sleep(cmp::min(
+                Duration::from_millis(100),
+                deadline.saturating_duration_since(Instant::now()),
+            ))
+            .await;
+        }
+    }
+
     /// Attempt to maintain `min_connections`, logging if unable.
     pub async fn min_connections_maintenance(self: &Arc<Self>, deadline: Option<Instant>) {
         let deadline = deadline.unwrap_or_else(|| {
@@ -454,9 +814,9 @@ pub(super) fn is_beyond_max_lifetime<DB: Database>(
     live: &Live<DB>,
     options: &PoolOptions<DB>,
 ) -> bool {
-    options
-        .max_lifetime
-        .map_or(false, |max| live.created_at.elapsed() > max)
+    options.max_lifetime.map_or(false, |max| {
+        live.created_at.elapsed() > max.saturating_add(live.lifetime_jitter)
+    })
 }
 
 /// Returns `true` if the connection has exceeded `options.idle_timeout` if set, `false` otherwise.
@@ -466,19 +826,35 @@ fn is_beyond_idle_timeout<DB: Database>(idle: &Idle<DB>, options: &PoolOptions<D
         .map_or(false, |timeout| idle.idle_since.elapsed() > timeout)
 }
 
+/// Returns why the reaper should close `idle`, if it should.
+fn expiry_reason<DB: Database>(idle: &Idle<DB>, options: &PoolOptions<DB>) -> Option<CloseReason> {
+    if is_beyond_max_lifetime(idle, options) {
+        Some(CloseReason::MaxLifetime)
+    } else if is_beyond_idle_timeout(idle, options) {
+        Some(CloseReason::IdleTimeout)
+    } else {
+        None
+    }
+}
+
 async fn check_idle_conn<DB: Database>(
     mut conn: Floating<DB, Idle<DB>>,
     options: &PoolOptions<DB>,
 ) -> Result<Floating<DB, Live<DB>>, DecrementSizeGuard<DB>> {
     if options.test_before_acquire {
         // Check that the connection is still live
-        if let Err(error) = conn.ping().await {
+        let res = match &options.test_before_acquire_with {
+            Some(test) => test(&mut conn.live.raw).await,
+            None => conn.ping().await,
+        };
+
+        if let Err(error) = res {
             // an error here means the other end has hung up or we lost connectivity
             // either way we're fine to just discard the connection
             // the error itself here isn't necessarily unexpected so WARN is too strong
             tracing::info!(%error, "ping on idle connection returned error");
             // connection is broken so don't try to close nicely
-            return Err(conn.close_hard().await);
+            return Err(conn.close_hard(CloseReason::FailedHealthCheck).await);
         }
     }
 
@@ -487,13 +863,13 @@ async fn check_idle_conn<DB: Database>(
         match test(&mut conn.live.raw, meta).await {
             Ok(false) => {
                 // connection was rejected by user-defined hook, close nicely
-                return Err(conn.close().await);
+                return Err(conn.close(CloseReason::FailedHealthCheck).await);
             }
 
             Err(error) => {
                 tracing::warn!(%error, "error from `before_acquire`");
                 // connection is broken so don't try to close nicely
-                return Err(conn.close_hard().await);
+                return Err(conn.close_hard(CloseReason::FailedHealthCheck).await);
             }
 
             Ok(true) => {}
@@ -535,7 +911,7 @@ fn spawn_maintenance_tasks<DB: Database>(pool: &Arc<PoolInner<DB>>) {
             .do_until(async {
                 // If the last handle to the pool was dropped while we were sleeping
                 while let Some(pool) = pool_weak.upgrade() {
-                    if pool.is_closed() {
+                    if pool.is_closed() || pool.is_draining() {
                         return;
                     }
 
@@ -545,15 +921,36 @@ fn spawn_maintenance_tasks<DB: Database>(pool: &Arc<PoolInner<DB>>) {
                     // and if we have fewer than min_connections after reaping a connection,
                     // open a new one immediately. Note that other connections may be popped from
                     // the queue in the meantime - that's fine, there is no harm in checking more
-                    for _ in 0..pool.num_idle() {
-                        if let Some(conn) = pool.try_acquire() {
-                            if is_beyond_idle_timeout(&conn, &pool.options)
-                                || is_beyond_max_lifetime(&conn, &pool.options)
-                            {
-                                let _ = conn.close().await;
+                    match pool.options.connection_order {
+                        PoolConnectionOrder::Fifo => {
+                            for _ in 0..pool.num_idle() {
+                                if let Some(conn) = pool.try_acquire() {
+                                    if let Some(reason) = expiry_reason(&conn, &pool.options) {
+                                        let _ = conn.close(reason).await;
+                                        pool.min_connections_maintenance(Some(next_run)).await;
+                                    } else {
+                                        // Keep the original `idle_since` so `idle_for`
+                                        // reflects the time since the connection was last used.
+                                        pool.release_idle(conn);
+                                    }
+                                }
+                            }
+                        }
+                        // Popping and releasing connections like above would only ever touch
+                        // the top of the stack, so the cold connections at the bottom would
+                        // never be reaped. Instead, pick out expired connections in place.
+                        PoolConnectionOrder::Lifo => {
+                            while let Some(permit) = pool.semaphore.try_acquire(1) {
+                                let Some(conn) = pool.pop_idle_where(permit, |idle| {
+                                    expiry_reason(idle, &pool.options).is_some()
+                                }) else {
+                                    break;
+                                };
+
+                                let reason = expiry_reason(&conn, &pool.options)
+                                    .unwrap_or(CloseReason::IdleTimeout);
+                                let _ = conn.close(reason).await;
                                 pool.min_connections_maintenance(Some(next_run)).await;
-                            } else {
-                                pool.release(conn.into_live());
                             }
                         }
                     }
@@ -574,6 +971,154 @@ fn spawn_maintenance_tasks<DB: Database>(pool: &Arc<PoolInner<DB>>) {
     });
 }
 
+/// The idle connections of a pool, in the order given by [`PoolConnectionOrder`].
+// There's only one of these per pool, so the size difference doesn't matter.
+#[allow(clippy::large_enum_variant)]
+pub(super) enum IdleQueue<DB: Database> {
+    Fifo(ArrayQueue<Idle<DB>>),
+    /// Least recently used first; connections are pushed to and popped from the end.
+    Lifo(Mutex<Vec<Idle<DB>>>),
+}
+
+impl<DB: Database> IdleQueue<DB> {
+    fn new(order: PoolConnectionOrder, capacity: usize) -> Self {
+        match order {
+            PoolConnectionOrder::Fifo => Self::Fifo(ArrayQueue::new(capacity)),
+            PoolConnectionOrder::Lifo => Self::Lifo(Mutex::new(Vec::with_capacity(capacity))),
+        }
+    }
+
+    fn push(&self, idle: Idle<DB>) -> Result<(), Idle<DB>> {
+        match self {
+            Self::Fifo(queue) => queue.push(idle),
+            Self::Lifo(stack) => {
+                stack
+                    .lock()
+                    .expect("BUG: panicked while holding lock")
+                    .push(idle);
+                Ok(())
+            }
+        }
+    }
+
+    fn pop(&self) -> Option<Idle<DB>> {
+        match self {
+            Self::Fifo(queue) => queue.pop(),
+            Self::Lifo(stack) => stack
+                .lock()
+                .expect("BUG: panicked while holding lock")
+                .pop(),
+        }
+    }
+
+    /// Remove the least recently used connection matching `predicate`.
+    ///
+    /// Only supported for [`PoolConnectionOrder::Lifo`], as `ArrayQueue` cannot remove
+    /// elements from the middle; returns `None` otherwise.
+    fn remove_oldest_where(&self, predicate: impl FnMut(&Idle<DB>) -> bool) -> Option<Idle<DB>> {
+        match self {
+            Self::Fifo(_) => None,
+            Self::Lifo(stack) => {
+                let mut stack = stack.lock().expect("BUG: panicked while holding lock");
+                let position = stack.iter().position(predicate)?;
+                Some(stack.remove(position))
+            }
+        }
+    }
+}
+
+/// Semaphore permits reserved by `acquire_many()` which have not yet been given to a connection.
+///
+/// Any that are left over on-drop (e.g. on error or cancellation) are released.
+struct ReservedPermits<'a, DB: Database> {
+    pool: &'a Arc<PoolInner<DB>>,
+    remaining: u32,
+}
+
+impl<DB: Database> ReservedPermits<'_, DB> {
+    fn take(&mut self) -> DecrementSizeGuard<DB> {
+        self.remaining = self
+            .remaining
+            .checked_sub(1)
+            .expect("BUG: took more permits than were reserved");
+
+        DecrementSizeGuard::new_permit(self.pool.clone())
+    }
+}
+
+impl<DB: Database> Drop for ReservedPermits<'_, DB> {
+    fn drop(&mut self) {
+        self.pool.semaphore.release(self.remaining as usize);
+    }
+}
+
+/// RAII guard recording the ticket of a task which gave up a permit in `acquire()`.
+struct YieldedGuard<'a, DB: Database> {
+    pool: &'a PoolInner<DB>,
+    priority: AcquirePriority,
+    ticket: u64,
+}
+
+impl<'a, DB: Database> YieldedGuard<'a, DB> {
+    fn new(pool: &'a PoolInner<DB>, priority: AcquirePriority, ticket: u64) -> Self {
+        pool.yielded_by_priority[priority.index()]
+            .lock()
+            .unwrap()
+            .insert(ticket);
+        Self {
+            pool,
+            priority,
+            ticket,
+        }
+    }
+}
+
+impl<DB: Database> Drop for YieldedGuard<'_, DB> {
+    fn drop(&mut self) {
+        self.pool.yielded_by_priority[self.priority.index()]
+            .lock()
+            .unwrap()
+            .remove(&self.ticket);
+        self.pool.on_waiter_done.notify(usize::MAX);
+    }
+}
+
+/// RAII guard counting a task waiting for a permit in `acquire()` by priority.
+struct PriorityWaiterGuard<'a, DB: Database> {
+    pool: &'a PoolInner<DB>,
+    priority: AcquirePriority,
+}
+
+impl<'a, DB: Database> PriorityWaiterGuard<'a, DB> {
+    fn new(pool: &'a PoolInner<DB>, priority: AcquirePriority) -> Self {
+        pool.waiters_by_priority[priority.index()].fetch_add(1, Ordering::AcqRel);
+        Self { pool, priority }
+    }
+}
+
+impl<DB: Database> Drop for PriorityWaiterGuard<'_, DB> {
+    fn drop(&mut self) {
+        self.pool.waiters_by_priority[self.priority.index()].fetch_sub(1, Ordering::AcqRel);
+        self.pool.on_waiter_done.notify(usize::MAX);
+    }
+}
+
+/// RAII guard counting a task waiting on the pool's semaphore in `acquire()`.
+struct WaiterGuard<'a>(&'a AtomicUsize);
+
+impl<'a> WaiterGuard<'a> {
+    fn new(waiters: &'a AtomicUsize) -> Self {
+        waiters.fetch_add(1, Ordering::Relaxed);
+        Self(waiters)
+    }
+}
+
+impl Drop for WaiterGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
 /// RAII guard returned by `Pool::try_increment_size()` and others.
 ///
 /// Will decrement the pool size if dropped, to avoid semantically "leaking" connections
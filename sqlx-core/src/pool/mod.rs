@@ -68,10 +68,12 @@ use crate::any::{Any, AnyKind};
 use crate::connection::Connection;
 use crate::database::Database;
 use crate::error::Error;
-use crate::transaction::Transaction;
+use crate::transaction::{Transaction, TransactionOptions, TransactionRetry};
 use event_listener::EventListener;
+use futures_core::future::BoxFuture;
 use futures_core::FusedFuture;
 use futures_util::FutureExt;
+use std::cmp;
 use std::fmt;
 use std::future::Future;
 use std::pin::Pin;
@@ -86,11 +88,13 @@ mod executor;
 pub mod maybe;
 
 mod connection;
+mod event;
 mod inner;
 mod options;
 
 pub use self::connection::PoolConnection;
-pub use self::options::{PoolConnectionMetadata, PoolOptions};
+pub use self::event::{CloseReason, PoolEvent};
+pub use self::options::{PoolConnectionMetadata, PoolConnectionOrder, PoolOptions};
 
 #[doc(hidden)]
 pub use self::maybe::MaybePoolConnection;
@@ -266,6 +270,42 @@ pub use self::maybe::MaybePoolConnection;
 /// well and queries will generally benefit from these caches being "warm" (populated with data).
 pub struct Pool<DB: Database>(pub(crate) Arc<PoolInner<DB>>);
 
+/// A snapshot of counters kept by a [`Pool`], returned by [`Pool::statistics()`].
+///
+/// All counts except `waiters` are cumulative over the lifetime of the pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct PoolStatistics {
+    /// Number of connections successfully opened by the pool.
+    pub connections_created: u64,
+    /// Number of connections closed by the pool, including broken or expired connections.
+    pub connections_closed: u64,
+    /// Number of calls to [`Pool::acquire()`] and friends.
+    pub acquire_calls: u64,
+    /// Number of acquire calls which failed with [`Error::PoolTimedOut`].
+    pub acquire_timeouts: u64,
+    /// Number of tasks currently waiting for a connection permit.
+    pub waiters: usize,
+}
+
+/// The priority of a task waiting for a connection in [`Pool::acquire_with_priority()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum AcquirePriority {
+    Low,
+    /// The priority used by [`Pool::acquire()`].
+    #[default]
+    Normal,
+    High,
+}
+
+impl AcquirePriority {
+    pub(crate) const COUNT: usize = 3;
+
+    pub(crate) fn index(self) -> usize {
+        self as usize
+    }
+}
+
 /// A future that resolves when the pool is closed.
 ///
 /// See [`Pool::close_event()`] for details.
@@ -362,8 +402,78 @@ impl<DB: Database> Pool<DB> {
     /// This should eliminate any potential `.await` points between acquiring a connection and
     /// returning it.
     pub fn acquire(&self) -> impl Future<Output = Result<PoolConnection<DB>, Error>> + 'static {
+        self.acquire_with_timeout(self.0.options.acquire_timeout)
+    }
+
+    /// Retrieves a connection from the pool, ahead of any lower priority tasks.
+    ///
+    /// Identical to [`acquire()`][Self::acquire] except that when a connection becomes available,
+    /// it goes to the waiting task with the highest priority. Tasks with the same priority are
+    /// served in the order they called this method (or `acquire()`, which uses
+    /// [`AcquirePriority::Normal`]).
+    ///
+    /// ### Note: Starvation
+    /// Under sustained load from higher priority tasks, lower priority tasks may not get a
+    /// connection at all. To bound this, a task stops yielding to higher priority tasks once
+    /// it has waited for half of [`PoolOptions::acquire_timeout`].
+    pub fn acquire_with_priority(
+        &self,
+        priority: AcquirePriority,
+    ) -> impl Future<Output = Result<PoolConnection<DB>, Error>> + 'static {
         let shared = self.0.clone();
-        async move { shared.acquire().await.map(|conn| conn.reattach()) }
+        let timeout = shared.options.acquire_timeout;
+        async move {
+            shared
+                .acquire(timeout, priority)
+                .await
+                .map(|conn| conn.reattach())
+        }
+    }
+
+    /// Retrieves a connection from the pool, waiting at most `timeout`.
+    ///
+    /// Identical to [`acquire()`][Self::acquire] except that `timeout` is used in place of
+    /// [`PoolOptions::acquire_timeout`] for this call only.
+    /// If the timeout elapses, this will return [`Error::PoolTimedOut`].
+    pub fn acquire_with_timeout(
+        &self,
+        timeout: Duration,
+    ) -> impl Future<Output = Result<PoolConnection<DB>, Error>> + 'static {
+        let shared = self.0.clone();
+        async move {
+            shared
+                .acquire(timeout, AcquirePriority::Normal)
+                .await
+                .map(|conn| conn.reattach())
+        }
+    }
+
+    /// Retrieves `n` connections from the pool at once.
+    ///
+    /// This waits until `n` connections can be checked out together, reserving them up-front
+    /// before opening any new connections. This avoids the deadlock which could occur if
+    /// multiple tasks each acquired part of the connections they need and waited for the rest.
+    ///
+    /// The total time this method is allowed to execute is capped by
+    /// [`PoolOptions::acquire_timeout`]. If that timeout elapses, this will return
+    /// [`Error::PoolTimedOut`] and any connections already checked out are dropped,
+    /// as with [`acquire()`][Self::acquire].
+    ///
+    /// Returns [`Error::Configuration`] if `n` is greater than
+    /// [`max_connections`][PoolOptions::max_connections], or if this pool has a
+    /// [parent][PoolOptions::parent], which is not currently supported.
+    pub fn acquire_many(
+        &self,
+        n: u32,
+    ) -> impl Future<Output = Result<Vec<PoolConnection<DB>>, Error>> + 'static {
+        let shared = self.0.clone();
+        async move {
+            let conns = shared
+                .acquire_many(n, shared.options.acquire_timeout)
+                .await?;
+
+            Ok(conns.into_iter().map(|conn| conn.reattach()).collect())
+        }
     }
 
     /// Attempts to retrieve a connection from the pool if there is one available.
@@ -379,6 +489,104 @@ impl<DB: Database> Pool<DB> {
         Ok(Transaction::begin(MaybePoolConnection::PoolConnection(self.acquire().await?)).await?)
     }
 
+    /// Retrieves a connection and immediately begins a new transaction with the given options.
+    ///
+    /// See [`Connection::begin_with()`] for details.
+    pub async fn begin_with(
+        &self,
+        options: TransactionOptions,
+    ) -> Result<Transaction<'static, DB>, Error> {
+        Ok(Transaction::begin_with(
+            MaybePoolConnection::PoolConnection(self.acquire().await?),
+            options,
+        )
+        .await?)
+    }
+
+    /// Run `callback` in a transaction, retrying it if it fails with a serialization failure,
+    /// a deadlock or a lock timeout.
+    ///
+    /// The transaction is committed if `callback` returns `Ok`, and rolled back otherwise.
+    /// If that fails with one of the [retryable errors], the transaction is run
+    /// again with a new connection from the pool, after a delay that doubles with every retry;
+    /// any other error is returned immediately, as is the last error once `max_retries` is
+    /// exhausted.
+    ///
+    /// Because `callback` may run more than once, it should not have side effects outside of the
+    /// transaction.
+    ///
+    /// ```rust,ignore
+    /// let balance: i64 = pool
+    ///     .transaction_retry(3, |tx| {
+    ///         Box::pin(async move {
+    ///             sqlx::query("UPDATE accounts SET balance = balance - 10 WHERE id = 1")
+    ///                 .execute(&mut **tx)
+    ///                 .await?;
+    ///
+    ///             sqlx::query_scalar("SELECT balance FROM accounts WHERE id = 1")
+    ///                 .fetch_one(&mut **tx)
+    ///                 .await
+    ///         })
+    ///     })
+    ///     .await?;
+    /// ```
+    ///
+    /// See [`transaction_retry_with()`][Self::transaction_retry_with] to set the isolation level,
+    /// the error codes to retry on, or the delay.
+    ///
+    /// [retryable errors]: TransactionRetry::retryable_codes
+    pub async fn transaction_retry<F, R>(&self, max_retries: u32, callback: F) -> Result<R, Error>
+    where
+        for<'c> F: FnMut(&'c mut Transaction<'_, DB>) -> BoxFuture<'c, Result<R, Error>>,
+    {
+        self.transaction_retry_with(TransactionRetry::new(max_retries), callback)
+            .await
+    }
+
+    /// Run `callback` in a transaction, retrying it as configured by `retry`.
+    ///
+    /// See [`transaction_retry()`][Self::transaction_retry] for details.
+    pub async fn transaction_retry_with<F, R>(
+        &self,
+        retry: TransactionRetry,
+        mut callback: F,
+    ) -> Result<R, Error>
+    where
+        for<'c> F: FnMut(&'c mut Transaction<'_, DB>) -> BoxFuture<'c, Result<R, Error>>,
+    {
+        let mut retries = 0;
+        let mut backoff = retry.initial_backoff;
+
+        loop {
+            let mut tx = self.begin_with(retry.options).await?;
+
+            let res = match callback(&mut tx).await {
+                Ok(ret) => tx.commit().await.map(|_| ret),
+                Err(e) => {
+                    // the error from the callback decides whether to retry; if the rollback
+                    // fails, dropping the transaction queues another one anyway
+                    if let Err(rollback_error) = tx.rollback().await {
+                        tracing::debug!(error = %rollback_error, "failed to roll back transaction");
+                    }
+
+                    Err(e)
+                }
+            };
+
+            match res {
+                Err(e) if retries < retry.max_retries && retry.is_retryable(&e) => {
+                    tracing::debug!(error = %e, retries, ?backoff, "retrying transaction");
+
+                    crate::rt::sleep(backoff).await;
+
+                    retries += 1;
+                    backoff = cmp::min(backoff * 2, retry.max_backoff);
+                }
+                res => return res,
+            }
+        }
+    }
+
     /// Attempts to retrieve a connection and immediately begins a new transaction if successful.
     pub async fn try_begin(&self) -> Result<Option<Transaction<'static, DB>>, Error> {
         match self.try_acquire() {
@@ -415,11 +623,65 @@ impl<DB: Database> Pool<DB> {
         self.0.close()
     }
 
+    /// Shut down the connection pool like [`.close()`][Pool::close], but give up on closing
+    /// connections gracefully after `timeout`.
+    ///
+    /// If connections are still open when `timeout` elapses, any idle connections and any
+    /// connection the pool was in the process of closing are dropped without waiting for the
+    /// database server, i.e. the underlying socket is closed immediately.
+    ///
+    /// Connections which are still checked out cannot be dropped by the pool; they will be closed
+    /// when they are returned, as with `.close()`.
+    ///
+    /// Returns the number of connections which were forcibly dropped. This does not include
+    /// connections which were still checked out when `timeout` elapsed.
+    pub async fn close_timeout(&self, timeout: Duration) -> u32 {
+        let closing = {
+            let close = self.close();
+            futures_util::pin_mut!(close);
+
+            if crate::rt::timeout(timeout, &mut close).await.is_ok() {
+                return 0;
+            }
+
+            self.size()
+            // Dropping `close` here cancels any graceful close in progress.
+        };
+
+        // Connections are closed one at a time, so at most one close was cancelled; any other
+        // change in size is from checked-out connections being returned and closed gracefully.
+        let cancelled = u32::from(self.size() < closing);
+
+        cancelled + self.0.drop_idle()
+    }
+
     /// Returns `true` if [`.close()`][Pool::close] has been called on the pool, `false` otherwise.
     pub fn is_closed(&self) -> bool {
         self.0.is_closed()
     }
 
+    /// Gracefully shut down the connection pool, letting checked-out connections finish their work.
+    ///
+    /// Upon calling this method, any subsequent calls to [`Pool::acquire`] and the like will
+    /// return [`Error::PoolDraining`] and no new connections will be opened. Tasks already waiting
+    /// for a connection will also return [`Error::PoolDraining`] as they are woken.
+    ///
+    /// Checked-out connections are unaffected until they are returned, at which point they
+    /// are gracefully closed. Idle connections are closed immediately.
+    ///
+    /// Returns a `Future` which resolves once the last connection has been returned and closed,
+    /// after which the pool is marked closed as if by [`.close()`][Pool::close].
+    /// Unlike `.close()`, this does not wake tasks waiting on [`.close_event()`][Pool::close_event]
+    /// until the pool is empty.
+    pub fn drain(&self) -> impl Future<Output = ()> + '_ {
+        self.0.drain()
+    }
+
+    /// Returns `true` if [`.drain()`][Pool::drain] has been called on the pool, `false` otherwise.
+    pub fn is_draining(&self) -> bool {
+        self.0.is_draining()
+    }
+
     /// Get a future that resolves when [`Pool::close()`] is called.
     ///
     /// If the pool is already closed, the future resolves immediately.
@@ -512,6 +774,13 @@ impl<DB: Database> Pool<DB> {
         self.0.num_idle()
     }
 
+    /// Returns a snapshot of the pool's cumulative counters.
+    ///
+    /// See [`PoolStatistics`] for details.
+    pub fn statistics(&self) -> PoolStatistics {
+        self.0.statistics()
+    }
+
     /// Gets a clone of the connection options for this pool
     pub fn connect_options(&self) -> Arc<<DB::Connection as Connection>::Options> {
         self.0
@@ -575,6 +844,7 @@ impl<DB: Database> fmt::Debug for Pool<DB> {
             .field("size", &self.0.size())
             .field("num_idle", &self.0.num_idle())
             .field("is_closed", &self.0.is_closed())
+            .field("is_draining", &self.0.is_draining())
             .field("options", &self.0.options)
             .finish()
     }
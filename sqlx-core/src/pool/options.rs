@@ -2,7 +2,7 @@ use crate::connection::Connection;
 use crate::database::Database;
 use crate::error::Error;
 use crate::pool::inner::PoolInner;
-use crate::pool::Pool;
+use crate::pool::{AcquirePriority, Pool, PoolEvent};
 use futures_core::future::BoxFuture;
 use log::LevelFilter;
 use std::fmt::{self, Debug, Formatter};
@@ -44,6 +44,12 @@ use std::time::{Duration, Instant};
 /// the perspectives of both API designer and consumer.
 pub struct PoolOptions<DB: Database> {
     pub(crate) test_before_acquire: bool,
+    pub(crate) ping_timeout: Option<Duration>,
+    pub(crate) test_before_acquire_with: Option<
+        Arc<
+            dyn Fn(&mut DB::Connection) -> BoxFuture<'_, Result<(), Error>> + 'static + Send + Sync,
+        >,
+    >,
     pub(crate) after_connect: Option<
         Arc<
             dyn Fn(&mut DB::Connection, PoolConnectionMetadata) -> BoxFuture<'_, Result<(), Error>>
@@ -74,15 +80,19 @@ pub struct PoolOptions<DB: Database> {
                 + Sync,
         >,
     >,
+    pub(crate) on_event: Option<Arc<dyn Fn(&PoolEvent) + 'static + Send + Sync>>,
     pub(crate) max_connections: u32,
     pub(crate) acquire_time_level: LevelFilter,
     pub(crate) acquire_slow_level: LevelFilter,
     pub(crate) acquire_slow_threshold: Duration,
     pub(crate) acquire_timeout: Duration,
+    pub(crate) max_acquire_queue: Option<usize>,
     pub(crate) min_connections: u32,
     pub(crate) max_lifetime: Option<Duration>,
+    pub(crate) max_lifetime_jitter: Duration,
     pub(crate) idle_timeout: Option<Duration>,
     pub(crate) fair: bool,
+    pub(crate) connection_order: PoolConnectionOrder,
 
     pub(crate) parent_pool: Option<Pool<DB>>,
 }
@@ -94,23 +104,47 @@ impl<DB: Database> Clone for PoolOptions<DB> {
     fn clone(&self) -> Self {
         PoolOptions {
             test_before_acquire: self.test_before_acquire,
+            ping_timeout: self.ping_timeout,
+            test_before_acquire_with: self.test_before_acquire_with.clone(),
             after_connect: self.after_connect.clone(),
             before_acquire: self.before_acquire.clone(),
             after_release: self.after_release.clone(),
+            on_event: self.on_event.clone(),
             max_connections: self.max_connections,
             acquire_time_level: self.acquire_time_level,
             acquire_slow_threshold: self.acquire_slow_threshold,
             acquire_slow_level: self.acquire_slow_level,
             acquire_timeout: self.acquire_timeout,
+            max_acquire_queue: self.max_acquire_queue,
             min_connections: self.min_connections,
             max_lifetime: self.max_lifetime,
+            max_lifetime_jitter: self.max_lifetime_jitter,
             idle_timeout: self.idle_timeout,
             fair: self.fair,
+            connection_order: self.connection_order,
             parent_pool: self.parent_pool.as_ref().map(Pool::clone),
         }
     }
 }
 
+/// The order in which idle connections are handed out by the pool.
+///
+/// See [`PoolOptions::connection_order`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PoolConnectionOrder {
+    /// Hand out the connection which has been idle the longest.
+    ///
+    /// This spreads load evenly over all connections in the pool.
+    Fifo,
+    /// Hand out the connection which was most recently returned to the pool.
+    ///
+    /// This keeps a small set of "hot" connections in use, with warm caches on both ends,
+    /// while the rest sit idle and are eventually closed by
+    /// [`idle_timeout`][PoolOptions::idle_timeout].
+    Lifo,
+}
+
 /// Metadata for the connection being processed by a [`PoolOptions`] callback.
 #[derive(Debug)] // Don't want to commit to any other trait impls yet.
 #[non_exhaustive] // So we can safely add fields in the future.
@@ -120,7 +154,8 @@ pub struct PoolConnectionMetadata {
     /// For [`after_connect`][PoolOptions::after_connect], this is [`Duration::ZERO`].
     pub age: Duration,
 
-    /// The duration that the connection spent in the idle queue.
+    /// The duration that the connection spent in the idle queue, i.e. since it was last
+    /// returned to the pool.
     ///
     /// Only relevant for [`before_acquire`][PoolOptions::before_acquire].
     /// For other callbacks, this is [`Duration::ZERO`].
@@ -146,7 +181,10 @@ impl<DB: Database> PoolOptions<DB> {
             after_connect: None,
             before_acquire: None,
             after_release: None,
+            on_event: None,
             test_before_acquire: true,
+            ping_timeout: None,
+            test_before_acquire_with: None,
             // A production application will want to set a higher limit than this.
             max_connections: 10,
             min_connections: 0,
@@ -158,9 +196,12 @@ impl<DB: Database> PoolOptions<DB> {
             // to not flag typical time to add a new connection to a pool.
             acquire_slow_threshold: Duration::from_secs(2),
             acquire_timeout: Duration::from_secs(30),
+            max_acquire_queue: None,
             idle_timeout: Some(Duration::from_secs(10 * 60)),
             max_lifetime: Some(Duration::from_secs(30 * 60)),
+            max_lifetime_jitter: Duration::ZERO,
             fair: true,
+            connection_order: PoolConnectionOrder::Fifo,
             parent_pool: None,
         }
     }
@@ -268,6 +309,26 @@ impl<DB: Database> PoolOptions<DB> {
         self.acquire_timeout
     }
 
+    /// Set the maximum number of tasks which may wait for a connection at once.
+    ///
+    /// Once this many tasks are waiting, [`Pool::acquire()`] and the like immediately
+    /// return [`Error::PoolQueueFull`] instead of waiting. This allows an application to shed
+    /// load quickly if the database stops responding, instead of accumulating waiting tasks.
+    ///
+    /// The limit is checked when `acquire()` is called, so it may be exceeded slightly
+    /// if many tasks call it at the same time.
+    ///
+    /// Defaults to `None`, i.e. unbounded.
+    pub fn max_acquire_queue(mut self, max: impl Into<Option<usize>>) -> Self {
+        self.max_acquire_queue = max.into();
+        self
+    }
+
+    /// Get the maximum number of tasks which may wait for a connection at once.
+    pub fn get_max_acquire_queue(&self) -> Option<usize> {
+        self.max_acquire_queue
+    }
+
     /// Set the maximum lifetime of individual connections.
     ///
     /// Any connection with a lifetime greater than this will be closed.
@@ -292,6 +353,23 @@ impl<DB: Database> PoolOptions<DB> {
         self.max_lifetime
     }
 
+    /// Extend the [`max_lifetime`][Self::max_lifetime] of each connection by a random duration
+    /// of up to `jitter`, chosen when the connection is opened.
+    ///
+    /// Connections opened at the same time, such as when the pool is first created, would
+    /// otherwise all expire at the same time and have to be reopened in a burst.
+    ///
+    /// Defaults to zero, i.e. no jitter.
+    pub fn max_lifetime_jitter(mut self, jitter: Duration) -> Self {
+        self.max_lifetime_jitter = jitter;
+        self
+    }
+
+    /// Get the maximum random extension of each connection's lifetime.
+    pub fn get_max_lifetime_jitter(&self) -> Duration {
+        self.max_lifetime_jitter
+    }
+
     /// Set a maximum idle duration for individual connections.
     ///
     /// Any connection that remains in the idle queue longer than this will be closed.
@@ -307,6 +385,21 @@ impl<DB: Database> PoolOptions<DB> {
         self.idle_timeout
     }
 
+    /// Set the order in which idle connections are handed out by `acquire()`.
+    ///
+    /// See [`PoolConnectionOrder`] for details.
+    ///
+    /// Defaults to [`PoolConnectionOrder::Fifo`].
+    pub fn connection_order(mut self, order: PoolConnectionOrder) -> Self {
+        self.connection_order = order;
+        self
+    }
+
+    /// Get the order in which idle connections are handed out by `acquire()`.
+    pub fn get_connection_order(&self) -> PoolConnectionOrder {
+        self.connection_order
+    }
+
     /// If true, the health of a connection will be verified by a call to [`Connection::ping`]
     /// before returning the connection.
     ///
@@ -321,6 +414,68 @@ impl<DB: Database> PoolOptions<DB> {
         self.test_before_acquire
     }
 
+    /// Set a timeout for the [`Connection::ping`] the pool uses to check the health of a
+    /// connection, before acquiring it and when it's released back to the pool.
+    ///
+    /// A connection whose ping times out is closed. Without this, an unresponsive connection
+    /// can hold up [`Pool::acquire`] until [`acquire_timeout`][Self::acquire_timeout] elapses,
+    /// instead of being discarded in favor of another connection.
+    ///
+    /// Defaults to `None` (no timeout).
+    pub fn ping_timeout(mut self, timeout: impl Into<Option<Duration>>) -> Self {
+        self.ping_timeout = timeout.into();
+        self
+    }
+
+    /// Get the timeout for health check pings, if set.
+    pub fn get_ping_timeout(&self) -> Option<Duration> {
+        self.ping_timeout
+    }
+
+    /// Verify the health of an idle connection with a custom check before returning it,
+    /// instead of the built-in [`Connection::ping`].
+    ///
+    /// This implies [`test_before_acquire(true)`][Self::test_before_acquire].
+    ///
+    /// If the check returns an error, the error is logged, the connection is closed and
+    /// [`Pool::acquire`] tries again with another idle connection, or opens a new one.
+    /// Newly opened connections are not checked. As with any other part of acquiring a connection,
+    /// this is bounded by [`acquire_timeout`][Self::acquire_timeout].
+    ///
+    /// # Example: Avoid Replicas in Recovery
+    /// This example is written for Postgres but should be trivially adaptable to other databases.
+    /// ```no_run
+    /// # async fn f() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sqlx::postgres::PgPoolOptions;
+    ///
+    /// let pool = PgPoolOptions::new()
+    ///     .test_before_acquire_with(|conn| Box::pin(async move {
+    ///         let in_recovery: bool = sqlx::query_scalar("SELECT pg_is_in_recovery()")
+    ///             .fetch_one(&mut *conn)
+    ///             .await?;
+    ///
+    ///         if in_recovery {
+    ///             return Err(sqlx::Error::Protocol("server is in recovery".into()));
+    ///         }
+    ///
+    ///         Ok(())
+    ///     }))
+    ///     .connect("postgres:// …").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// For a discussion on why `Box::pin()` is required, see [the type-level docs][Self].
+    pub fn test_before_acquire_with<F>(mut self, test: F) -> Self
+    where
+        for<'c> F:
+            Fn(&'c mut DB::Connection) -> BoxFuture<'c, Result<(), Error>> + 'static + Send + Sync,
+    {
+        self.test_before_acquire = true;
+        self.test_before_acquire_with = Some(Arc::new(test));
+        self
+    }
+
     /// If set to `true`, calls to `acquire()` are fair and connections  are issued
     /// in first-come-first-serve order. If `false`, "drive-by" tasks may steal idle connections
     /// ahead of tasks that have been waiting.
@@ -489,6 +644,29 @@ impl<DB: Database> PoolOptions<DB> {
     ///     .connect("postgres:// …").await?;
     /// # Ok(())
     /// # }
+    /// ```
+    ///
+    /// # Example (Postgres): Reset Session State
+    /// Session-local settings changed while a connection was checked out would otherwise
+    /// leak into the next task to acquire it.
+    ///
+    /// ```no_run
+    /// # async fn f() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sqlx::Executor;
+    /// use sqlx::postgres::PgPoolOptions;
+    ///
+    /// let pool = PgPoolOptions::new()
+    ///     .after_release(|conn, _meta| Box::pin(async move {
+    ///         // `DISCARD ALL` also drops temporary tables and deallocates prepared statements,
+    ///         // which would invalidate SQLx's statement cache, so stick to `RESET ALL`.
+    ///         conn.execute("RESET ALL").await?;
+    ///
+    ///         Ok(true)
+    ///     }))
+    ///     .connect("postgres:// …").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn after_release<F>(mut self, callback: F) -> Self
     where
         for<'c> F: Fn(&'c mut DB::Connection, PoolConnectionMetadata) -> BoxFuture<'c, Result<bool, Error>>
@@ -500,6 +678,37 @@ impl<DB: Database> PoolOptions<DB> {
         self
     }
 
+    /// Register a callback to be invoked for each lifecycle event of the pool.
+    ///
+    /// This is a more structured alternative to the `sqlx::pool::acquire` logs, e.g. for
+    /// recording metrics. See [`PoolEvent`] for the events which are reported.
+    ///
+    /// The callback is invoked synchronously from within the pool, so it should return quickly;
+    /// send the event to a channel if you need to do anything more involved.
+    ///
+    /// ```no_run
+    /// # async fn f() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sqlx::pool::PoolEvent;
+    /// use sqlx::postgres::PgPoolOptions;
+    ///
+    /// let pool = PgPoolOptions::new()
+    ///     .on_event(|event| {
+    ///         if let PoolEvent::AcquireCompleted { wait_duration } = event {
+    ///             println!("waited {wait_duration:?} for a connection");
+    ///         }
+    ///     })
+    ///     .connect("postgres:// …").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_event<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&PoolEvent) + 'static + Send + Sync,
+    {
+        self.on_event = Some(Arc::new(callback));
+        self
+    }
+
     /// Set the parent `Pool` from which the new pool will inherit its semaphore.
     ///
     /// This is currently an internal-only API.
@@ -534,6 +743,10 @@ impl<DB: Database> PoolOptions<DB> {
     /// This ensures the configuration is correct.
     ///
     /// The total number of connections opened is <code>max(1, [min_connections][Self::min_connections])</code>.
+    ///
+    /// The `min_connections` are opened concurrently, with [`acquire_timeout`][Self::acquire_timeout]
+    /// as the budget for the whole call. If opening one of them fails, the error is logged and
+    /// the attempt retried; an error is only returned if no connection could be opened at all.
     pub async fn connect_with(
         self,
         options: <DB::Connection as Connection>::Options,
@@ -544,14 +757,18 @@ impl<DB: Database> PoolOptions<DB> {
         let inner = PoolInner::new_arc(self, options);
 
         if inner.options.min_connections > 0 {
-            // If the idle reaper is spawned then this will race with the call from that task
-            // and may not report any connection errors.
-            inner.try_min_connections(deadline).await?;
+            // If the idle reaper is spawned then this will race with the call from that task,
+            // which is fine since both obey `min_connections` and `max_connections`.
+            inner.warmup(deadline).await;
         }
 
         // If `min_connections` is nonzero then we'll likely just pull a connection
         // from the idle queue here, but it should at least get tested first.
-        let conn = inner.acquire().await?;
+        //
+        // This is also where we report an error if warmup was unable to open any connections.
+        let conn = inner
+            .acquire(inner.options.acquire_timeout, AcquirePriority::Normal)
+            .await?;
         inner.release(conn);
 
         Ok(Pool(inner))
@@ -589,6 +806,7 @@ impl<DB: Database> Debug for PoolOptions<DB> {
             .field("min_connections", &self.min_connections)
             .field("connect_timeout", &self.acquire_timeout)
             .field("max_lifetime", &self.max_lifetime)
+            .field("max_lifetime_jitter", &self.max_lifetime_jitter)
             .field("idle_timeout", &self.idle_timeout)
             .field("test_before_acquire", &self.test_before_acquire)
             .finish()
@@ -1,4 +1,5 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use either::Either;
 use futures_core::stream::BoxStream;
@@ -19,6 +20,8 @@ pub struct Query<'q, DB: Database, A> {
     pub(crate) arguments: Option<A>,
     pub(crate) database: PhantomData<DB>,
     pub(crate) persistent: bool,
+    pub(crate) slow_statements_duration: Option<Duration>,
+    pub(crate) text_results: bool,
 }
 
 /// A single SQL query that will map its results to an owned Rust type.
@@ -67,6 +70,16 @@ where
     fn persistent(&self) -> bool {
         self.persistent
     }
+
+    #[inline]
+    fn slow_statements_duration(&self) -> Option<Duration> {
+        self.slow_statements_duration
+    }
+
+    #[inline]
+    fn text_results(&self) -> bool {
+        self.text_results
+    }
 }
 
 impl<'q, DB: Database> Query<'q, DB, <DB as Database>::Arguments<'q>> {
@@ -107,6 +120,33 @@ where
     }
 }
 
+impl<'q, DB: Database, A> Query<'q, DB, A> {
+    /// Log this query as slow if it takes longer than `duration`, in place of the
+    /// connection's configured slow statement threshold.
+    ///
+    /// Useful for queries which are expected to take a long time, such as analytics rollups.
+    ///
+    /// Not currently supported by the `Any` driver.
+    pub fn slow_statements_duration(mut self, duration: Duration) -> Self {
+        self.slow_statements_duration = Some(duration);
+        self
+    }
+
+    /// If `true`, ask the database to send the results in its text format instead of its
+    /// binary format, and decode them from text.
+    ///
+    /// This is useful for types which only have a text output, or whose binary decoding is
+    /// broken, as well as for debugging.
+    ///
+    /// Only supported by Postgres; other drivers ignore it.
+    ///
+    /// Default: `false`.
+    pub fn text_results(mut self, value: bool) -> Self {
+        self.text_results = value;
+        self
+    }
+}
+
 impl<'q, DB, A: Send> Query<'q, DB, A>
 where
     DB: Database,
@@ -288,6 +328,34 @@ where
     fn persistent(&self) -> bool {
         self.inner.arguments.is_some()
     }
+
+    #[inline]
+    fn slow_statements_duration(&self) -> Option<Duration> {
+        Execute::slow_statements_duration(&self.inner)
+    }
+
+    #[inline]
+    fn text_results(&self) -> bool {
+        Execute::text_results(&self.inner)
+    }
+}
+
+impl<'q, DB: Database, F, A> Map<'q, DB, F, A> {
+    /// Log this query as slow if it takes longer than `duration`.
+    ///
+    /// See [`Query::slow_statements_duration`].
+    pub fn slow_statements_duration(mut self, duration: Duration) -> Self {
+        self.inner = self.inner.slow_statements_duration(duration);
+        self
+    }
+
+    /// Request the results in the database's text format.
+    ///
+    /// See [`Query::text_results`].
+    pub fn text_results(mut self, value: bool) -> Self {
+        self.inner = self.inner.text_results(value);
+        self
+    }
 }
 
 impl<'q, DB, F, O, A> Map<'q, DB, F, A>
@@ -475,6 +543,8 @@ where
         arguments: Some(Default::default()),
         statement: Either::Right(statement),
         persistent: true,
+        slow_statements_duration: None,
+        text_results: false,
     }
 }
 
@@ -492,6 +562,8 @@ where
         arguments: Some(arguments),
         statement: Either::Right(statement),
         persistent: true,
+        slow_statements_duration: None,
+        text_results: false,
     }
 }
 
@@ -628,6 +700,8 @@ where
         arguments: Some(Default::default()),
         statement: Either::Left(sql),
         persistent: true,
+        slow_statements_duration: None,
+        text_results: false,
     }
 }
 
@@ -644,5 +718,7 @@ where
         arguments: Some(arguments),
         statement: Either::Left(sql),
         persistent: true,
+        slow_statements_duration: None,
+        text_results: false,
     }
 }
@@ -1,4 +1,5 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use either::Either;
 use futures_core::stream::BoxStream;
@@ -45,6 +46,16 @@ where
     fn persistent(&self) -> bool {
         self.inner.persistent()
     }
+
+    #[inline]
+    fn slow_statements_duration(&self) -> Option<Duration> {
+        Execute::slow_statements_duration(&self.inner)
+    }
+
+    #[inline]
+    fn text_results(&self) -> bool {
+        Execute::text_results(&self.inner)
+    }
 }
 
 impl<'q, DB: Database, O> QueryAs<'q, DB, O, <DB as Database>::Arguments<'q>> {
@@ -77,6 +88,24 @@ where
     }
 }
 
+impl<'q, DB: Database, O, A> QueryAs<'q, DB, O, A> {
+    /// Log this query as slow if it takes longer than `duration`.
+    ///
+    /// See [`Query::slow_statements_duration`].
+    pub fn slow_statements_duration(mut self, duration: Duration) -> Self {
+        self.inner = self.inner.slow_statements_duration(duration);
+        self
+    }
+
+    /// Request the results in the database's text format.
+    ///
+    /// See [`Query::text_results`].
+    pub fn text_results(mut self, value: bool) -> Self {
+        self.inner = self.inner.text_results(value);
+        self
+    }
+}
+
 // FIXME: This is very close, nearly 1:1 with `Map`
 // noinspection DuplicatedCode
 impl<'q, DB, O, A> QueryAs<'q, DB, O, A>
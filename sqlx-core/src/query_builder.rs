@@ -453,6 +453,8 @@ where
             arguments: self.arguments.take(),
             database: PhantomData,
             persistent: true,
+            slow_statements_duration: None,
+            text_results: false,
         }
     }
 
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use either::Either;
 use futures_core::stream::BoxStream;
 use futures_util::{StreamExt, TryFutureExt, TryStreamExt};
@@ -42,6 +44,16 @@ where
     fn persistent(&self) -> bool {
         Execute::persistent(&self.inner)
     }
+
+    #[inline]
+    fn slow_statements_duration(&self) -> Option<Duration> {
+        Execute::slow_statements_duration(&self.inner)
+    }
+
+    #[inline]
+    fn text_results(&self) -> bool {
+        Execute::text_results(&self.inner)
+    }
 }
 
 impl<'q, DB: Database, O> QueryScalar<'q, DB, O, <DB as Database>::Arguments<'q>> {
@@ -74,6 +86,24 @@ where
     }
 }
 
+impl<'q, DB: Database, O, A> QueryScalar<'q, DB, O, A> {
+    /// Log this query as slow if it takes longer than `duration`.
+    ///
+    /// See [`Query::slow_statements_duration`](crate::query::Query::slow_statements_duration).
+    pub fn slow_statements_duration(mut self, duration: Duration) -> Self {
+        self.inner = self.inner.slow_statements_duration(duration);
+        self
+    }
+
+    /// Request the results in the database's text format.
+    ///
+    /// See [`Query::text_results`](crate::query::Query::text_results).
+    pub fn text_results(mut self, value: bool) -> Self {
+        self.inner = self.inner.text_results(value);
+        self
+    }
+}
+
 // FIXME: This is very close, nearly 1:1 with `Map`
 // noinspection DuplicatedCode
 impl<'q, DB, O, A> QueryScalar<'q, DB, O, A>
@@ -190,6 +220,36 @@ where
     }
 }
 
+impl<'q, DB, T, A> QueryScalar<'q, DB, Option<T>, A>
+where
+    DB: Database,
+    Option<T>: Send + Unpin,
+    (Option<T>,): Send + Unpin + for<'r> FromRow<'r, DB::Row>,
+    A: 'q + IntoArguments<'q, DB>,
+{
+    /// Execute the query, returning the value of the first row, or `None` if either no row was
+    /// returned or the value was `NULL`.
+    ///
+    /// This collapses the `Option<Option<T>>` that [`fetch_optional()`][Self::fetch_optional]
+    /// would return for a nullable column, for when there is no need to tell the two cases apart.
+    ///
+    /// See [`fetch_optional()`][Self::fetch_optional] for notes on performance.
+    #[inline]
+    pub async fn fetch_optional_flatten<'e, 'c: 'e, E>(
+        self,
+        executor: E,
+    ) -> Result<Option<T>, Error>
+    where
+        'q: 'e,
+        E: 'e + Executor<'c, Database = DB>,
+        DB: 'e,
+        T: 'e,
+        A: 'e,
+    {
+        Ok(self.fetch_optional(executor).await?.flatten())
+    }
+}
+
 /// Execute a single SQL query as a prepared statement (transparently cached) and extract the first
 /// column of each row.
 ///
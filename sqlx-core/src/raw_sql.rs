@@ -1,6 +1,9 @@
+use std::ops::Range;
+
 use either::Either;
 use futures_core::stream::BoxStream;
 
+use crate::connection::Connection;
 use crate::database::Database;
 use crate::executor::{Execute, Executor};
 use crate::Error;
@@ -162,6 +165,46 @@ impl<'q> RawSql<'q> {
         executor.execute_many(self)
     }
 
+    /// Split the SQL string into individual statements and execute them one at a time, in order.
+    ///
+    /// Returns a stream which gives the result of each statement. The stream stops at the first
+    /// statement which fails; statements after it are not executed.
+    ///
+    /// Unlike [`execute_many()`][Self::execute_many], this does not rely on the driver or server
+    /// accepting multiple statements in one string, which makes it suitable for running schema or
+    /// seed files. Each statement executes on its own, so they are *not* implicitly wrapped in
+    /// a single transaction; see the note on [`raw_sql()`] about autocommit.
+    ///
+    /// ### Note: statement splitting
+    /// Statements are split on semicolons (`;`), except those appearing within:
+    /// * string literals (`'a;b'`), including doubled-quote escapes,
+    /// * quoted identifiers (`"a;b"`, and `` `a;b` `` on MySQL and SQLite or `[a;b]` on SQLite),
+    /// * Postgres dollar-quoted strings (`$$a;b$$`, `$func$a;b$func$`),
+    /// * line (`-- a;b`, and `# a;b` on MySQL) and block (`/* a;b */`) comments,
+    /// * the `BEGIN ... END` body of a `CREATE TRIGGER`, `PROCEDURE`, `FUNCTION` or `EVENT`.
+    ///
+    /// Backslash escapes are only recognized where the database supports them: in MySQL strings
+    /// and Postgres `E'...'` strings. Block comments only nest on Postgres.
+    ///
+    /// Statements which are empty or only contain comments are skipped.
+    pub fn execute_all<'e, C>(
+        self,
+        conn: &'e mut C,
+    ) -> BoxStream<'e, crate::Result<<C::Database as Database>::QueryResult>>
+    where
+        'q: 'e,
+        C: Connection,
+        for<'c> &'c mut C: Executor<'c, Database = C::Database>,
+    {
+        Box::pin(try_stream! {
+            for statement in split_statements(self.0, C::Database::SQL_DIALECT) {
+                r#yield!(conn.execute(RawSql(statement)).await?);
+            }
+
+            Ok(())
+        })
+    }
+
     /// Execute the SQL string and return the generated results as a stream.
     ///
     /// If the string contains multiple statements, their results will be concatenated together.
@@ -266,3 +309,438 @@ impl<'q> RawSql<'q> {
         executor.fetch_one(self).await
     }
 }
+
+/// Split `sql` on semicolons which are not inside a string, quoted identifier, comment or the
+/// `BEGIN ... END` body of a trigger or routine.
+///
+/// Returned statements are trimmed; those which are empty or only contain comments are skipped.
+pub(crate) fn split_statements(sql: &str, dialect: SqlDialect) -> Vec<&str> {
+    let mut statements = Vec::new();
+    let mut start = 0;
+    // Whether the current statement has anything other than whitespace and comments.
+    let mut has_content = false;
+    let mut block = BlockTracker::default();
+
+    for (span, range) in SqlLexer::new(sql, dialect) {
+        match span {
+            SqlSpan::Comment => {}
+            SqlSpan::Quoted => has_content = true,
+            SqlSpan::Code => {
+                let code = &sql[range.clone()];
+                let bytes = code.as_bytes();
+                let mut i = 0;
+
+                while i < bytes.len() {
+                    match bytes[i] {
+                        b';' if !block.in_block() => {
+                            let end = range.start + i;
+
+                            if has_content {
+                                statements.push(sql[start..end].trim());
+                            }
+
+                            start = end + 1;
+                            has_content = false;
+                            block = BlockTracker::default();
+                            i += 1;
+                        }
+                        b';' => {
+                            block.semicolon();
+                            i += 1;
+                        }
+                        b if b.is_ascii_alphabetic() || b == b'_' => {
+                            let end = code[i..]
+                                .find(|c: char| {
+                                    !(c.is_ascii_alphanumeric() || c == '_' || c == '$')
+                                })
+                                .map_or(code.len(), |end| i + end);
+
+                            block.word(&code[i..end]);
+                            has_content = true;
+                            i = end;
+                        }
+                        b => {
+                            has_content |= !b.is_ascii_whitespace();
+                            i += 1;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if has_content {
+        statements.push(sql[start..].trim());
+    }
+
+    statements
+}
+
+/// Follows the `BEGIN ... END` blocks in the body of a `CREATE TRIGGER`, `PROCEDURE`, `FUNCTION`
+/// or `EVENT` statement, as the `;` inside them don't end the statement.
+#[derive(Default)]
+struct BlockTracker {
+    words: usize,
+    creates_routine: bool,
+    is_create: bool,
+    depth: usize,
+    after_end: bool,
+}
+
+impl BlockTracker {
+    fn in_block(&self) -> bool {
+        self.depth > 0
+    }
+
+    fn semicolon(&mut self) {
+        self.after_end = false;
+    }
+
+    fn word(&mut self, word: &str) {
+        let is = |keyword: &str| word.eq_ignore_ascii_case(keyword);
+
+        self.words += 1;
+
+        if self.words == 1 {
+            self.is_create = is("CREATE");
+        }
+
+        if !self.is_create {
+            return;
+        }
+
+        if std::mem::take(&mut self.after_end) {
+            // MySQL ends other compound statements with e.g. `END IF`, which didn't open a block
+            if is("IF") || is("LOOP") || is("WHILE") || is("REPEAT") {
+                self.depth += 1;
+                return;
+            }
+
+            // `END CASE` closes a `CASE` statement
+            if is("CASE") {
+                return;
+            }
+        }
+
+        if !self.creates_routine {
+            self.creates_routine =
+                is("TRIGGER") || is("PROCEDURE") || is("FUNCTION") || is("EVENT");
+        } else if is("BEGIN") || (self.in_block() && is("CASE")) {
+            self.depth += 1;
+        } else if self.in_block() && is("END") {
+            self.depth -= 1;
+            self.after_end = true;
+        }
+    }
+}
+
+/// The SQL dialect followed by [`SqlLexer`].
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SqlDialect {
+    /// Accepts the syntax of all of the below, e.g. for `Any`.
+    Generic,
+    Postgres,
+    MySql,
+    Sqlite,
+}
+
+/// The kind of a span of SQL text returned by [`SqlLexer`].
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SqlSpan {
+    /// Anything which is not one of the below, including placeholders and `;`.
+    Code,
+    /// A string literal, quoted identifier or Postgres dollar-quoted string.
+    Quoted,
+    /// A line (`--`) or block (`/* */`) comment.
+    Comment,
+}
+
+/// Splits SQL text into spans of code, quoted text and comments, so that callers can look for
+/// syntax (e.g. `;` or placeholders) only where it is meaningful.
+///
+/// Unterminated quotes and comments extend to the end of the text.
+#[doc(hidden)]
+pub struct SqlLexer<'a> {
+    sql: &'a str,
+    dialect: SqlDialect,
+    pos: usize,
+}
+
+impl<'a> SqlLexer<'a> {
+    pub fn new(sql: &'a str, dialect: SqlDialect) -> Self {
+        Self {
+            sql,
+            dialect,
+            pos: 0,
+        }
+    }
+
+    /// If a quoted span or comment starts at `i`, return its kind and end.
+    fn special_at(&self, i: usize) -> Option<(SqlSpan, usize)> {
+        let sql = self.sql;
+        let bytes = sql.as_bytes();
+        let dialect = self.dialect;
+
+        let end = match bytes[i] {
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                return Some((SqlSpan::Comment, line_end(bytes, i)));
+            }
+            b'#' if dialect == SqlDialect::MySql => {
+                return Some((SqlSpan::Comment, line_end(bytes, i)));
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                // Only Postgres allows block comments to nest.
+                let nested = matches!(dialect, SqlDialect::Generic | SqlDialect::Postgres);
+                let mut depth = 0;
+                let mut end = i;
+
+                while end < bytes.len() {
+                    if bytes[end..].starts_with(b"/*") && (nested || depth == 0) {
+                        depth += 1;
+                        end += 2;
+                    } else if bytes[end..].starts_with(b"*/") {
+                        depth -= 1;
+                        end += 2;
+
+                        if depth == 0 {
+                            break;
+                        }
+                    } else {
+                        end += 1;
+                    }
+                }
+
+                return Some((SqlSpan::Comment, end));
+            }
+            b'\'' => {
+                let escapes = match dialect {
+                    SqlDialect::Generic | SqlDialect::MySql => true,
+                    // only in escape strings, e.g. `E'a\'b'`
+                    SqlDialect::Postgres => is_escape_string_prefix(&bytes[..i]),
+                    SqlDialect::Sqlite => false,
+                };
+
+                quote_end(bytes, i, b'\'', escapes)
+            }
+            b'"' => quote_end(bytes, i, b'"', dialect == SqlDialect::MySql),
+            b'`' if dialect != SqlDialect::Postgres => quote_end(bytes, i, b'`', false),
+            b'[' if dialect == SqlDialect::Sqlite => bytes[i..]
+                .iter()
+                .position(|&b| b == b']')
+                .map_or(bytes.len(), |end| i + end + 1),
+            b'$' if matches!(dialect, SqlDialect::Generic | SqlDialect::Postgres) => {
+                let tag = dollar_quote_tag(&sql[i..])?;
+                let body = i + tag.len();
+
+                sql[body..]
+                    .find(tag)
+                    .map_or(bytes.len(), |end| body + end + tag.len())
+            }
+            _ => return None,
+        };
+
+        Some((SqlSpan::Quoted, end))
+    }
+}
+
+impl Iterator for SqlLexer<'_> {
+    type Item = (SqlSpan, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.pos;
+
+        if start >= self.sql.len() {
+            return None;
+        }
+
+        if let Some((span, end)) = self.special_at(start) {
+            self.pos = end;
+            return Some((span, start..end));
+        }
+
+        let mut end = start + 1;
+
+        while end < self.sql.len() && self.special_at(end).is_none() {
+            end += 1;
+        }
+
+        self.pos = end;
+        Some((SqlSpan::Code, start..end))
+    }
+}
+
+/// Whether a string literal after `before` is a Postgres escape string (`E'...'`).
+fn is_escape_string_prefix(before: &[u8]) -> bool {
+    match before {
+        [.., prev, b'e' | b'E'] => !(prev.is_ascii_alphanumeric() || *prev == b'_'),
+        [b'e' | b'E'] => true,
+        _ => false,
+    }
+}
+
+/// Return the end of the line comment starting at `i`, including the newline.
+fn line_end(bytes: &[u8], i: usize) -> usize {
+    bytes[i..]
+        .iter()
+        .position(|&b| b == b'\n')
+        .map_or(bytes.len(), |end| i + end + 1)
+}
+
+/// Return the end of the string or quoted identifier starting at `i`.
+///
+/// A doubled quote is always an escaped quote; with `escapes`, so is one after a backslash.
+fn quote_end(bytes: &[u8], i: usize, quote: u8, escapes: bool) -> usize {
+    let mut end = i + 1;
+
+    while end < bytes.len() {
+        if bytes[end] == b'\\' && escapes {
+            end += 2;
+        } else if bytes[end] == quote {
+            end += 1;
+
+            if bytes.get(end) != Some(&quote) {
+                return end;
+            }
+
+            end += 1;
+        } else {
+            end += 1;
+        }
+    }
+
+    // an escape may have skipped past the end
+    bytes.len()
+}
+
+/// If `sql` starts with a Postgres dollar-quote tag (`$$` or `$tag$`), return it.
+///
+/// The tag can't start with a digit, which distinguishes it from a positional parameter (`$1`).
+fn dollar_quote_tag(sql: &str) -> Option<&str> {
+    let rest = sql.strip_prefix('$')?;
+
+    if rest.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let end = rest.find(|c: char| !(c.is_alphanumeric() || c == '_'))?;
+
+    rest[end..].starts_with('$').then(|| &sql[..end + 2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{split_statements, SqlDialect};
+
+    #[test]
+    fn it_splits_statements() {
+        assert_eq!(
+            split_statements("SELECT 1; SELECT 2;\n\nSELECT 3", SqlDialect::Generic),
+            ["SELECT 1", "SELECT 2", "SELECT 3"]
+        );
+    }
+
+    #[test]
+    fn it_skips_empty_statements() {
+        assert_eq!(
+            split_statements(
+                ";; SELECT 1; -- trailing comment\n /* another; */ ;",
+                SqlDialect::Generic
+            ),
+            ["SELECT 1"]
+        );
+    }
+
+    #[test]
+    fn it_does_not_split_inside_strings() {
+        assert_eq!(
+            split_statements(
+                r#"INSERT INTO t VALUES ('a;b', 'it''s;', 'c\';d'); SELECT "x;y", `z;w`"#,
+                SqlDialect::MySql
+            ),
+            [
+                r#"INSERT INTO t VALUES ('a;b', 'it''s;', 'c\';d')"#,
+                r#"SELECT "x;y", `z;w`"#
+            ]
+        );
+    }
+
+    #[test]
+    fn it_does_not_split_inside_comments() {
+        assert_eq!(
+            split_statements(
+                "SELECT 1 -- a;b\n; SELECT /* c; /* nested; */ d; */ 2",
+                SqlDialect::Postgres
+            ),
+            ["SELECT 1 -- a;b", "SELECT /* c; /* nested; */ d; */ 2"]
+        );
+    }
+
+    #[test]
+    fn it_does_not_split_inside_dollar_quotes() {
+        let function = "CREATE FUNCTION f() RETURNS int AS $func$ \
+            BEGIN SELECT 1; RETURN $$;$$; END \
+        $func$ LANGUAGE plpgsql";
+
+        assert_eq!(
+            split_statements(
+                &format!("{function}; SELECT $1, $$a;b$$"),
+                SqlDialect::Postgres
+            ),
+            [function, "SELECT $1, $$a;b$$"]
+        );
+    }
+
+    #[test]
+    fn it_follows_the_dialect() {
+        // backslashes only escape in `E''` strings
+        assert_eq!(
+            split_statements(r"SELECT 'a\'; SELECT E'b\';c'", SqlDialect::Postgres),
+            [r"SELECT 'a\'", r"SELECT E'b\';c'"]
+        );
+
+        // block comments don't nest, and `[]` quotes identifiers
+        assert_eq!(
+            split_statements("SELECT /* a /* b */ 1; SELECT [c;d]", SqlDialect::Sqlite),
+            ["SELECT /* a /* b */ 1", "SELECT [c;d]"]
+        );
+        assert_eq!(
+            split_statements(r"SELECT 'e\'; SELECT 2", SqlDialect::Sqlite),
+            [r"SELECT 'e\'", "SELECT 2"]
+        );
+
+        // `#` starts a comment, and backslashes escape in double-quoted strings
+        assert_eq!(
+            split_statements("SELECT 1 # a;b\n; SELECT \"c\\\";d\"", SqlDialect::MySql),
+            ["SELECT 1 # a;b", "SELECT \"c\\\";d\""]
+        );
+    }
+
+    #[test]
+    fn it_does_not_split_inside_trigger_bodies() {
+        let trigger = "CREATE TRIGGER log_insert AFTER INSERT ON t BEGIN \
+            INSERT INTO log VALUES (CASE WHEN new.x > 0 THEN 'a' ELSE 'b' END); \
+            UPDATE t SET y = 1; \
+        END";
+
+        assert_eq!(
+            split_statements(
+                &format!("{trigger}; BEGIN; INSERT INTO t VALUES (1); COMMIT;"),
+                SqlDialect::Sqlite
+            ),
+            [trigger, "BEGIN", "INSERT INTO t VALUES (1)", "COMMIT"]
+        );
+
+        let procedure = "CREATE PROCEDURE p(IN n INT) BEGIN \
+            IF n > 0 THEN SELECT 1; END IF; \
+            CASE n WHEN 1 THEN SELECT 2; ELSE BEGIN END; END CASE; \
+            SELECT CASE WHEN n = 0 THEN 3 END; \
+        END";
+
+        assert_eq!(
+            split_statements(&format!("{procedure}; CALL p(1)"), SqlDialect::MySql),
+            [procedure, "CALL p(1)"]
+        );
+    }
+}
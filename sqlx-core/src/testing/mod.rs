@@ -41,6 +41,16 @@ pub trait TestSupport: Database {
     /// This snapshot can then be used to generate test fixtures.
     fn snapshot(conn: &mut Self::Connection)
         -> BoxFuture<'_, Result<FixtureSnapshot<Self>, Error>>;
+
+    /// Get the options of the connection which applies migrations and fixtures before the test.
+    ///
+    /// Fixtures are scripts that usually contain more than one statement, so drivers which
+    /// reject those by default may enable them here.
+    fn setup_connect_options(
+        opts: &<Self::Connection as Connection>::Options,
+    ) -> <Self::Connection as Connection>::Options {
+        opts.clone()
+    }
 }
 
 pub struct TestFixture {
@@ -229,14 +239,14 @@ where
     })
 }
 
-async fn setup_test_db<DB: Database>(
+async fn setup_test_db<DB: TestSupport>(
     copts: &<DB::Connection as Connection>::Options,
     args: &TestArgs,
 ) where
     DB::Connection: Migrate + Sized,
     for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
 {
-    let mut conn = copts
+    let mut conn = DB::setup_connect_options(copts)
         .connect()
         .await
         .expect("failed to connect to test database");
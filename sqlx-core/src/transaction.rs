@@ -1,11 +1,12 @@
 use std::borrow::Cow;
 use std::fmt::{self, Debug, Formatter};
 use std::ops::{Deref, DerefMut};
+use std::time::Duration;
 
 use futures_core::future::BoxFuture;
 
 use crate::database::Database;
-use crate::error::Error;
+use crate::error::{Error, ErrorKind};
 use crate::pool::MaybePoolConnection;
 
 /// Generic management of database transactions.
@@ -20,6 +21,24 @@ pub trait TransactionManager {
         conn: &mut <Self::Database as Database>::Connection,
     ) -> BoxFuture<'_, Result<(), Error>>;
 
+    /// Begin a new transaction with the given options.
+    ///
+    /// Returns an error without touching the connection if the database doesn't support the
+    /// options, or if a transaction is already active and the options are not the default.
+    ///
+    /// The default implementation only supports the default options, which it forwards to
+    /// [`begin()`][Self::begin()].
+    fn begin_with(
+        conn: &mut <Self::Database as Database>::Connection,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<(), Error>> {
+        if !options.is_default() {
+            return Box::pin(async { Err(unsupported_transaction_options()) });
+        }
+
+        Self::begin(conn)
+    }
+
     /// Commit the active transaction or release the most recent savepoint.
     fn commit(
         conn: &mut <Self::Database as Database>::Connection,
@@ -32,6 +51,196 @@ pub trait TransactionManager {
 
     /// Starts to abort the active transaction or restore from the most recent snapshot.
     fn start_rollback(conn: &mut <Self::Database as Database>::Connection);
+
+    /// The status of the active transaction, as far as the driver can tell.
+    ///
+    /// The default implementation can't tell, and always returns [`TransactionStatus::Active`].
+    fn transaction_status(_conn: &<Self::Database as Database>::Connection) -> TransactionStatus {
+        TransactionStatus::Active
+    }
+
+    /// The number of statements run on this connection that may have written to the database,
+    /// or `None` if the driver doesn't keep count.
+    ///
+    /// The default implementation returns `None`, so every transaction counts as dirty.
+    fn write_count(_conn: &<Self::Database as Database>::Connection) -> Option<u64> {
+        None
+    }
+}
+
+/// Options for beginning a transaction with [`Connection::begin_with()`].
+///
+/// The default leaves everything up to the database, same as [`Connection::begin()`].
+///
+/// ```rust,ignore
+/// use sqlx::{IsolationLevel, TransactionOptions};
+///
+/// let mut tx = conn
+///     .begin_with(TransactionOptions {
+///         isolation: Some(IsolationLevel::Serializable),
+///         read_only: true,
+///         ..Default::default()
+///     })
+///     .await?;
+/// ```
+///
+/// Not every database supports every option; unsupported combinations are rejected before
+/// anything is sent to the database:
+///
+/// | Option       | Postgres                       | MySQL                          | SQLite                      |
+/// |--------------|--------------------------------|--------------------------------|-----------------------------|
+/// | `isolation`  | all levels                     | all levels                     | only `Serializable`         |
+/// | `read_only`  | `BEGIN ... READ ONLY`          | `START TRANSACTION READ ONLY`  | not supported               |
+/// | `deferrable` | `BEGIN ... DEFERRABLE`         | not supported                  | not supported               |
+///
+/// Options can't be applied to a savepoint, so they are rejected for a transaction that is
+/// begun inside another one (SQLite excepted, where the only accepted options are no-ops).
+///
+/// [`Connection::begin_with()`]: crate::connection::Connection::begin_with()
+/// [`Connection::begin()`]: crate::connection::Connection::begin()
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransactionOptions {
+    /// The isolation level of the transaction, or `None` for the database or session default.
+    pub isolation: Option<IsolationLevel>,
+
+    /// Start the transaction in read-only mode.
+    pub read_only: bool,
+
+    /// Postgres only: a `SERIALIZABLE READ ONLY` transaction may block when it starts, so it
+    /// can then run without the risk of a serialization failure.
+    pub deferrable: bool,
+}
+
+impl TransactionOptions {
+    /// Returns `true` if these are the default options, i.e. a plain `BEGIN`.
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// How [`Pool::transaction_retry_with()`] retries a transaction.
+///
+/// ```rust,ignore
+/// use std::time::Duration;
+/// use sqlx::{IsolationLevel, TransactionOptions, TransactionRetry};
+///
+/// let retry = TransactionRetry::new(5)
+///     .options(TransactionOptions {
+///         isolation: Some(IsolationLevel::Serializable),
+///         ..Default::default()
+///     })
+///     .backoff(Duration::from_millis(50), Duration::from_secs(2));
+///
+/// let balance = pool
+///     .transaction_retry_with(retry, |tx| Box::pin(async move { transfer(tx).await }))
+///     .await?;
+/// ```
+///
+/// [`Pool::transaction_retry_with()`]: crate::pool::Pool::transaction_retry_with()
+#[derive(Debug, Clone)]
+pub struct TransactionRetry {
+    pub(crate) max_retries: u32,
+    pub(crate) retryable_codes: Option<Vec<Cow<'static, str>>>,
+    pub(crate) initial_backoff: Duration,
+    pub(crate) max_backoff: Duration,
+    pub(crate) options: TransactionOptions,
+}
+
+impl TransactionRetry {
+    /// Retry up to `max_retries` times, i.e. run the transaction at most `max_retries + 1` times.
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            retryable_codes: None,
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_secs(1),
+            options: TransactionOptions::default(),
+        }
+    }
+
+    /// Set the [error codes][crate::error::DatabaseError::code] to retry on.
+    ///
+    /// Defaults to the errors reported by the database whose [kind][Error::kind] is
+    /// [`Serialization`][ErrorKind::Serialization], [`Deadlock`][ErrorKind::Deadlock] or
+    /// [`Timeout`][ErrorKind::Timeout], i.e. those [`Error::is_retryable()`] returns `true` for,
+    /// except for lost connections, as a lost `COMMIT` may have been applied.
+    pub fn retryable_codes<I>(mut self, codes: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Cow<'static, str>>,
+    {
+        self.retryable_codes = Some(codes.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the delay before the first retry, which doubles with every retry up to `max`.
+    ///
+    /// Defaults to 10 milliseconds, up to 1 second.
+    pub fn backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// Set the options to begin each attempt with, see [`TransactionOptions`].
+    pub fn options(mut self, options: TransactionOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub(crate) fn is_retryable(&self, error: &Error) -> bool {
+        let Error::Database(error) = error else {
+            return false;
+        };
+
+        match &self.retryable_codes {
+            Some(codes) => error.code().is_some_and(|code| codes.contains(&code)),
+            None => matches!(
+                error.kind(),
+                ErrorKind::Serialization | ErrorKind::Deadlock | ErrorKind::Timeout
+            ),
+        }
+    }
+}
+
+/// The SQL standard transaction isolation levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
+impl IsolationLevel {
+    /// The level as it is spelled in SQL, e.g. `REPEATABLE READ`.
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            IsolationLevel::ReadUncommitted => "READ UNCOMMITTED",
+            IsolationLevel::ReadCommitted => "READ COMMITTED",
+            IsolationLevel::RepeatableRead => "REPEATABLE READ",
+            IsolationLevel::Serializable => "SERIALIZABLE",
+        }
+    }
+}
+
+/// The status of a [`Transaction`], as returned by [`Transaction::status()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionStatus {
+    /// The transaction is open and can be used.
+    Active,
+
+    /// A statement failed and the database will not run any more statements in this
+    /// transaction; it has to be rolled back (or rolled back to a savepoint) first.
+    ///
+    /// Postgres reports this state directly. MySQL and SQLite can't, so it's inferred from the
+    /// last error: a MySQL deadlock or a SQLite error that made SQLite roll back the transaction
+    /// on its own.
+    Aborted,
+
+    /// The transaction was committed or rolled back, or the database reports that no
+    /// transaction is open anymore.
+    Closed,
 }
 
 /// An in-progress database transaction or savepoint.
@@ -46,6 +255,35 @@ pub trait TransactionManager {
 /// executed after it was established to be rolled back, restoring the transaction state to
 /// what it was at the time of the savepoint.
 ///
+/// # Nested transactions
+///
+/// Calling `begin()` on a `Transaction` (which dereferences to the connection) establishes a
+/// savepoint instead of starting a new transaction. This works the same way on Postgres, MySQL
+/// and SQLite:
+///
+/// | Depth | `begin()`                     | `commit()`                            | `rollback()`                                  |
+/// |-------|-------------------------------|---------------------------------------|-----------------------------------------------|
+/// | 1     | `BEGIN`                       | `COMMIT`                              | `ROLLBACK`                                    |
+/// | N > 1 | `SAVEPOINT _sqlx_savepoint_N` | `RELEASE SAVEPOINT _sqlx_savepoint_N` | `ROLLBACK TO SAVEPOINT _sqlx_savepoint_N`     |
+///
+/// Rolling back a savepoint (including by dropping it) only undoes what was done since it was
+/// established; the outer transaction stays open and can still be committed:
+///
+/// ```rust,ignore
+/// let mut tx = conn.begin().await?;
+/// sqlx::query("INSERT INTO users (id) VALUES (1)").execute(&mut *tx).await?;
+///
+/// let mut savepoint = tx.begin().await?;
+/// sqlx::query("INSERT INTO users (id) VALUES (2)").execute(&mut *savepoint).await?;
+/// savepoint.rollback().await?;
+///
+/// // only user 1 is inserted
+/// tx.commit().await?;
+/// ```
+///
+/// Committing a savepoint doesn't make its changes visible to others; that only happens once
+/// the outermost transaction is committed.
+///
 /// [`Connection::begin`]: crate::connection::Connection::begin()
 /// [`Pool::begin`]: crate::pool::Pool::begin()
 /// [`commit`]: Self::commit()
@@ -56,6 +294,7 @@ where
 {
     connection: MaybePoolConnection<'c, DB>,
     open: bool,
+    write_count: Option<u64>,
 }
 
 impl<'c, DB> Transaction<'c, DB>
@@ -72,6 +311,25 @@ where
             DB::TransactionManager::begin(&mut conn).await?;
 
             Ok(Self {
+                write_count: DB::TransactionManager::write_count(&conn),
+                connection: conn,
+                open: true,
+            })
+        })
+    }
+
+    #[doc(hidden)]
+    pub fn begin_with(
+        conn: impl Into<MaybePoolConnection<'c, DB>>,
+        options: TransactionOptions,
+    ) -> BoxFuture<'c, Result<Self, Error>> {
+        let mut conn = conn.into();
+
+        Box::pin(async move {
+            DB::TransactionManager::begin_with(&mut conn, options).await?;
+
+            Ok(Self {
+                write_count: DB::TransactionManager::write_count(&conn),
                 connection: conn,
                 open: true,
             })
@@ -93,6 +351,65 @@ where
 
         Ok(())
     }
+
+    /// Returns `true` if this transaction or savepoint has not been committed or rolled back yet.
+    ///
+    /// This only reflects what was done through this `Transaction`; see [`status()`][Self::status]
+    /// to also account for what the database reports.
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Returns `true` if a statement that may have written to the database was run in this
+    /// transaction or savepoint, e.g. to skip emitting change events for a transaction that
+    /// only read.
+    ///
+    /// This is conservative: anything that isn't known to be read-only counts as a write,
+    /// including statements that failed. SQLite asks the database whether each statement is
+    /// read-only. Postgres and MySQL only look at the SQL: a single `SELECT`, `SHOW`, `VALUES`,
+    /// `TABLE` or `DESCRIBE` statement is read-only, unless it has an `INTO` clause or calls a
+    /// function other than common built-ins such as `count()` or `coalesce()`. Drivers that
+    /// don't track writes at all report every transaction as dirty.
+    ///
+    /// Writes in a savepoint also mark the transactions it is nested in as dirty, even if the
+    /// savepoint was rolled back.
+    pub fn was_dirty(&self) -> bool {
+        match (
+            self.write_count,
+            DB::TransactionManager::write_count(&self.connection),
+        ) {
+            (Some(at_begin), Some(now)) => at_begin != now,
+            _ => true,
+        }
+    }
+
+    /// Returns the status of this transaction or savepoint.
+    ///
+    /// After a failed statement, this tells whether the transaction can still be used or has to
+    /// be rolled back first, instead of running into a cascade of errors:
+    ///
+    /// ```rust,ignore
+    /// use sqlx::TransactionStatus;
+    ///
+    /// let mut tx = conn.begin().await?;
+    ///
+    /// if let Err(e) = sqlx::query("INSERT INTO users (id) VALUES (1)").execute(&mut *tx).await {
+    ///     if tx.status() == TransactionStatus::Aborted {
+    ///         tx.rollback().await?;
+    ///         return Err(e.into());
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// The status of a savepoint is that of the whole transaction: a failed statement in a
+    /// savepoint aborts the outer transaction as well, until the savepoint is rolled back.
+    pub fn status(&self) -> TransactionStatus {
+        if !self.open {
+            return TransactionStatus::Closed;
+        }
+
+        DB::TransactionManager::transaction_status(&self.connection)
+    }
 }
 
 // NOTE: fails to compile due to lack of lazy normalization
@@ -248,6 +565,153 @@ pub fn begin_ansi_transaction_sql(depth: usize) -> Cow<'static, str> {
     }
 }
 
+/// Returns an error if `options` can't be used at the given transaction depth.
+pub fn check_transaction_options(depth: usize, options: &TransactionOptions) -> Result<(), Error> {
+    if depth > 0 && !options.is_default() {
+        return Err(Error::Configuration(
+            "transaction options cannot be set for a savepoint inside another transaction".into(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// The error returned by the default [`TransactionManager::begin_with()`].
+pub(crate) fn unsupported_transaction_options() -> Error {
+    Error::Configuration("this database driver does not support transaction options".into())
+}
+
+/// Returns `true` if `sql` is a single statement that is known not to write to the database.
+///
+/// Used by drivers that can't ask the database, see [`Transaction::was_dirty()`].
+pub fn is_read_only_sql(sql: &str) -> bool {
+    let sql = crate::logger::strip_leading_comments(sql);
+
+    // more than one statement
+    if sql.trim_end().trim_end_matches(';').contains(';') {
+        return false;
+    }
+
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+    let mut rest = sql;
+    let mut first = true;
+
+    while let Some(start) = rest.find(is_word_char) {
+        let end = rest[start..]
+            .find(|c: char| !is_word_char(c))
+            .map_or(rest.len(), |end| start + end);
+        let word = &rest[start..end];
+        rest = &rest[end..];
+
+        if first {
+            first = false;
+
+            if !READ_ONLY_STATEMENTS
+                .iter()
+                .any(|keyword| word.eq_ignore_ascii_case(keyword))
+            {
+                return false;
+            }
+        }
+
+        // `SELECT ... INTO` creates a table in Postgres, or writes to a file or variables in MySQL
+        if word.eq_ignore_ascii_case("into") {
+            return false;
+        }
+
+        // any other function may write, e.g. `nextval()`
+        if rest.trim_start().starts_with('(')
+            && !READ_ONLY_KEYWORDS
+                .iter()
+                .chain(READ_ONLY_FUNCTIONS)
+                .any(|keyword| word.eq_ignore_ascii_case(keyword))
+        {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// The statements that [`is_read_only_sql()`] may consider read-only.
+const READ_ONLY_STATEMENTS: &[&str] = &["select", "show", "values", "table", "describe", "desc"];
+
+/// The keywords that [`is_read_only_sql()`] allows before a `(`.
+const READ_ONLY_KEYWORDS: &[&str] = &[
+    "select",
+    "from",
+    "join",
+    "where",
+    "and",
+    "or",
+    "not",
+    "in",
+    "exists",
+    "any",
+    "all",
+    "some",
+    "as",
+    "on",
+    "using",
+    "values",
+    "over",
+    "filter",
+    "within",
+    "lateral",
+    "when",
+    "then",
+    "else",
+    "by",
+    "having",
+    "union",
+    "intersect",
+    "except",
+    "limit",
+    "offset",
+    "row",
+    "array",
+    "cast",
+];
+
+/// The built-in functions that [`is_read_only_sql()`] knows don't write.
+const READ_ONLY_FUNCTIONS: &[&str] = &[
+    "count",
+    "sum",
+    "avg",
+    "min",
+    "max",
+    "coalesce",
+    "nullif",
+    "greatest",
+    "least",
+    "ifnull",
+    "lower",
+    "upper",
+    "length",
+    "char_length",
+    "substring",
+    "substr",
+    "concat",
+    "trim",
+    "replace",
+    "abs",
+    "round",
+    "floor",
+    "ceil",
+    "now",
+    "date",
+    "extract",
+    "array_agg",
+    "string_agg",
+    "json_agg",
+    "jsonb_agg",
+    "to_json",
+    "to_jsonb",
+    "row_number",
+    "rank",
+    "dense_rank",
+];
+
 pub fn commit_ansi_transaction_sql(depth: usize) -> Cow<'static, str> {
     if depth == 1 {
         Cow::Borrowed("COMMIT")
@@ -266,3 +730,38 @@ pub fn rollback_ansi_transaction_sql(depth: usize) -> Cow<'static, str> {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::is_read_only_sql;
+
+    #[test]
+    fn it_detects_read_only_sql() {
+        for sql in [
+            "SELECT 1",
+            "  select * from users where id = $1;",
+            "-- comment\n/* block */ SELECT 1",
+            "SHOW server_version",
+            "VALUES (1), (2)",
+            "SELECT count(*), coalesce(max(id), 0) FROM users WHERE id IN (1, 2)",
+            "",
+        ] {
+            assert!(is_read_only_sql(sql), "{sql:?}");
+        }
+
+        for sql in [
+            "INSERT INTO users (id) VALUES (1)",
+            "SELECT * INTO backup FROM users",
+            "SELECT 1; DELETE FROM users",
+            "WITH deleted AS (DELETE FROM users RETURNING *) SELECT * FROM deleted",
+            "EXPLAIN ANALYZE DELETE FROM users",
+            "SELECT id FROM users INTO OUTFILE '/tmp/users'",
+            "SELECT id INTO @id FROM users",
+            "SELECT nextval('users_id_seq')",
+            "SELECT * FROM users WHERE id = archive_user (1)",
+            "VALUES (setval('users_id_seq', 1))",
+        ] {
+            assert!(!is_read_only_sql(sql), "{sql:?}");
+        }
+    }
+}
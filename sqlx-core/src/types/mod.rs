@@ -178,6 +178,11 @@ pub use text::Text;
 /// enum Color { Red, Green, Blue }
 /// ```
 ///
+/// For enums matched by variant name, add `#[sqlx(pg_array)]` to also implement `PgHasArrayType`
+/// so that e.g. `Vec<Color>` maps to `color[]` (and `Vec<Option<Color>>` to an array containing
+/// `NULL`s). It is not implemented by default, as that would conflict with existing hand-written
+/// impls.
+///
 /// ### Records
 ///
 /// User-defined composite types are supported through deriving a `struct`.
@@ -194,6 +199,8 @@ pub use text::Text;
 /// }
 /// ```
 ///
+/// Add `#[sqlx(pg_array)]` to also implement `PgHasArrayType` for arrays of the composite type.
+///
 pub trait Type<DB: Database> {
     /// Returns the canonical SQL type for this Rust type.
     ///
@@ -2,11 +2,14 @@ macro_rules! impl_database_ext {
     (
         $database:path,
         row: $row:path,
+        placeholder: $placeholder:ident,
         $(describe-blocking: $describe:path,)?
     ) => {
         impl $crate::database::DatabaseExt for $database {
             const DATABASE_PATH: &'static str = stringify!($database);
             const ROW_PATH: &'static str = stringify!($row);
+            const PLACEHOLDER_STYLE: $crate::database::PlaceholderStyle =
+                $crate::database::PlaceholderStyle::$placeholder;
             impl_describe_blocking!($database, $($describe)?);
         }
     }
@@ -53,18 +56,21 @@ mod sqlx {
 impl_database_ext! {
     sqlx::mysql::MySql,
     row: sqlx::mysql::MySqlRow,
+    placeholder: Question,
 }
 
 #[cfg(feature = "postgres")]
 impl_database_ext! {
     sqlx::postgres::Postgres,
     row: sqlx::postgres::PgRow,
+    placeholder: Dollar,
 }
 
 #[cfg(feature = "sqlite")]
 impl_database_ext! {
     sqlx::sqlite::Sqlite,
     row: sqlx::sqlite::SqliteRow,
+    placeholder: QuestionNumbered,
     // Since proc-macros don't benefit from async, we can make a describe call directly
     // which also ensures that the database is closed afterwards, regardless of errors.
     describe-blocking: sqlx_sqlite::describe_blocking,
@@ -16,6 +16,7 @@ mod impls;
 pub trait DatabaseExt: Database + TypeChecking {
     const DATABASE_PATH: &'static str;
     const ROW_PATH: &'static str;
+    const PLACEHOLDER_STYLE: PlaceholderStyle;
 
     fn db_path() -> syn::Path {
         syn::parse_str(Self::DATABASE_PATH).unwrap()
@@ -28,6 +29,18 @@ pub trait DatabaseExt: Database + TypeChecking {
     fn describe_blocking(query: &str, database_url: &str) -> sqlx_core::Result<Describe<Self>>;
 }
 
+/// How a driver expects bind parameters to be written in SQL.
+#[allow(dead_code)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PlaceholderStyle {
+    /// `$1`, `$2`, ...; a number may be referenced more than once.
+    Dollar,
+    /// `?1`, `?2`, ...; a number may be referenced more than once.
+    QuestionNumbered,
+    /// `?`; each occurrence binds the next argument.
+    Question,
+}
+
 #[allow(dead_code)]
 pub struct CachingDescribeBlocking<DB: DatabaseExt> {
     connections: Lazy<Mutex<HashMap<String, DB::Connection>>>,
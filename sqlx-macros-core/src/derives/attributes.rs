@@ -57,12 +57,16 @@ pub struct SqlxContainerAttributes {
     pub rename_all: Option<RenameAll>,
     pub repr: Option<Ident>,
     pub no_pg_array: bool,
+    pub pg_array: bool,
     pub default: bool,
+    pub default_on_null: bool,
+    pub discriminant: Option<String>,
 }
 
 pub struct SqlxChildAttributes {
     pub rename: Option<String>,
     pub default: bool,
+    pub default_on_null: bool,
     pub flatten: bool,
     pub try_from: Option<Type>,
     pub skip: bool,
@@ -75,7 +79,10 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
     let mut type_name = None;
     let mut rename_all = None;
     let mut no_pg_array = None;
+    let mut pg_array = None;
     let mut default = None;
+    let mut default_on_null = None;
+    let mut discriminant = None;
 
     for attr in input {
         if attr.path().is_ident("sqlx") {
@@ -84,8 +91,12 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
                     try_set!(transparent, true, attr);
                 } else if meta.path.is_ident("no_pg_array") {
                     try_set!(no_pg_array, true, attr);
+                } else if meta.path.is_ident("pg_array") {
+                    try_set!(pg_array, true, attr);
                 } else if meta.path.is_ident("default") {
                     try_set!(default, true, attr);
+                } else if meta.path.is_ident("default_on_null") {
+                    try_set!(default_on_null, true, attr);
                 } else if meta.path.is_ident("rename_all") {
                     meta.input.parse::<Token![=]>()?;
                     let lit: LitStr = meta.input.parse()?;
@@ -111,6 +122,11 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
                     };
 
                     try_set!(type_name, name, lit)
+                } else if meta.path.is_ident("discriminant") {
+                    meta.input.parse::<Token![=]>()?;
+                    let lit: LitStr = meta.input.parse()?;
+
+                    try_set!(discriminant, lit.value(), lit)
                 } else {
                     fail!(meta.path, "unexpected attribute")
                 }
@@ -133,13 +149,17 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
         type_name,
         rename_all,
         no_pg_array: no_pg_array.unwrap_or(false),
+        pg_array: pg_array.unwrap_or(false),
         default: default.unwrap_or(false),
+        default_on_null: default_on_null.unwrap_or(false),
+        discriminant,
     })
 }
 
 pub fn parse_child_attributes(input: &[Attribute]) -> syn::Result<SqlxChildAttributes> {
     let mut rename = None;
     let mut default = false;
+    let mut default_on_null = false;
     let mut try_from = None;
     let mut flatten = false;
     let mut skip: bool = false;
@@ -157,6 +177,8 @@ pub fn parse_child_attributes(input: &[Attribute]) -> syn::Result<SqlxChildAttri
                 try_set!(try_from, val.parse()?, val);
             } else if meta.path.is_ident("default") {
                 default = true;
+            } else if meta.path.is_ident("default_on_null") {
+                default_on_null = true;
             } else if meta.path.is_ident("flatten") {
                 flatten = true;
             } else if meta.path.is_ident("skip") {
@@ -179,6 +201,7 @@ pub fn parse_child_attributes(input: &[Attribute]) -> syn::Result<SqlxChildAttri
     Ok(SqlxChildAttributes {
         rename,
         default,
+        default_on_null,
         flatten,
         try_from,
         skip,
@@ -198,6 +221,12 @@ pub fn check_transparent_attributes(
         field
     );
 
+    assert_attribute!(
+        !attributes.pg_array,
+        "unexpected #[sqlx(pg_array)]; transparent types implement `PgHasArrayType` unless #[sqlx(no_pg_array)] is given",
+        input
+    );
+
     let ch_attributes = parse_child_attributes(&field.attrs)?;
 
     assert_attribute!(
@@ -220,7 +249,7 @@ pub fn check_enum_attributes(input: &DeriveInput) -> syn::Result<SqlxContainerAt
 
     assert_attribute!(
         !attributes.no_pg_array,
-        "unused #[sqlx(no_pg_array)]; derive does not emit `PgHasArrayType` impls for enums",
+        "unused #[sqlx(no_pg_array)]; derive does not emit `PgHasArrayType` impls for enums unless #[sqlx(pg_array)] is given",
         input
     );
 
@@ -235,6 +264,12 @@ pub fn check_weak_enum_attributes(
 
     assert_attribute!(attributes.repr.is_some(), "expected #[repr(..)]", input);
 
+    assert_attribute!(
+        !attributes.pg_array,
+        "unexpected #[sqlx(pg_array)]; derive does not emit `PgHasArrayType` impls for `#[repr(..)]` enums",
+        input
+    );
+
     assert_attribute!(
         attributes.rename_all.is_none(),
         "unexpected #[sqlx(c = ..)]",
@@ -285,7 +320,7 @@ pub fn check_struct_attributes<'a>(
 
     assert_attribute!(
         !attributes.no_pg_array,
-        "unused #[sqlx(no_pg_array)]; derive does not emit `PgHasArrayType` impls for custom structs",
+        "unused #[sqlx(no_pg_array)]; derive does not emit `PgHasArrayType` impls for custom structs unless #[sqlx(pg_array)] is given",
         input
     );
 
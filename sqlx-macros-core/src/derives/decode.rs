@@ -315,6 +315,8 @@ fn expand_derive_decode_struct(
 
                     #(#reads)*
 
+                    decoder.finish()?;
+
                     ::std::result::Result::Ok(#ident {
                         #(#names),*
                     })
@@ -1,12 +1,12 @@
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::{
-    parse_quote, punctuated::Punctuated, token::Comma, Data, DataStruct, DeriveInput, Expr, Field,
-    Fields, FieldsNamed, FieldsUnnamed, Lifetime, Stmt,
+    parse_quote, punctuated::Punctuated, token::Comma, Data, DataEnum, DataStruct, DeriveInput,
+    Expr, Field, Fields, FieldsNamed, FieldsUnnamed, Lifetime, Stmt, Variant, WherePredicate,
 };
 
 use super::{
-    attributes::{parse_child_attributes, parse_container_attributes},
+    attributes::{parse_child_attributes, parse_container_attributes, RenameAll},
     rename_all,
 };
 
@@ -30,7 +30,7 @@ pub fn expand_derive_from_row(input: &DeriveInput) -> syn::Result<TokenStream> {
             "unit structs are not supported",
         )),
 
-        Data::Enum(_) => Err(syn::Error::new_spanned(input, "enums are not supported")),
+        Data::Enum(DataEnum { variants, .. }) => expand_derive_from_row_enum(input, variants),
 
         Data::Union(_) => Err(syn::Error::new_spanned(input, "unions are not supported")),
     }
@@ -76,7 +76,47 @@ fn expand_derive_from_row_struct(
         default_instance = None;
     }
 
-    let reads: Vec<Stmt> = fields
+    let reads = expand_field_reads(
+        fields,
+        container_attributes.rename_all,
+        container_attributes.default,
+        container_attributes.default_on_null,
+        &lifetime,
+        predicates,
+    );
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    let names = fields.iter().map(|field| &field.ident);
+
+    Ok(quote!(
+        #[automatically_derived]
+        impl #impl_generics ::sqlx::FromRow<#lifetime, R> for #ident #ty_generics #where_clause {
+            fn from_row(row: &#lifetime R) -> ::sqlx::Result<Self> {
+                #default_instance
+
+                #(#reads)*
+
+                ::std::result::Result::Ok(#ident {
+                    #(#names),*
+                })
+            }
+        }
+    ))
+}
+
+/// Expand a `let` statement reading each named field from `row`.
+///
+/// If `container_default` is set, a `__default` instance must be in scope.
+fn expand_field_reads(
+    fields: &Punctuated<Field, Comma>,
+    rename_all_pattern: Option<RenameAll>,
+    container_default: bool,
+    container_default_on_null: bool,
+    lifetime: &Lifetime,
+    predicates: &mut Punctuated<WherePredicate, Comma>,
+) -> Vec<Stmt> {
+    fields
         .iter()
         .filter_map(|field| -> Option<Stmt> {
             let id = &field.ident.as_ref()?;
@@ -92,12 +132,74 @@ fn expand_derive_from_row_struct(
             let id_s = attributes
                 .rename
                 .or_else(|| Some(id.to_string().trim_start_matches("r#").to_owned()))
-                .map(|s| match container_attributes.rename_all {
+                .map(|s| match rename_all_pattern {
                     Some(pattern) => rename_all(&s, pattern),
                     None => s,
                 })
                 .unwrap();
 
+            // A container-level `default_on_null` only applies to fields read from a single column.
+            let default_on_null = attributes.default_on_null
+                || (container_default_on_null
+                    && !attributes.flatten
+                    && attributes.try_from.is_none());
+
+            if default_on_null {
+                let expr: Expr = match (attributes.flatten, &attributes.try_from, attributes.json) {
+                    (false, None, false) => {
+                        predicates.push(parse_quote!(
+                            ::std::option::Option<#ty>: ::sqlx::decode::Decode<#lifetime, R::Database>
+                        ));
+                        predicates.push(parse_quote!(
+                            ::std::option::Option<#ty>: ::sqlx::types::Type<R::Database>
+                        ));
+
+                        parse_quote!(row.try_get::<::std::option::Option<#ty>, _>(#id_s))
+                    }
+                    (false, None, true) => {
+                        predicates.push(parse_quote!(
+                            ::std::option::Option<::sqlx::types::Json<#ty>>: ::sqlx::decode::Decode<#lifetime, R::Database>
+                        ));
+                        predicates.push(parse_quote!(
+                            ::std::option::Option<::sqlx::types::Json<#ty>>: ::sqlx::types::Type<R::Database>
+                        ));
+
+                        parse_quote!(
+                            row.try_get::<::std::option::Option<::sqlx::types::Json<_>>, _>(#id_s)
+                                .map(|x| x.map(|x| x.0))
+                        )
+                    }
+                    _ => panic!("Cannot use `default_on_null` with `flatten` or `try_from`"),
+                };
+
+                let null_default: Expr = if container_default {
+                    parse_quote!(__default.#id)
+                } else {
+                    parse_quote!(::std::default::Default::default())
+                };
+
+                let missing_arm = if attributes.default {
+                    quote!(::std::result::Result::Err(::sqlx::Error::ColumnNotFound(_)) => {
+                        ::std::default::Default::default()
+                    })
+                } else if container_default {
+                    quote!(::std::result::Result::Err(::sqlx::Error::ColumnNotFound(_)) => {
+                        __default.#id
+                    })
+                } else {
+                    quote!()
+                };
+
+                return Some(parse_quote!(
+                    let #id: #ty = match #expr {
+                        ::std::result::Result::Ok(::std::option::Option::Some(v)) => v,
+                        ::std::result::Result::Ok(::std::option::Option::None) => #null_default,
+                        #missing_arm
+                        ::std::result::Result::Err(e) => return ::std::result::Result::Err(e),
+                    };
+                ));
+            }
+
             let expr: Expr = match (attributes.flatten, attributes.try_from, attributes.json) {
                 // <No attributes>
                 (false, None, false) => {
@@ -159,7 +261,7 @@ fn expand_derive_from_row_struct(
                 },
                 e => ::std::result::Result::Err(e)
             })?;))
-            } else if container_attributes.default {
+            } else if container_default {
                 Some(parse_quote!(let #id: #ty = #expr.or_else(|e| match e {
                     ::sqlx::Error::ColumnNotFound(_) => {
                         ::std::result::Result::Ok(__default.#id)
@@ -172,26 +274,7 @@ fn expand_derive_from_row_struct(
                 ))
             }
         })
-        .collect();
-
-    let (impl_generics, _, where_clause) = generics.split_for_impl();
-
-    let names = fields.iter().map(|field| &field.ident);
-
-    Ok(quote!(
-        #[automatically_derived]
-        impl #impl_generics ::sqlx::FromRow<#lifetime, R> for #ident #ty_generics #where_clause {
-            fn from_row(row: &#lifetime R) -> ::sqlx::Result<Self> {
-                #default_instance
-
-                #(#reads)*
-
-                ::std::result::Result::Ok(#ident {
-                    #(#names),*
-                })
-            }
-        }
-    ))
+        .collect()
 }
 
 fn expand_derive_from_row_struct_unnamed(
@@ -248,3 +331,118 @@ fn expand_derive_from_row_struct_unnamed(
         }
     ))
 }
+
+fn expand_derive_from_row_enum(
+    input: &DeriveInput,
+    variants: &Punctuated<Variant, Comma>,
+) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+
+    let generics = &input.generics;
+
+    let (lifetime, provided) = generics
+        .lifetimes()
+        .next()
+        .map(|def| (def.lifetime.clone(), false))
+        .unwrap_or_else(|| (Lifetime::new("'a", Span::call_site()), true));
+
+    let (_, ty_generics, _) = generics.split_for_impl();
+
+    let mut generics = generics.clone();
+    generics.params.insert(0, parse_quote!(R: ::sqlx::Row));
+
+    if provided {
+        generics.params.insert(0, parse_quote!(#lifetime));
+    }
+
+    let predicates = &mut generics.make_where_clause().predicates;
+
+    predicates.push(parse_quote!(&#lifetime ::std::primitive::str: ::sqlx::ColumnIndex<R>));
+    predicates.push(parse_quote!(
+        ::std::string::String: ::sqlx::decode::Decode<#lifetime, R::Database>
+    ));
+    predicates.push(parse_quote!(::std::string::String: ::sqlx::types::Type<R::Database>));
+
+    let container_attributes = parse_container_attributes(&input.attrs)?;
+
+    let Some(discriminant) = container_attributes.discriminant else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "enums require #[sqlx(discriminant = \"<column>\")]",
+        ));
+    };
+
+    if container_attributes.default {
+        return Err(syn::Error::new_spanned(
+            input,
+            "#[sqlx(default)] is not supported on enums",
+        ));
+    }
+
+    let mut arms = Vec::with_capacity(variants.len());
+
+    for variant in variants {
+        let variant_ident = &variant.ident;
+        let attributes = parse_child_attributes(&variant.attrs)?;
+
+        let value = attributes.rename.unwrap_or_else(|| {
+            let name = variant_ident
+                .to_string()
+                .trim_start_matches("r#")
+                .to_owned();
+
+            match container_attributes.rename_all {
+                Some(pattern) => rename_all(&name, pattern),
+                None => name,
+            }
+        });
+
+        let arm = match &variant.fields {
+            Fields::Named(FieldsNamed { named, .. }) => {
+                let reads = expand_field_reads(named, None, false, false, &lifetime, predicates);
+                let names = named.iter().map(|field| &field.ident);
+
+                quote!(#value => {
+                    #(#reads)*
+
+                    ::std::result::Result::Ok(#ident::#variant_ident {
+                        #(#names),*
+                    })
+                })
+            }
+
+            Fields::Unit => quote!(#value => ::std::result::Result::Ok(#ident::#variant_ident)),
+
+            Fields::Unnamed(_) => {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "tuple variants are not supported",
+                ))
+            }
+        };
+
+        arms.push(arm);
+    }
+
+    // Matches how `Row::try_get()` formats the column index in `Error::ColumnDecode`.
+    let discriminant_debug = format!("{discriminant:?}");
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    Ok(quote!(
+        #[automatically_derived]
+        impl #impl_generics ::sqlx::FromRow<#lifetime, R> for #ident #ty_generics #where_clause {
+            fn from_row(row: &#lifetime R) -> ::sqlx::Result<Self> {
+                let __discriminant: ::std::string::String = row.try_get(#discriminant)?;
+
+                match __discriminant.as_str() {
+                    #(#arms,)*
+                    _ => ::std::result::Result::Err(::sqlx::Error::ColumnDecode {
+                        index: ::std::string::String::from(#discriminant_debug),
+                        source: ::std::format!("unknown discriminant value {:?}", __discriminant).into(),
+                    }),
+                }
+            }
+        }
+    ))
+}
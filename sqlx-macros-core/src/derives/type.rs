@@ -184,6 +184,19 @@ fn expand_derive_has_sql_type_strong_enum(
                 }
             }
         ));
+
+        if attributes.pg_array {
+            let array_name = array_type_name(ident, attributes.type_name.as_ref());
+
+            tts.extend(quote!(
+                #[automatically_derived]
+                impl ::sqlx::postgres::PgHasArrayType for #ident {
+                    fn array_type_info() -> ::sqlx::postgres::PgTypeInfo {
+                        ::sqlx::postgres::PgTypeInfo::with_name(#array_name)
+                    }
+                }
+            ));
+        }
     }
 
     if cfg!(feature = "sqlite") {
@@ -224,11 +237,34 @@ fn expand_derive_has_sql_type_struct(
                 }
             }
         ));
+
+        if attributes.pg_array {
+            let array_name = array_type_name(ident, attributes.type_name.as_ref());
+
+            tts.extend(quote!(
+                #[automatically_derived]
+                impl ::sqlx::postgres::PgHasArrayType for #ident {
+                    fn array_type_info() -> ::sqlx::postgres::PgTypeInfo {
+                        ::sqlx::postgres::PgTypeInfo::with_name(#array_name)
+                    }
+                }
+            ));
+        }
     }
 
     Ok(tts)
 }
 
+/// Postgres names the array type of `foo` as `_foo`, keeping any schema qualification.
+fn array_type_name(ident: &Ident, explicit_name: Option<&TypeName>) -> String {
+    let name = explicit_name.map_or_else(|| ident.to_string(), |tn| tn.val.clone());
+
+    match name.rsplit_once('.') {
+        Some((schema, name)) => format!("{schema}._{name}"),
+        None => format!("_{name}"),
+    }
+}
+
 fn type_name(ident: &Ident, explicit_name: Option<&TypeName>) -> TokenStream {
     explicit_name.map(|tn| tn.get()).unwrap_or_else(|| {
         let s = ident.to_string();
@@ -39,6 +39,8 @@ impl ToTokens for QuoteMigration {
             ..
         } = &self.migration;
 
+        let no_tx = self.migration.no_tx();
+
         let migration_type = QuoteMigrationType(*migration_type);
 
         let sql = self
@@ -64,15 +66,14 @@ impl ToTokens for QuoteMigration {
             .unwrap_or_else(|e| quote! { compile_error!(#e) });
 
         let ts = quote! {
-            ::sqlx::migrate::Migration {
-                version: #version,
-                description: ::std::borrow::Cow::Borrowed(#description),
-                migration_type:  #migration_type,
-                sql: ::std::borrow::Cow::Borrowed(#sql),
-                checksum: ::std::borrow::Cow::Borrowed(&[
-                    #(#checksum),*
-                ]),
-            }
+            ::sqlx::migrate::Migration::from_macro(
+                #version,
+                #description,
+                #migration_type,
+                #sql,
+                &[#(#checksum),*],
+                #no_tx,
+            )
         };
 
         tokens.append_all(ts.into_iter());
@@ -117,12 +118,18 @@ pub(crate) fn expand_migrator(path: &Path) -> crate::Result<TokenStream> {
         proc_macro::tracked_path::path(path);
     }
 
+    // `Migration::from_macro()` is a `const fn`, but calls to it aren't promoted to `'static`
+    // outside of a const context.
     Ok(quote! {
-        ::sqlx::migrate::Migrator {
-            migrations: ::std::borrow::Cow::Borrowed(&[
-                    #(#migrations),*
-            ]),
-            ..::sqlx::migrate::Migrator::DEFAULT
+        {
+            const MIGRATIONS: &[::sqlx::migrate::Migration] = &[
+                #(#migrations),*
+            ];
+
+            ::sqlx::migrate::Migrator {
+                migrations: ::std::borrow::Cow::Borrowed(MIGRATIONS),
+                ..::sqlx::migrate::Migrator::DEFAULT
+            }
         }
     })
 }
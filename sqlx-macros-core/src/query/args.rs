@@ -46,7 +46,12 @@ pub fn quote_args<DB: DatabaseExt>(
         Some(Either::Left(params)) => {
             params
                 .iter()
-                .zip(arg_names.iter().zip(&input.arg_exprs))
+                .zip(
+                    input
+                        .bind_order
+                        .iter()
+                        .map(|&idx| (&arg_names[idx], &input.arg_exprs[idx])),
+                )
                 .enumerate()
                 .map(|(i, (param_ty, (name, expr)))| -> crate::Result<_> {
                     if get_type_override(expr).is_some() {
@@ -97,7 +102,12 @@ pub fn quote_args<DB: DatabaseExt>(
         }
     };
 
-    let args_count = input.arg_exprs.len();
+    let args_count = input.bind_order.len();
+    let bound_name = input
+        .bind_order
+        .iter()
+        .map(|&idx| &arg_names[idx])
+        .collect::<Vec<_>>();
 
     Ok(quote! {
         #arg_bindings
@@ -107,9 +117,9 @@ pub fn quote_args<DB: DatabaseExt>(
         let mut query_args = <#db_path as ::sqlx::database::Database>::Arguments::<'_>::default();
         query_args.reserve(
             #args_count,
-            0 #(+ ::sqlx::encode::Encode::<#db_path>::size_hint(#arg_name))*
+            0 #(+ ::sqlx::encode::Encode::<#db_path>::size_hint(#bound_name))*
         );
-        #(query_args.add(#arg_name);)*
+        #(query_args.add(#bound_name);)*
     })
 }
 
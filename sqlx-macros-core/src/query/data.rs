@@ -150,10 +150,16 @@ where
         }
     }
 
-    pub(super) fn save_in(&self, dir: impl AsRef<Path>) -> crate::Result<()> {
+    pub(super) fn save_in(
+        &self,
+        dir: impl AsRef<Path>,
+        database_url_var: Option<&str>,
+    ) -> crate::Result<()> {
         use std::io::ErrorKind;
 
-        let path = dir.as_ref().join(format!("query-{}.json", self.hash));
+        let path = dir
+            .as_ref()
+            .join(data_file_name(&self.hash, database_url_var));
         match std::fs::remove_file(&path) {
             Ok(()) => {}
             Err(err)
@@ -190,6 +196,17 @@ where
     }
 }
 
+/// The name of the offline data file for a query with the given hash.
+///
+/// Queries checked against a database other than `DATABASE_URL` are namespaced by the name of
+/// its environment variable, so the same query may be cached for more than one database.
+pub(super) fn data_file_name(hash: &str, database_url_var: Option<&str>) -> String {
+    match database_url_var {
+        Some(var) => format!("query-{var}-{hash}.json"),
+        None => format!("query-{hash}.json"),
+    }
+}
+
 pub(super) fn hash_string(query: &str) -> String {
     // picked `sha2` because it's already in the dependency tree for both MySQL and Postgres
     use sha2::{Digest, Sha256};
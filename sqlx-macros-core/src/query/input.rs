@@ -4,7 +4,7 @@ use proc_macro2::{Ident, Span};
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::{Expr, LitBool, LitStr, Token};
-use syn::{ExprArray, Type};
+use syn::{ExprArray, Type, Visibility};
 
 /// Macro input shared by `query!()` and `query_file!()`
 pub struct QueryMacroInput {
@@ -16,9 +16,20 @@ pub struct QueryMacroInput {
 
     pub(super) arg_exprs: Vec<Expr>,
 
+    /// Index into `arg_exprs` of the argument bound to each placeholder, in order.
+    ///
+    /// This only differs from `0..arg_exprs.len()` when a named argument is bound more than once.
+    pub(super) bind_order: Vec<usize>,
+
+    /// Arguments given as `name = expr`, bound to `:name` placeholders in the query.
+    pub(super) named_args: Vec<(Ident, Expr)>,
+
     pub(super) checked: bool,
 
     pub(super) file_path: Option<String>,
+
+    /// The environment variable to read the database URL from, instead of `DATABASE_URL`.
+    pub(super) database_url_var: Option<String>,
 }
 
 enum QuerySrc {
@@ -30,6 +41,11 @@ pub enum RecordType {
     Given(Type),
     Scalar,
     Generated,
+    /// Define a struct with the given name for the query's output columns.
+    Define {
+        vis: Visibility,
+        name: Ident,
+    },
 }
 
 impl Parse for QueryMacroInput {
@@ -38,6 +54,7 @@ impl Parse for QueryMacroInput {
         let mut args: Option<Vec<Expr>> = None;
         let mut record_type = RecordType::Generated;
         let mut checked = true;
+        let mut database_url_var = None;
 
         let mut expect_comma = false;
 
@@ -69,6 +86,15 @@ impl Parse for QueryMacroInput {
                 }
 
                 record_type = RecordType::Given(input.parse()?);
+            } else if key == "define" {
+                if !matches!(record_type, RecordType::Generated) {
+                    return Err(input.error("colliding `define`, `scalar` or `record` key"));
+                }
+
+                record_type = RecordType::Define {
+                    vis: input.parse()?,
+                    name: input.parse()?,
+                };
             } else if key == "scalar" {
                 if !matches!(record_type, RecordType::Generated) {
                     return Err(input.error("colliding `scalar` or `record` key"));
@@ -79,6 +105,18 @@ impl Parse for QueryMacroInput {
                 // of the column in SQL
                 input.parse::<syn::Token![_]>()?;
                 record_type = RecordType::Scalar;
+            } else if key == "db" {
+                let lit_str = input.parse::<LitStr>()?;
+                let var = lit_str.value();
+
+                if var.is_empty() || !var.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                    return Err(syn::Error::new_spanned(
+                        lit_str,
+                        "expected the name of an environment variable",
+                    ));
+                }
+
+                database_url_var = Some(var);
             } else if key == "checked" {
                 let lit_bool = input.parse::<LitBool>()?;
                 checked = lit_bool.value;
@@ -93,7 +131,7 @@ impl Parse for QueryMacroInput {
         let (src, src_span) =
             query_src.ok_or_else(|| input.error("expected `source` or `source_file` key"))?;
 
-        let arg_exprs = args.unwrap_or_default();
+        let (arg_exprs, named_args) = split_named_args(args.unwrap_or_default())?;
 
         let file_path = src.file_path(src_span)?;
 
@@ -101,13 +139,58 @@ impl Parse for QueryMacroInput {
             sql: src.resolve(src_span)?,
             src_span,
             record_type,
+            bind_order: (0..arg_exprs.len()).collect(),
             arg_exprs,
+            named_args,
             checked,
             file_path,
+            database_url_var,
         })
     }
 }
 
+/// Separate `name = expr` arguments from positional ones; the two cannot be mixed.
+fn split_named_args(args: Vec<Expr>) -> syn::Result<(Vec<Expr>, Vec<(Ident, Expr)>)> {
+    let mut positional = Vec::new();
+    let mut named: Vec<(Ident, Expr)> = Vec::new();
+
+    for arg in args {
+        let Expr::Assign(assign) = arg else {
+            positional.push(arg);
+            continue;
+        };
+
+        let name = match &*assign.left {
+            Expr::Path(path) if path.attrs.is_empty() && path.qself.is_none() => {
+                path.path.get_ident().cloned()
+            }
+            _ => None,
+        };
+
+        let Some(name) = name else {
+            return Err(syn::Error::new_spanned(
+                &assign.left,
+                "expected an identifier as the name of a named argument",
+            ));
+        };
+
+        if named.iter().any(|(existing, _)| *existing == name) {
+            return Err(syn::Error::new_spanned(name, "duplicate named argument"));
+        }
+
+        named.push((name, *assign.right));
+    }
+
+    if !positional.is_empty() && !named.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &positional[0],
+            "cannot mix positional and named arguments",
+        ));
+    }
+
+    Ok((positional, named))
+}
+
 impl QuerySrc {
     /// If the query source is a file, read it to a string. Otherwise return the query string.
     fn resolve(self, source_span: Span) -> syn::Result<String> {
@@ -153,3 +236,38 @@ fn read_file_src(source: &str, source_span: Span) -> syn::Result<String> {
         )
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::data::data_file_name;
+    use quote::quote;
+
+    #[test]
+    fn it_parses_the_database_url_var() {
+        let input: QueryMacroInput =
+            syn::parse2(quote!(source = "SELECT 1", db = "ANALYTICS_DATABASE_URL")).unwrap();
+        assert_eq!(
+            input.database_url_var.as_deref(),
+            Some("ANALYTICS_DATABASE_URL")
+        );
+
+        let input: QueryMacroInput = syn::parse2(quote!(source = "SELECT 1")).unwrap();
+        assert_eq!(input.database_url_var, None);
+
+        assert!(syn::parse2::<QueryMacroInput>(quote!(
+            source = "SELECT 1",
+            db = "postgres://localhost"
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn it_namespaces_offline_data_by_database_url_var() {
+        assert_eq!(data_file_name("abc", None), "query-abc.json");
+        assert_eq!(
+            data_file_name("abc", Some("ANALYTICS_DATABASE_URL")),
+            "query-ANALYTICS_DATABASE_URL-abc.json"
+        );
+    }
+}
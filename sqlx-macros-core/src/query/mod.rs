@@ -12,7 +12,7 @@ use sqlx_core::database::Database;
 use sqlx_core::{column::Column, describe::Describe, type_info::TypeInfo};
 
 use crate::database::DatabaseExt;
-use crate::query::data::{hash_string, DynQueryData, QueryData};
+use crate::query::data::{data_file_name, hash_string, DynQueryData, QueryData};
 use crate::query::input::RecordType;
 use either::Either;
 use url::Url;
@@ -20,6 +20,7 @@ use url::Url;
 mod args;
 mod data;
 mod input;
+mod named;
 mod output;
 
 #[derive(Copy, Clone)]
@@ -153,16 +154,21 @@ pub fn expand_input<'a>(
     input: QueryMacroInput,
     drivers: impl IntoIterator<Item = &'a QueryDriver>,
 ) -> crate::Result<TokenStream> {
-    let data_source = match &*METADATA {
-        Metadata {
-            offline: false,
-            database_url: Some(db_url),
-            ..
-        } => QueryDataSource::live(db_url)?,
+    // `METADATA` loads `.env`, so it must be initialized before reading any other variable.
+    let metadata = &*METADATA;
+
+    let database_url = match &input.database_url_var {
+        Some(var) => env(var).ok(),
+        None => metadata.database_url.clone(),
+    };
 
-        Metadata { offline, .. } => {
+    let data_source = match (metadata.offline, &database_url) {
+        (false, Some(db_url)) => QueryDataSource::live(db_url)?,
+
+        (offline, _) => {
             // Try load the cached query metadata file.
-            let filename = format!("query-{}.json", hash_string(&input.sql));
+            let filename =
+                data_file_name(&hash_string(&input.sql), input.database_url_var.as_deref());
 
             // Check SQLX_OFFLINE_DIR, then local .sqlx, then workspace .sqlx.
             let dirs = [
@@ -176,11 +182,13 @@ pub fn expand_input<'a>(
                 .map(|path| path.join(&filename))
                 .find(|path| path.exists())
             else {
+                let var = input.database_url_var.as_deref().unwrap_or("DATABASE_URL");
+
                 return Err(
-                    if *offline {
-                        "`SQLX_OFFLINE=true` but there is no cached data for this query, run `cargo sqlx prepare` to update the query cache or unset `SQLX_OFFLINE`"
+                    if offline {
+                        "`SQLX_OFFLINE=true` but there is no cached data for this query, run `cargo sqlx prepare` to update the query cache or unset `SQLX_OFFLINE`".into()
                     } else {
-                        "set `DATABASE_URL` to use query macros online, or run `cargo sqlx prepare` to update the query cache"
+                        format!("set `{var}` to use query macros online, or run `cargo sqlx prepare` to update the query cache")
                     }.into()
                 );
             };
@@ -213,20 +221,44 @@ pub fn expand_input<'a>(
 }
 
 fn expand_with<DB: DatabaseExt>(
-    input: QueryMacroInput,
+    mut input: QueryMacroInput,
     data_source: QueryDataSource,
 ) -> crate::Result<TokenStream>
 where
     Describe<DB>: DescribeExt,
 {
+    // Rewrite `:name` parameters into the driver's own placeholders up front. The query data
+    // stays keyed by the SQL as written, so the offline cache can find it again.
+    let sql = if input.named_args.is_empty() {
+        None
+    } else {
+        let (sql, arg_exprs, bind_order) = named::rewrite(
+            &input.sql,
+            &input.named_args,
+            DB::PLACEHOLDER_STYLE,
+            DB::SQL_DIALECT,
+            input.src_span,
+        )?;
+
+        input.arg_exprs = arg_exprs;
+        input.bind_order = bind_order;
+
+        Some(sql)
+    };
+
     let (query_data, offline): (QueryData<DB>, bool) = match data_source {
         QueryDataSource::Cached(dyn_data) => (QueryData::from_dyn_data(dyn_data)?, true),
         QueryDataSource::Live { database_url, .. } => {
-            let describe = DB::describe_blocking(&input.sql, &database_url)?;
+            let describe =
+                DB::describe_blocking(sql.as_deref().unwrap_or(&input.sql), &database_url)?;
             (QueryData::from_describe(&input.sql, describe), false)
         }
     };
 
+    if let Some(sql) = sql {
+        input.sql = sql;
+    }
+
     expand_with_data(input, query_data, offline)
 }
 
@@ -246,6 +278,15 @@ fn expand_with_data<DB: DatabaseExt>(
 where
     Describe<DB>: DescribeExt,
 {
+    // `query_struct!()` emits items rather than an expression, and takes no arguments
+    if let RecordType::Define { ref vis, ref name } = input.record_type {
+        let tokens = output::quote_struct::<DB>(&input, vis, name, &data.describe)?;
+
+        save_query_data(&data, &input, offline)?;
+
+        return Ok(tokens);
+    }
+
     // validate at the minimum that our args match the query's input parameters
     let num_parameters = match data.describe.parameters() {
         Some(Either::Left(params)) => Some(params.len()),
@@ -255,10 +296,13 @@ where
     };
 
     if let Some(num) = num_parameters {
-        if num != input.arg_exprs.len() {
-            return Err(
-                format!("expected {} parameters, got {}", num, input.arg_exprs.len()).into(),
-            );
+        if num != input.bind_order.len() {
+            return Err(format!(
+                "expected {} parameters, got {}",
+                num,
+                input.bind_order.len()
+            )
+            .into());
         }
     }
 
@@ -327,6 +371,7 @@ where
             RecordType::Scalar => {
                 output::quote_query_scalar::<DB>(&input, &query_args, &data.describe)?
             }
+            RecordType::Define { .. } => unreachable!(),
         }
     };
 
@@ -343,6 +388,19 @@ where
         }
     };
 
+    save_query_data(&data, &input, offline)?;
+
+    Ok(ret_tokens)
+}
+
+fn save_query_data<DB: DatabaseExt>(
+    data: &QueryData<DB>,
+    input: &QueryMacroInput,
+    offline: bool,
+) -> crate::Result<()>
+where
+    Describe<DB>: DescribeExt,
+{
     // Store query metadata only if offline support is enabled but the current build is online.
     // If the build is offline, the cache is our input so it's pointless to also write data for it.
     if !offline {
@@ -369,13 +427,13 @@ where
                     }
 
                     // .sqlx exists and is a directory, store data.
-                    data.save_in(path)?;
+                    data.save_in(path, input.database_url_var.as_deref())?;
                 }
             }
         }
     }
 
-    Ok(ret_tokens)
+    Ok(())
 }
 
 /// Get the value of an environment variable, telling the compiler about it if applicable.
@@ -0,0 +1,206 @@
+//! Rewriting of named parameters (`:name`) into the driver's positional placeholders.
+
+use std::fmt::Write;
+
+use proc_macro2::{Ident, Span};
+use sqlx_core::raw_sql::{SqlDialect, SqlLexer, SqlSpan};
+use syn::Expr;
+
+use crate::database::PlaceholderStyle;
+
+/// Replace each `:name` in `sql` with a positional placeholder.
+///
+/// Returns the new SQL, the argument expressions in the order they are first used, and the index
+/// into those of the argument bound to each placeholder. With numbered placeholders, each distinct
+/// name is bound once no matter how often it is used; otherwise it is bound again for each use.
+pub fn rewrite(
+    sql: &str,
+    named_args: &[(Ident, Expr)],
+    style: PlaceholderStyle,
+    dialect: SqlDialect,
+    span: Span,
+) -> syn::Result<(String, Vec<Expr>, Vec<usize>)> {
+    let mut out = String::with_capacity(sql.len());
+    // Index into `named_args` of each argument, in the order they are first used.
+    let mut used: Vec<usize> = Vec::new();
+    let mut bind_order = Vec::new();
+
+    for (kind, range) in SqlLexer::new(sql, dialect) {
+        let code = &sql[range];
+
+        if kind != SqlSpan::Code {
+            out.push_str(code);
+            continue;
+        }
+
+        let bytes = code.as_bytes();
+        let mut copied = 0;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            if bytes[i] != b':' {
+                i += 1;
+                continue;
+            }
+
+            // Postgres casts: `value::type`
+            if bytes.get(i + 1) == Some(&b':') {
+                i += 2;
+                continue;
+            }
+
+            if !bytes
+                .get(i + 1)
+                .is_some_and(|&b| b.is_ascii_alphabetic() || b == b'_')
+            {
+                i += 1;
+                continue;
+            }
+
+            let start = i + 1;
+            let end = code[start..]
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .map_or(code.len(), |end| start + end);
+
+            let name = &code[start..end];
+
+            let Some(idx) = named_args.iter().position(|(ident, _)| ident == name) else {
+                return Err(syn::Error::new(
+                    span,
+                    format!("no value given for named parameter `:{name}`"),
+                ));
+            };
+
+            let position = match used.iter().position(|&used| used == idx) {
+                Some(position) => position,
+                None => {
+                    used.push(idx);
+                    used.len() - 1
+                }
+            };
+
+            out.push_str(&code[copied..i]);
+            copied = end;
+
+            match style {
+                PlaceholderStyle::Question => {
+                    bind_order.push(position);
+                    out.push('?');
+                }
+                PlaceholderStyle::Dollar => write!(out, "${}", position + 1).unwrap(),
+                PlaceholderStyle::QuestionNumbered => write!(out, "?{}", position + 1).unwrap(),
+            }
+
+            i = end;
+        }
+
+        out.push_str(&code[copied..]);
+    }
+
+    if let Some((name, _)) = named_args
+        .iter()
+        .enumerate()
+        .find(|(idx, _)| !used.contains(idx))
+        .map(|(_, arg)| arg)
+    {
+        return Err(syn::Error::new(
+            name.span(),
+            format!("named argument `{name}` is not used in the query"),
+        ));
+    }
+
+    if style != PlaceholderStyle::Question {
+        bind_order = (0..used.len()).collect();
+    }
+
+    let arg_exprs = used.iter().map(|&idx| named_args[idx].1.clone()).collect();
+
+    Ok((out, arg_exprs, bind_order))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::ToTokens;
+    use syn::parse_quote;
+
+    fn args() -> Vec<(Ident, Expr)> {
+        vec![
+            (parse_quote!(user_id), parse_quote!(id)),
+            (parse_quote!(status), parse_quote!(self.status)),
+        ]
+    }
+
+    fn exprs(exprs: Vec<Expr>) -> Vec<String> {
+        exprs
+            .iter()
+            .map(|e| e.to_token_stream().to_string())
+            .collect()
+    }
+
+    const SQL: &str = "SELECT ':status', id::text FROM users -- :status\n\
+        WHERE id = :user_id AND (status = :status OR :status IS NULL)";
+
+    #[test]
+    fn it_rewrites_to_numbered_placeholders() {
+        let (sql, args, bind_order) = rewrite(
+            SQL,
+            &args(),
+            PlaceholderStyle::Dollar,
+            SqlDialect::Postgres,
+            Span::call_site(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT ':status', id::text FROM users -- :status\n\
+            WHERE id = $1 AND (status = $2 OR $2 IS NULL)"
+        );
+        assert_eq!(exprs(args), ["id", "self . status"]);
+        assert_eq!(bind_order, [0, 1]);
+    }
+
+    #[test]
+    fn it_rewrites_to_positional_placeholders() {
+        let (sql, args, bind_order) = rewrite(
+            SQL,
+            &args(),
+            PlaceholderStyle::Question,
+            SqlDialect::MySql,
+            Span::call_site(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT ':status', id::text FROM users -- :status\n\
+            WHERE id = ? AND (status = ? OR ? IS NULL)"
+        );
+        // each expression is evaluated once, and its value bound for each use
+        assert_eq!(exprs(args), ["id", "self . status"]);
+        assert_eq!(bind_order, [0, 1, 1]);
+    }
+
+    #[test]
+    fn it_rejects_missing_and_unused_arguments() {
+        let style = PlaceholderStyle::QuestionNumbered;
+
+        assert!(rewrite(
+            "SELECT :other",
+            &args(),
+            style,
+            SqlDialect::Sqlite,
+            Span::call_site()
+        )
+        .is_err());
+        assert!(rewrite(
+            "SELECT :user_id",
+            &args(),
+            style,
+            SqlDialect::Sqlite,
+            Span::call_site()
+        )
+        .is_err());
+    }
+}
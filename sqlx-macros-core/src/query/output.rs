@@ -1,6 +1,6 @@
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, ToTokens, TokenStreamExt};
-use syn::Type;
+use syn::{Type, Visibility};
 
 use sqlx_core::column::Column;
 use sqlx_core::describe::Describe;
@@ -165,11 +165,23 @@ pub fn quote_query_as<DB: DatabaseExt>(
     let row_path = DB::row_path();
 
     // if this query came from a file, use `include_str!()` to tell the compiler where it came from
-    let sql = if let Some(ref path) = &input.file_path {
-        quote::quote_spanned! { input.src_span => include_str!(#path) }
-    } else {
-        let sql = &input.sql;
-        quote! { #sql }
+    let sql = match &input.file_path {
+        Some(path) if input.named_args.is_empty() => {
+            quote::quote_spanned! { input.src_span => include_str!(#path) }
+        }
+        // named parameters were rewritten, so the file contents can't be used as-is; still
+        // include it so that editing the file triggers a rebuild
+        Some(path) => {
+            let sql = &input.sql;
+            quote::quote_spanned! { input.src_span => {
+                const _: &str = include_str!(#path);
+                #sql
+            } }
+        }
+        None => {
+            let sql = &input.sql;
+            quote! { #sql }
+        }
     };
 
     quote! {
@@ -183,6 +195,61 @@ pub fn quote_query_as<DB: DatabaseExt>(
     }
 }
 
+/// Define a struct for the output columns of the query, which can be decoded with `FromRow`.
+pub fn quote_struct<DB: DatabaseExt>(
+    input: &QueryMacroInput,
+    vis: &Visibility,
+    name: &Ident,
+    describe: &Describe<DB>,
+) -> crate::Result<TokenStream> {
+    let columns = columns_to_rust::<DB>(describe)?;
+
+    if columns.is_empty() {
+        return Err(syn::Error::new(input.src_span, "query does not return any columns").into());
+    }
+
+    if columns.iter().any(|col| col.type_.is_wildcard()) {
+        return Err(syn::Error::new(
+            input.src_span,
+            "wildcard overrides are not allowed when defining a struct, \
+             the type of each field must be known",
+        )
+        .into());
+    }
+
+    let ident = columns.iter().map(|col| &col.ident);
+    let type_ = columns.iter().map(|col| &col.type_);
+    let read = columns.iter().enumerate().map(|(i, col)| {
+        let ident = &col.ident;
+        quote! { #ident: row.try_get(#i)?, }
+    });
+
+    let row_path = DB::row_path();
+    let sql = &input.sql;
+
+    Ok(quote! {
+        #[derive(Debug)]
+        #vis struct #name {
+            #(#vis #ident: #type_,)*
+        }
+
+        impl #name {
+            /// The query this struct was defined from.
+            #vis const QUERY: &'static str = #sql;
+        }
+
+        impl<'r> ::sqlx::FromRow<'r, #row_path> for #name {
+            fn from_row(row: &'r #row_path) -> ::sqlx::Result<Self> {
+                use ::sqlx::Row as _;
+
+                ::std::result::Result::Ok(#name {
+                    #(#read)*
+                })
+            }
+        }
+    })
+}
+
 pub fn quote_query_scalar<DB: DatabaseExt>(
     input: &QueryMacroInput,
     bind_args: &Ident,
@@ -1,4 +1,4 @@
-use crate::protocol::text::ColumnType;
+use crate::protocol::text::{ColumnFlags, ColumnType};
 use crate::{
     MySql, MySqlColumn, MySqlConnectOptions, MySqlConnection, MySqlQueryResult, MySqlRow,
     MySqlTransactionManager, MySqlTypeInfo,
@@ -15,7 +15,8 @@ use sqlx_core::connection::Connection;
 use sqlx_core::database::Database;
 use sqlx_core::describe::Describe;
 use sqlx_core::executor::Executor;
-use sqlx_core::transaction::TransactionManager;
+use sqlx_core::transaction::{TransactionManager, TransactionOptions, TransactionStatus};
+use sqlx_core::type_info::TypeInfo;
 
 sqlx_core::declare_driver_with_optional_migrate!(DRIVER = MySql);
 
@@ -40,6 +41,10 @@ impl AnyConnectionBackend for MySqlConnection {
         MySqlTransactionManager::begin(self)
     }
 
+    fn begin_with(&mut self, options: TransactionOptions) -> BoxFuture<'_, sqlx_core::Result<()>> {
+        MySqlTransactionManager::begin_with(self, options)
+    }
+
     fn commit(&mut self) -> BoxFuture<'_, sqlx_core::Result<()>> {
         MySqlTransactionManager::commit(self)
     }
@@ -52,6 +57,14 @@ impl AnyConnectionBackend for MySqlConnection {
         MySqlTransactionManager::start_rollback(self)
     }
 
+    fn transaction_status(&self) -> TransactionStatus {
+        MySqlTransactionManager::transaction_status(self)
+    }
+
+    fn write_count(&self) -> Option<u64> {
+        MySqlTransactionManager::write_count(self)
+    }
+
     fn shrink_buffers(&mut self) {
         Connection::shrink_buffers(self);
     }
@@ -80,7 +93,7 @@ impl AnyConnectionBackend for MySqlConnection {
         let args = arguments.as_ref().map(AnyArguments::convert_to);
 
         Box::pin(
-            self.run(query, args, persistent)
+            self.run(query, args, persistent, None)
                 .try_flatten_stream()
                 .map(|res| {
                     Ok(match res? {
@@ -100,7 +113,7 @@ impl AnyConnectionBackend for MySqlConnection {
         let args = arguments.as_ref().map(AnyArguments::convert_to);
 
         Box::pin(async move {
-            let stream = self.run(query, args, persistent).await?;
+            let stream = self.run(query, args, persistent, None).await?;
             futures_util::pin_mut!(stream);
 
             if let Some(Either::Right(row)) = stream.try_next().await? {
@@ -138,10 +151,15 @@ impl<'a> TryFrom<&'a MySqlTypeInfo> for AnyTypeInfo {
     type Error = sqlx_core::Error;
 
     fn try_from(type_info: &'a MySqlTypeInfo) -> Result<Self, Self::Error> {
+        // the integer decoders sign-extend, so only the narrower signed types can be widened
+        let signed = !type_info.flags.contains(ColumnFlags::UNSIGNED);
+
         Ok(AnyTypeInfo {
             kind: match &type_info.r#type {
                 ColumnType::Null => AnyTypeInfoKind::Null,
+                ColumnType::Tiny if signed => AnyTypeInfoKind::SmallInt,
                 ColumnType::Short => AnyTypeInfoKind::SmallInt,
+                ColumnType::Int24 if signed => AnyTypeInfoKind::Integer,
                 ColumnType::Long => AnyTypeInfoKind::Integer,
                 ColumnType::LongLong => AnyTypeInfoKind::BigInt,
                 ColumnType::Float => AnyTypeInfoKind::Real,
@@ -153,11 +171,7 @@ impl<'a> TryFrom<&'a MySqlTypeInfo> for AnyTypeInfo {
                 ColumnType::String | ColumnType::VarString | ColumnType::VarChar => {
                     AnyTypeInfoKind::Text
                 }
-                _ => {
-                    return Err(sqlx_core::Error::AnyDriverError(
-                        format!("Any driver does not support MySql type {type_info:?}").into(),
-                    ))
-                }
+                _ => AnyTypeInfoKind::Other(type_info.name().to_owned()),
             },
         })
     }
@@ -185,12 +199,29 @@ impl<'a> TryFrom<&'a MySqlRow> for AnyRow {
     }
 }
 
+impl From<MySqlConnectOptions> for AnyConnectOptions {
+    fn from(options: MySqlConnectOptions) -> Self {
+        let mut any = AnyConnectOptions::from_driver_options(options.clone());
+        any.log_settings = options.log_settings;
+        any.after_connect_sql = options.after_connect_sql;
+        any.connect_timeout = options.connect_timeout;
+        any.tcp_options = options.tcp_options;
+        any
+    }
+}
+
 impl<'a> TryFrom<&'a AnyConnectOptions> for MySqlConnectOptions {
     type Error = sqlx_core::Error;
 
     fn try_from(any_opts: &'a AnyConnectOptions) -> Result<Self, Self::Error> {
-        let mut opts = Self::parse_from_url(&any_opts.database_url)?;
+        let mut opts = match any_opts.downcast_ref::<MySqlConnectOptions>() {
+            Some(opts) => opts.clone(),
+            None => Self::parse_from_url(&any_opts.database_url)?,
+        };
         opts.log_settings = any_opts.log_settings.clone();
+        opts.after_connect_sql = any_opts.after_connect_sql.clone();
+        opts.connect_timeout = any_opts.connect_timeout;
+        opts.tcp_options = any_opts.tcp_options.clone();
         Ok(opts)
     }
 }
@@ -148,8 +148,23 @@ async fn encrypt_rsa<'s>(
 
     // server sends a public key response
     let packet = stream.recv_packet().await?;
-    let rsa_pub_key = &packet[1..];
 
+    // the key is sent as extra auth data
+    if packet.first() != Some(&0x01) {
+        return Err(err_protocol!(
+            "expected 0x01 (AUTH_MORE_DATA) with the server's public key but found 0x{:02x}",
+            packet.first().copied().unwrap_or_default()
+        ));
+    }
+
+    encrypt_password(&packet[1..], password, nonce)
+}
+
+fn encrypt_password(
+    rsa_pub_key: &[u8],
+    password: &str,
+    nonce: &Chain<Bytes, Bytes>,
+) -> Result<Vec<u8>, Error> {
     // xor the password with the given nonce
     let mut pass = to_asciz(password);
 
@@ -195,3 +210,34 @@ fn parse_rsa_pub_key(key: &[u8]) -> Result<RsaPublicKey, Error> {
 
     RsaPublicKey::from_public_key_pem(&pem).map_err(Error::protocol)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Buf;
+    use rsa::pkcs8::{DecodePrivateKey, EncodePublicKey, LineEnding};
+    use rsa::RsaPrivateKey;
+
+    #[test]
+    fn it_encrypts_the_password_with_the_public_key() {
+        let private_key =
+            RsaPrivateKey::from_pkcs8_pem(include_str!("../../../tests/keys/server.key")).unwrap();
+        let public_key = private_key
+            .to_public_key()
+            .to_public_key_pem(LineEnding::LF)
+            .unwrap();
+
+        let nonce = Bytes::from_static(b"01234567").chain(Bytes::from_static(b"89abcdefghij"));
+
+        let encrypted = encrypt_password(public_key.as_bytes(), "password", &nonce).unwrap();
+
+        let mut decrypted = private_key
+            .decrypt(Oaep::new::<sha1::Sha1>(), &encrypted)
+            .unwrap();
+
+        // the nonce is its own inverse under XOR
+        xor_eq(&mut decrypted, b"0123456789abcdefghij");
+
+        assert_eq!(decrypted, b"password\0");
+    }
+}
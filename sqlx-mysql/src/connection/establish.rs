@@ -19,7 +19,15 @@ impl MySqlConnection {
 
         let handshake = match &options.socket {
             Some(path) => crate::net::connect_uds(path, do_handshake).await?,
-            None => crate::net::connect_tcp(&options.host, options.port, do_handshake).await?,
+            None => {
+                crate::net::connect_tcp(
+                    &options.host,
+                    options.port,
+                    &options.tcp_options,
+                    do_handshake,
+                )
+                .await?
+            }
         };
 
         let stream = handshake.await?;
@@ -27,6 +35,7 @@ impl MySqlConnection {
         Ok(Self {
             stream,
             transaction_depth: 0,
+            write_count: 0,
             cache_statement: StatementCache::new(options.statement_cache_capacity),
             log_settings: options.log_settings.clone(),
         })
@@ -80,7 +89,7 @@ impl<'a> DoHandshake<'a> {
         let handshake: Handshake = stream.recv_packet().await?.decode()?;
 
         let mut plugin = handshake.auth_plugin;
-        let nonce = handshake.auth_plugin_data;
+        let mut nonce = handshake.auth_plugin_data;
 
         // FIXME: server version parse is a bit ugly
         // expecting MAJOR.MINOR.PATCH
@@ -147,7 +156,9 @@ impl<'a> DoHandshake<'a> {
                         packet.decode_with(self.options.enable_cleartext_plugin)?;
 
                     plugin = Some(switch.plugin);
-                    let nonce = switch.data.chain(Bytes::new());
+                    // the new plugin may continue authentication (e.g. `caching_sha2_password`
+                    // performing full authentication), which must use the nonce it was given
+                    nonce = switch.data.chain(Bytes::new());
 
                     let response = switch
                         .plugin
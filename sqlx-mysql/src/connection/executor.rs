@@ -12,6 +12,7 @@ use crate::protocol::statement::{
 };
 use crate::protocol::text::{ColumnDefinition, ColumnFlags, Query, TextRow};
 use crate::statement::{MySqlStatement, MySqlStatementMetadata};
+use crate::transaction::is_read_only_sql;
 use crate::HashMap;
 use crate::{
     MySql, MySqlArguments, MySqlColumn, MySqlConnection, MySqlQueryResult, MySqlRow, MySqlTypeInfo,
@@ -22,7 +23,7 @@ use futures_core::future::BoxFuture;
 use futures_core::stream::BoxStream;
 use futures_core::Stream;
 use futures_util::{pin_mut, TryStreamExt};
-use std::{borrow::Cow, sync::Arc};
+use std::{borrow::Cow, sync::Arc, time::Duration};
 
 impl MySqlConnection {
     async fn prepare_statement<'c>(
@@ -94,13 +95,19 @@ impl MySqlConnection {
         sql: &'q str,
         arguments: Option<MySqlArguments>,
         persistent: bool,
+        slow_statements_duration: Option<Duration>,
     ) -> Result<impl Stream<Item = Result<Either<MySqlQueryResult, MySqlRow>, Error>> + 'e, Error>
     {
         let mut logger = QueryLogger::new(sql, self.log_settings.clone());
+        logger.override_slow_statements_duration(slow_statements_duration);
 
         self.stream.wait_until_ready().await?;
         self.stream.waiting.push_back(Waiting::Result);
 
+        if !is_read_only_sql(sql) {
+            self.write_count += 1;
+        }
+
         Ok(Box::pin(try_stream! {
             // make a slot for the shared column data
             // as long as a reference to a row is not held past one iteration, this enables us
@@ -108,7 +115,17 @@ impl MySqlConnection {
             let mut columns = Arc::new(Vec::new());
 
             let (mut column_names, format, mut needs_metadata) = if let Some(arguments) = arguments {
+                logger.set_param_count(arguments.len());
+
                 if persistent && self.cache_statement.is_enabled() {
+                    let cache_hit = self.cache_statement.contains_key(sql);
+                    logger.set_cache_hit(cache_hit);
+
+                    if !cache_hit {
+                        // the statement must be prepared before it can be executed
+                        logger.increment_round_trips();
+                    }
+
                     let (id, metadata) = self
                         .get_or_prepare_statement(sql)
                         .await?;
@@ -126,6 +143,7 @@ impl MySqlConnection {
                     let (id, metadata) = self
                         .prepare_statement(sql)
                         .await?;
+                    logger.increment_round_trips();
 
                     // https://dev.mysql.com/doc/internals/en/com-stmt-execute.html
                     self.stream
@@ -146,6 +164,8 @@ impl MySqlConnection {
                 (Arc::default(), MySqlValueFormat::Text, true)
             };
 
+            logger.increment_round_trips();
+
             loop {
                 // query response is a meta-packet which may be one of:
                 //  Ok, Err, ResultSet, or (unhandled) LocalInfileRequest
@@ -161,6 +181,8 @@ impl MySqlConnection {
                     let done = MySqlQueryResult {
                         rows_affected,
                         last_insert_id: ok.last_insert_id,
+                        warnings: ok.warnings,
+                        info: ok.info,
                     };
 
                     r#yield!(Either::Left(done));
@@ -179,16 +201,18 @@ impl MySqlConnection {
 
                 let num_columns = packet.get_uint_lenenc() as usize; // column count
 
-                if needs_metadata {
+                // a prepared `CALL` does not describe its result sets up front, so the metadata
+                // from `COM_STMT_PREPARE` can't be relied on if the column count differs
+                if needs_metadata || num_columns != column_names.len() {
                     column_names = Arc::new(recv_result_metadata(&mut self.stream, num_columns, Arc::make_mut(&mut columns)).await?);
                 } else {
-                    // next time we hit here, it'll be a new result set and we'll need the
-                    // full metadata
-                    needs_metadata = true;
-
                     recv_result_columns(&mut self.stream, num_columns, Arc::make_mut(&mut columns)).await?;
                 }
 
+                // next time we hit here, it'll be a new result set and we'll need the
+                // full metadata
+                needs_metadata = true;
+
                 // finally, there will be none or many result-rows
                 loop {
                     let packet = self.stream.recv_packet().await?;
@@ -199,6 +223,8 @@ impl MySqlConnection {
                         r#yield!(Either::Left(MySqlQueryResult {
                             rows_affected: 0,
                             last_insert_id: 0,
+                            warnings: eof.warnings,
+                            info: String::new(),
                         }));
 
                         if eof.status.contains(Status::SERVER_MORE_RESULTS_EXISTS) {
@@ -246,9 +272,10 @@ impl<'c> Executor<'c> for &'c mut MySqlConnection {
         let sql = query.sql();
         let arguments = query.take_arguments();
         let persistent = query.persistent();
+        let slow_statements_duration = query.slow_statements_duration();
 
         Box::pin(try_stream! {
-            let s = self.run(sql, arguments, persistent).await?;
+            let s = self.run(sql, arguments, persistent, slow_statements_duration).await?;
             pin_mut!(s);
 
             while let Some(v) = s.try_next().await? {
@@ -8,7 +8,8 @@ pub(crate) use stream::{MySqlStream, Waiting};
 use crate::common::StatementCache;
 use crate::error::Error;
 use crate::protocol::statement::StmtClose;
-use crate::protocol::text::{Ping, Quit};
+use crate::protocol::text::{Ping, Quit, SetOption};
+use crate::protocol::Capabilities;
 use crate::statement::MySqlStatementMetadata;
 use crate::transaction::Transaction;
 use crate::{MySql, MySqlConnectOptions};
@@ -31,6 +32,9 @@ pub struct MySqlConnection {
     // transaction status
     pub(crate) transaction_depth: usize,
 
+    // number of statements run that may have written to the database
+    pub(crate) write_count: u64,
+
     // cache by query string to the statement id and metadata
     cache_statement: StatementCache<(u32, MySqlStatementMetadata)>,
 
@@ -43,6 +47,28 @@ impl Debug for MySqlConnection {
     }
 }
 
+impl MySqlConnection {
+    /// Whether the connection was opened with [`MySqlConnectOptions::multi_statements()`].
+    pub(crate) fn multi_statements(&self) -> bool {
+        self.stream
+            .capabilities
+            .contains(Capabilities::MULTI_STATEMENTS)
+    }
+
+    /// Allow or forbid multiple statements in one query for the rest of the session.
+    pub(crate) async fn set_multi_statements(&mut self, enabled: bool) -> Result<(), Error> {
+        self.stream.wait_until_ready().await?;
+        self.stream
+            .send_packet(SetOption::MultiStatements(enabled))
+            .await?;
+
+        // the server replies with an EOF packet, or an ERR packet returned as an error
+        self.stream.recv_packet().await?;
+
+        Ok(())
+    }
+}
+
 impl Connection for MySqlConnection {
     type Database = MySql;
 
@@ -4,7 +4,7 @@ use std::ops::{Deref, DerefMut};
 use bytes::{Buf, Bytes, BytesMut};
 
 use crate::collation::{CharSet, Collation};
-use crate::error::Error;
+use crate::error::{error_codes, Error};
 use crate::io::MySqlBufExt;
 use crate::io::{Decode, Encode};
 use crate::net::{BufferedSocket, Socket};
@@ -22,6 +22,8 @@ pub struct MySqlStream<S = Box<dyn Socket>> {
     pub(crate) charset: CharSet,
     pub(crate) collation: Collation,
     pub(crate) is_tls: bool,
+    // set when the server rolled back the active transaction because of an error
+    pub(crate) transaction_aborted: bool,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -47,7 +49,6 @@ impl<S: Socket> MySqlStream<S> {
             | Capabilities::TRANSACTIONS
             | Capabilities::SECURE_CONNECTION
             | Capabilities::PLUGIN_AUTH_LENENC_DATA
-            | Capabilities::MULTI_STATEMENTS
             | Capabilities::MULTI_RESULTS
             | Capabilities::PLUGIN_AUTH
             | Capabilities::PS_MULTI_RESULTS
@@ -57,6 +58,10 @@ impl<S: Socket> MySqlStream<S> {
             capabilities |= Capabilities::CONNECT_WITH_DB;
         }
 
+        if options.multi_statements {
+            capabilities |= Capabilities::MULTI_STATEMENTS;
+        }
+
         Self {
             waiting: VecDeque::new(),
             capabilities,
@@ -66,6 +71,7 @@ impl<S: Socket> MySqlStream<S> {
             charset,
             socket: BufferedSocket::new(socket),
             is_tls: false,
+            transaction_aborted: false,
         }
     }
 
@@ -174,9 +180,13 @@ impl<S: Socket> MySqlStream<S> {
 
             // instead of letting this packet be looked at everywhere, we check here
             // and emit a proper Error
-            return Err(
-                MySqlDatabaseError(ErrPacket::decode_with(payload, self.capabilities)?).into(),
-            );
+            let err = ErrPacket::decode_with(payload, self.capabilities)?;
+
+            if err.error_code == error_codes::ER_LOCK_DEADLOCK {
+                self.transaction_aborted = true;
+            }
+
+            return Err(MySqlDatabaseError(err).into());
         }
 
         Ok(Packet(payload))
@@ -223,6 +233,7 @@ impl<S: Socket> MySqlStream<S> {
             charset: self.charset,
             collation: self.collation,
             is_tls: self.is_tls,
+            transaction_aborted: self.transaction_aborted,
         }
     }
 }
@@ -4,6 +4,7 @@ use crate::{
     MySqlTransactionManager, MySqlTypeInfo,
 };
 pub(crate) use sqlx_core::database::{Database, HasStatementCache};
+use sqlx_core::raw_sql::SqlDialect;
 
 /// MySQL database driver.
 #[derive(Debug)]
@@ -33,6 +34,8 @@ impl Database for MySql {
     const NAME: &'static str = "MySQL";
 
     const URL_SCHEMES: &'static [&'static str] = &["mysql", "mariadb"];
+
+    const SQL_DIALECT: SqlDialect = SqlDialect::MySql;
 }
 
 impl HasStatementCache for MySql {}
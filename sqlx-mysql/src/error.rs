@@ -31,6 +31,38 @@ impl MySqlDatabaseError {
     }
 }
 
+// The error packet has no separate fields for the affected objects, so they are parsed from
+// the messages of the errors that include them.
+impl MySqlDatabaseError {
+    /// The `'{table}.{key}'` of a duplicate entry, or just `'{key}'` before MySQL 8.0.19.
+    fn duplicate_key(&self) -> Option<&str> {
+        match self.number() {
+            error_codes::ER_DUP_ENTRY | error_codes::ER_DUP_ENTRY_WITH_KEY_NAME => {
+                // the entry itself comes first and could contain anything
+                let message = self.message();
+                let start = message.rfind(" for key '")? + " for key '".len();
+                message[start..].strip_suffix('\'')
+            }
+            _ => None,
+        }
+    }
+
+    fn is_foreign_key_error(&self) -> bool {
+        matches!(
+            self.number(),
+            error_codes::ER_NO_REFERENCED_ROW_2 | error_codes::ER_ROW_IS_REFERENCED_2
+        )
+    }
+}
+
+/// Returns the part of `message` between the first `prefix` and the next `end`.
+fn find_between<'a>(message: &'a str, prefix: &str, end: char) -> Option<&'a str> {
+    let start = message.find(prefix)? + prefix.len();
+    let rest = &message[start..];
+
+    Some(&rest[..rest.find(end)?])
+}
+
 impl Debug for MySqlDatabaseError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_struct("MySqlDatabaseError")
@@ -79,6 +111,46 @@ impl DatabaseError for MySqlDatabaseError {
         self
     }
 
+    fn constraint(&self) -> Option<&str> {
+        if let Some(key) = self.duplicate_key() {
+            return Some(key.rsplit_once('.').map_or(key, |(_, key)| key));
+        }
+
+        match self.number() {
+            // (`db`.`child`, CONSTRAINT `fk` FOREIGN KEY (`parent_id`) REFERENCES ...)
+            _ if self.is_foreign_key_error() => find_between(self.message(), "CONSTRAINT `", '`'),
+            // Check constraint 'name' is violated.
+            error_codes::ER_CHECK_CONSTRAINT_VIOLATED => {
+                find_between(self.message(), "Check constraint '", '\'')
+            }
+            _ => None,
+        }
+    }
+
+    fn table(&self) -> Option<&str> {
+        if let Some(key) = self.duplicate_key() {
+            return key.rsplit_once('.').map(|(table, _)| table);
+        }
+
+        if self.is_foreign_key_error() {
+            // the table is qualified with the database
+            return find_between(self.message(), "`.`", '`');
+        }
+
+        None
+    }
+
+    fn column(&self) -> Option<&str> {
+        match self.number() {
+            _ if self.is_foreign_key_error() => find_between(self.message(), "FOREIGN KEY (`", '`'),
+            // Column 'name' cannot be null
+            error_codes::ER_BAD_NULL_ERROR => find_between(self.message(), "Column '", '\''),
+            // Field 'name' doesn't have a default value
+            error_codes::ER_NO_DEFAULT_FOR_FIELD => find_between(self.message(), "Field '", '\''),
+            _ => None,
+        }
+    }
+
     fn kind(&self) -> ErrorKind {
         match self.number() {
             error_codes::ER_DUP_KEY
@@ -100,6 +172,14 @@ impl DatabaseError for MySqlDatabaseError {
 
             error_codes::ER_CHECK_CONSTRAINT_VIOLATED => ErrorKind::CheckViolation,
 
+            error_codes::ER_LOCK_DEADLOCK => ErrorKind::Deadlock,
+
+            error_codes::ER_LOCK_WAIT_TIMEOUT | error_codes::ER_QUERY_TIMEOUT => ErrorKind::Timeout,
+
+            error_codes::ER_CON_COUNT_ERROR | error_codes::ER_SERVER_SHUTDOWN => {
+                ErrorKind::Connection
+            }
+
             _ => ErrorKind::Other,
         }
     }
@@ -154,4 +234,99 @@ pub(crate) mod error_codes {
     ///
     /// Only available after 8.0.16.
     pub const ER_CHECK_CONSTRAINT_VIOLATED: u16 = 3819;
+
+    /// Caused when a transaction is chosen as the victim of a deadlock.
+    /// The server rolls back the whole transaction.
+    pub const ER_LOCK_DEADLOCK: u16 = 1213;
+    /// Caused when waiting for a row lock exceeds `innodb_lock_wait_timeout`.
+    /// Only the statement is rolled back, unless `innodb_rollback_on_timeout` is enabled.
+    pub const ER_LOCK_WAIT_TIMEOUT: u16 = 1205;
+    /// Caused when a `SELECT` exceeds `max_execution_time`.
+    pub const ER_QUERY_TIMEOUT: u16 = 3024;
+    /// Caused when connecting while `max_connections` clients are already connected.
+    pub const ER_CON_COUNT_ERROR: u16 = 1040;
+    /// Caused when the server is shutting down.
+    pub const ER_SERVER_SHUTDOWN: u16 = 1053;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(error_code: u16, message: &str) -> MySqlDatabaseError {
+        MySqlDatabaseError(ErrPacket {
+            error_code,
+            sql_state: None,
+            error_message: message.to_owned(),
+        })
+    }
+
+    #[test]
+    fn it_parses_duplicate_entries() {
+        let err = error(
+            error_codes::ER_DUP_ENTRY,
+            "Duplicate entry 'a' for key 'b' for key 'users.users_email_key'",
+        );
+        assert_eq!(DatabaseError::constraint(&err), Some("users_email_key"));
+        assert_eq!(DatabaseError::table(&err), Some("users"));
+
+        // before MySQL 8.0.19
+        let err = error(
+            error_codes::ER_DUP_ENTRY,
+            "Duplicate entry '1' for key 'PRIMARY'",
+        );
+        assert_eq!(DatabaseError::constraint(&err), Some("PRIMARY"));
+        assert_eq!(DatabaseError::table(&err), None);
+    }
+
+    #[test]
+    fn it_parses_foreign_key_violations() {
+        let err = error(
+            error_codes::ER_NO_REFERENCED_ROW_2,
+            "Cannot add or update a child row: a foreign key constraint fails \
+             (`test`.`posts`, CONSTRAINT `posts_user_id_fkey` FOREIGN KEY (`user_id`) \
+             REFERENCES `users` (`id`))",
+        );
+        assert_eq!(DatabaseError::constraint(&err), Some("posts_user_id_fkey"));
+        assert_eq!(DatabaseError::table(&err), Some("posts"));
+        assert_eq!(DatabaseError::column(&err), Some("user_id"));
+    }
+
+    #[test]
+    fn it_parses_not_null_and_check_violations() {
+        let err = error(
+            error_codes::ER_BAD_NULL_ERROR,
+            "Column 'name' cannot be null",
+        );
+        assert_eq!(DatabaseError::column(&err), Some("name"));
+        assert_eq!(DatabaseError::table(&err), None);
+
+        let err = error(
+            error_codes::ER_CHECK_CONSTRAINT_VIOLATED,
+            "Check constraint 'price_positive' is violated.",
+        );
+        assert_eq!(DatabaseError::constraint(&err), Some("price_positive"));
+        assert_eq!(DatabaseError::column(&err), None);
+    }
+
+    #[test]
+    fn it_classifies_retryable_errors() {
+        let err = error(
+            error_codes::ER_LOCK_DEADLOCK,
+            "Deadlock found when trying to get lock; try restarting transaction",
+        );
+        assert_eq!(DatabaseError::kind(&err), ErrorKind::Deadlock);
+
+        let err = error(
+            error_codes::ER_LOCK_WAIT_TIMEOUT,
+            "Lock wait timeout exceeded; try restarting transaction",
+        );
+        assert_eq!(DatabaseError::kind(&err), ErrorKind::Timeout);
+
+        let err = Error::Database(Box::new(err));
+        assert!(err.is_retryable());
+
+        let err = error(1064, "You have an error in your SQL syntax");
+        assert!(!Error::Database(Box::new(err)).is_retryable());
+    }
 }
@@ -96,6 +96,24 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
+    fn migrations_table_exists(&mut self) -> BoxFuture<'_, Result<bool, MigrateError>> {
+        Box::pin(async move {
+            // language=MySQL
+            let exists: bool = query_scalar(
+                r#"
+SELECT EXISTS (
+    SELECT 1 FROM information_schema.tables
+    WHERE table_schema = DATABASE() AND table_name = '_sqlx_migrations'
+)
+                "#,
+            )
+            .fetch_one(self)
+            .await?;
+
+            Ok(exists)
+        })
+    }
+
     fn dirty_version(&mut self) -> BoxFuture<'_, Result<Option<i64>, MigrateError>> {
         Box::pin(async move {
             // language=SQL
@@ -113,18 +131,37 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         &mut self,
     ) -> BoxFuture<'_, Result<Vec<AppliedMigration>, MigrateError>> {
         Box::pin(async move {
-            // language=SQL
-            let rows: Vec<(i64, Vec<u8>)> =
-                query_as("SELECT version, checksum FROM _sqlx_migrations ORDER BY version")
-                    .fetch_all(self)
-                    .await?;
+            // language=MySQL
+            let rows: Vec<(i64, Vec<u8>, String, i64, i64, bool)> = query_as(
+                r#"
+SELECT
+    version,
+    checksum,
+    description,
+    CAST(UNIX_TIMESTAMP(installed_on) * 1000000 AS SIGNED),
+    execution_time,
+    success
+FROM _sqlx_migrations
+ORDER BY version
+                "#,
+            )
+            .fetch_all(self)
+            .await?;
 
             let migrations = rows
                 .into_iter()
-                .map(|(version, checksum)| AppliedMigration {
-                    version,
-                    checksum: checksum.into(),
-                })
+                .map(
+                    |(version, checksum, description, installed_on, execution_time, success)| {
+                        AppliedMigration::from_row(
+                            version,
+                            checksum,
+                            description,
+                            installed_on,
+                            execution_time,
+                            success,
+                        )
+                    },
+                )
                 .collect();
 
             Ok(migrations)
@@ -132,30 +169,51 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
     }
 
     fn lock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
+        self.lock_with(None, None)
+    }
+
+    fn unlock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
+        self.unlock_with(None)
+    }
+
+    fn lock_with(
+        &mut self,
+        key: Option<i64>,
+        timeout: Option<Duration>,
+    ) -> BoxFuture<'_, Result<(), MigrateError>> {
         Box::pin(async move {
-            let database_name = current_database(self).await?;
-            let lock_id = generate_lock_id(&database_name);
+            let lock_id = lock_id(self, key).await?;
 
             // create an application lock over the database
-            // this function will not return until the lock is acquired
+            // this function will not return until the lock is acquired or the timeout expires
 
-            // https://www.postgresql.org/docs/current/explicit-locking.html#ADVISORY-LOCKS
-            // https://www.postgresql.org/docs/current/functions-admin.html#FUNCTIONS-ADVISORY-LOCKS-TABLE
+            // https://dev.mysql.com/doc/refman/8.0/en/locking-functions.html#function_get-lock
+
+            // a negative timeout waits indefinitely
+            let timeout = timeout.map_or(-1, |timeout| {
+                let secs = timeout.as_secs() + u64::from(timeout.subsec_nanos() > 0);
+                i64::try_from(secs).unwrap_or(i64::MAX)
+            });
 
             // language=MySQL
-            let _ = query("SELECT GET_LOCK(?, -1)")
+            let locked: Option<i64> = query_scalar("SELECT GET_LOCK(?, ?)")
                 .bind(lock_id)
-                .execute(self)
+                .bind(timeout)
+                .fetch_one(self)
                 .await?;
 
+            // `0` if the timeout expired; `NULL` on error, e.g. if the thread was killed
+            if locked != Some(1) {
+                return Err(MigrateError::LockTimeout);
+            }
+
             Ok(())
         })
     }
 
-    fn unlock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
+    fn unlock_with(&mut self, key: Option<i64>) -> BoxFuture<'_, Result<(), MigrateError>> {
         Box::pin(async move {
-            let database_name = current_database(self).await?;
-            let lock_id = generate_lock_id(&database_name);
+            let lock_id = lock_id(self, key).await?;
 
             // language=MySQL
             let _ = query("SELECT RELEASE_LOCK(?)")
@@ -177,46 +235,16 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
             // The `execution_time` however can only be measured for the whole transaction. This value _only_ exists for
             // data lineage and debugging reasons, so it is not super important if it is lost. So we initialize it to -1
             // and update it once the actual transaction completed.
-            let mut tx = self.begin().await?;
             let start = Instant::now();
 
-            // For MySQL we cannot really isolate migrations due to implicit commits caused by table modification, see
-            // https://dev.mysql.com/doc/refman/8.0/en/implicit-commit.html
-            //
-            // To somewhat try to detect this, we first insert the migration into the migration table with
-            // `success=FALSE` and later modify the flag.
-            //
-            // language=MySQL
-            let _ = query(
-                r#"
-    INSERT INTO _sqlx_migrations ( version, description, success, checksum, execution_time )
-    VALUES ( ?, ?, FALSE, ?, -1 )
-                "#,
-            )
-            .bind(migration.version)
-            .bind(&*migration.description)
-            .bind(&*migration.checksum)
-            .execute(&mut *tx)
-            .await?;
-
-            let _ = tx
-                .execute(&*migration.sql)
-                .await
-                .map_err(|e| MigrateError::ExecuteMigration(e, migration.version))?;
-
-            // language=MySQL
-            let _ = query(
-                r#"
-    UPDATE _sqlx_migrations
-    SET success = TRUE
-    WHERE version = ?
-                "#,
-            )
-            .bind(migration.version)
-            .execute(&mut *tx)
-            .await?;
-
-            tx.commit().await?;
+            if migration.no_tx() {
+                // opted out with `-- sqlx:no-transaction`
+                execute_migration(&mut *self, migration).await?;
+            } else {
+                let mut tx = self.begin().await?;
+                execute_migration(&mut tx, migration).await?;
+                tx.commit().await?;
+            }
 
             // Update `elapsed_time`.
             // NOTE: The process may disconnect/die at this point, so the elapsed time value might be lost. We accept
@@ -247,42 +275,133 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         Box::pin(async move {
             // Use a single transaction for the actual migration script and the essential bookeeping so we never
             // execute migrations twice. See https://github.com/launchbadge/sqlx/issues/1966.
-            let mut tx = self.begin().await?;
             let start = Instant::now();
 
-            // For MySQL we cannot really isolate migrations due to implicit commits caused by table modification, see
-            // https://dev.mysql.com/doc/refman/8.0/en/implicit-commit.html
-            //
-            // To somewhat try to detect this, we first insert the migration into the migration table with
-            // `success=FALSE` and later remove the migration altogether.
-            //
-            // language=MySQL
-            let _ = query(
-                r#"
-    UPDATE _sqlx_migrations
-    SET success = FALSE
-    WHERE version = ?
-                "#,
-            )
-            .bind(migration.version)
-            .execute(&mut *tx)
-            .await?;
+            if migration.no_tx() {
+                // opted out with `-- sqlx:no-transaction`
+                revert_migration(&mut *self, migration).await?;
+            } else {
+                let mut tx = self.begin().await?;
+                revert_migration(&mut tx, migration).await?;
+                tx.commit().await?;
+            }
+
+            let elapsed = start.elapsed();
 
-            tx.execute(&*migration.sql).await?;
+            Ok(elapsed)
+        })
+    }
 
+    fn update_checksum<'e: 'm, 'm>(
+        &'e mut self,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<(), MigrateError>> {
+        Box::pin(async move {
             // language=SQL
-            let _ = query(r#"DELETE FROM _sqlx_migrations WHERE version = ?"#)
+            let _ = query(r#"UPDATE _sqlx_migrations SET checksum = ? WHERE version = ?"#)
+                .bind(&*migration.checksum)
                 .bind(migration.version)
-                .execute(&mut *tx)
+                .execute(self)
                 .await?;
 
-            tx.commit().await?;
+            Ok(())
+        })
+    }
+}
 
-            let elapsed = start.elapsed();
+async fn execute_migration(
+    conn: &mut MySqlConnection,
+    migration: &Migration,
+) -> Result<(), MigrateError> {
+    // For MySQL we cannot really isolate migrations due to implicit commits caused by table modification, see
+    // https://dev.mysql.com/doc/refman/8.0/en/implicit-commit.html
+    //
+    // To somewhat try to detect this, we first insert the migration into the migration table with
+    // `success=FALSE` and later modify the flag.
+    //
+    // language=MySQL
+    let _ = query(
+        r#"
+    INSERT INTO _sqlx_migrations ( version, description, success, checksum, execution_time )
+    VALUES ( ?, ?, FALSE, ?, -1 )
+            "#,
+    )
+    .bind(migration.version)
+    .bind(&*migration.description)
+    .bind(&*migration.checksum)
+    .execute(&mut *conn)
+    .await?;
+
+    // Migration scripts usually contain several statements, so allow them for the duration of the
+    // script if the connection was opened without `multi_statements`.
+    let multi_statements = conn.multi_statements();
+    if !multi_statements {
+        conn.set_multi_statements(true).await?;
+    }
 
-            Ok(elapsed)
-        })
+    let res = migration.execute(&mut *conn).await;
+
+    if !multi_statements {
+        conn.set_multi_statements(false).await?;
+    }
+
+    res?;
+
+    // language=MySQL
+    let _ = query(
+        r#"
+    UPDATE _sqlx_migrations
+    SET success = TRUE
+    WHERE version = ?
+            "#,
+    )
+    .bind(migration.version)
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+async fn revert_migration(
+    conn: &mut MySqlConnection,
+    migration: &Migration,
+) -> Result<(), MigrateError> {
+    // See `execute_migration()`; here we mark the migration as failed until it has been
+    // reverted and then remove it altogether.
+    //
+    // language=MySQL
+    let _ = query(
+        r#"
+    UPDATE _sqlx_migrations
+    SET success = FALSE
+    WHERE version = ?
+            "#,
+    )
+    .bind(migration.version)
+    .execute(&mut *conn)
+    .await?;
+
+    // See `execute_migration()`.
+    let multi_statements = conn.multi_statements();
+    if !multi_statements {
+        conn.set_multi_statements(true).await?;
+    }
+
+    let res = conn.execute(&*migration.sql).await;
+
+    if !multi_statements {
+        conn.set_multi_statements(false).await?;
     }
+
+    res?;
+
+    // language=SQL
+    let _ = query(r#"DELETE FROM _sqlx_migrations WHERE version = ?"#)
+        .bind(migration.version)
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(())
 }
 
 async fn current_database(conn: &mut MySqlConnection) -> Result<String, MigrateError> {
@@ -290,6 +409,13 @@ async fn current_database(conn: &mut MySqlConnection) -> Result<String, MigrateE
     Ok(query_scalar("SELECT DATABASE()").fetch_one(conn).await?)
 }
 
+async fn lock_id(conn: &mut MySqlConnection, key: Option<i64>) -> Result<String, MigrateError> {
+    match key {
+        Some(key) => Ok(format!("{key:x}")),
+        None => Ok(generate_lock_id(&current_database(conn).await?)),
+    }
+}
+
 // inspired from rails: https://github.com/rails/rails/blob/6e49cc77ab3d16c06e12f93158eaf3e507d4120e/activerecord/lib/active_record/migration.rb#L1308
 fn generate_lock_id(database_name: &str) -> String {
     const CRC_IEEE: crc::Crc<u32> = crc::Crc::<u32>::new(&crc::CRC_32_ISO_HDLC);
@@ -1,10 +1,16 @@
-use crate::connection::ConnectOptions;
+use crate::connection::{connect_with_timeout, ConnectOptions};
 use crate::error::Error;
 use crate::executor::Executor;
+use crate::logger::QueryExecuted;
+use crate::net::connect_failover;
 use crate::{MySqlConnectOptions, MySqlConnection};
 use futures_core::future::BoxFuture;
 use log::LevelFilter;
 use sqlx_core::Url;
+use std::borrow::Cow;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::Arc;
 use std::time::Duration;
 
 impl ConnectOptions for MySqlConnectOptions {
@@ -22,8 +28,19 @@ impl ConnectOptions for MySqlConnectOptions {
     where
         Self::Connection: Sized,
     {
-        Box::pin(async move {
-            let mut conn = MySqlConnection::establish(self).await?;
+        Box::pin(connect_with_timeout(self.connect_timeout, async move {
+            let mut conn = if self.fallback_hosts.is_empty() || self.socket.is_some() {
+                MySqlConnection::establish(self).await?
+            } else {
+                let hosts = std::iter::once((self.host.clone(), self.port))
+                    .chain(self.fallback_hosts.iter().cloned());
+
+                connect_failover(hosts, |host, port| {
+                    let options = self.clone().host(host).port(port);
+                    async move { MySqlConnection::establish(&options).await }
+                })
+                .await?
+            };
 
             // After the connection is established, we initialize by configuring a few
             // connection parameters
@@ -81,8 +98,12 @@ impl ConnectOptions for MySqlConnectOptions {
                     .await?;
             }
 
+            for sql in &self.after_connect_sql {
+                conn.execute(sql.as_str()).await?;
+            }
+
             Ok(conn)
-        })
+        }))
     }
 
     fn log_statements(mut self, level: LevelFilter) -> Self {
@@ -94,4 +115,58 @@ impl ConnectOptions for MySqlConnectOptions {
         self.log_settings.log_slow_statements(level, duration);
         self
     }
+
+    fn log_summary_word_count(mut self, count: usize) -> Self {
+        self.log_settings.log_summary_word_count(count);
+        self
+    }
+
+    fn log_max_statement_length(mut self, len: usize) -> Self {
+        self.log_settings.log_max_statement_length(len);
+        self
+    }
+
+    fn redact_statements<F>(mut self, redactor: F) -> Self
+    where
+        F: Fn(&str) -> Cow<'_, str> + Send + Sync + 'static,
+    {
+        self.log_settings.redact_statements(redactor);
+        self
+    }
+
+    fn on_query_executed<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&QueryExecuted<'_>) + Send + Sync + 'static,
+    {
+        self.log_settings.on_query_executed(callback);
+        self
+    }
+
+    fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    fn tcp_keepalive(mut self, time: Duration) -> Self {
+        self.tcp_options.keepalive = Some(time);
+        self
+    }
+
+    fn tcp_user_timeout(mut self, timeout: Duration) -> Self {
+        self.tcp_options.user_timeout = Some(timeout);
+        self
+    }
+
+    fn resolver<F>(mut self, resolver: F) -> Self
+    where
+        F: Fn(&str) -> BoxFuture<'static, io::Result<Vec<SocketAddr>>> + Send + Sync + 'static,
+    {
+        self.tcp_options.resolver = Some(Arc::new(resolver));
+        self
+    }
+
+    fn after_connect_sql(mut self, sql: impl Into<String>) -> Self {
+        self.after_connect_sql.push(sql.into());
+        self
+    }
 }
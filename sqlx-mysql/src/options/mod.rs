@@ -1,10 +1,14 @@
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 mod connect;
 mod parse;
 mod ssl_mode;
 
-use crate::{connection::LogSettings, net::tls::CertificateInput};
+use crate::{
+    connection::LogSettings,
+    net::{tls::CertificateInput, TcpOptions},
+};
 pub use ssl_mode::MySqlSslMode;
 
 /// Options and flags which can be used to configure a MySQL connection.
@@ -63,6 +67,7 @@ pub use ssl_mode::MySqlSslMode;
 pub struct MySqlConnectOptions {
     pub(crate) host: String,
     pub(crate) port: u16,
+    pub(crate) fallback_hosts: Vec<(String, u16)>,
     pub(crate) socket: Option<PathBuf>,
     pub(crate) username: String,
     pub(crate) password: Option<String>,
@@ -75,11 +80,15 @@ pub struct MySqlConnectOptions {
     pub(crate) charset: String,
     pub(crate) collation: Option<String>,
     pub(crate) log_settings: LogSettings,
+    pub(crate) after_connect_sql: Vec<String>,
+    pub(crate) connect_timeout: Option<Duration>,
+    pub(crate) tcp_options: TcpOptions,
     pub(crate) pipes_as_concat: bool,
     pub(crate) enable_cleartext_plugin: bool,
     pub(crate) no_engine_subsitution: bool,
     pub(crate) timezone: Option<String>,
     pub(crate) set_names: bool,
+    pub(crate) multi_statements: bool,
 }
 
 impl Default for MySqlConnectOptions {
@@ -94,6 +103,7 @@ impl MySqlConnectOptions {
         Self {
             port: 3306,
             host: String::from("localhost"),
+            fallback_hosts: Vec::new(),
             socket: None,
             username: String::from("root"),
             password: None,
@@ -106,11 +116,15 @@ impl MySqlConnectOptions {
             ssl_client_key: None,
             statement_cache_capacity: 100,
             log_settings: Default::default(),
+            after_connect_sql: Vec::new(),
+            connect_timeout: None,
+            tcp_options: TcpOptions::default(),
             pipes_as_concat: true,
             enable_cleartext_plugin: false,
             no_engine_subsitution: true,
             timezone: Some(String::from("+00:00")),
             set_names: true,
+            multi_statements: false,
         }
     }
 
@@ -131,6 +145,17 @@ impl MySqlConnectOptions {
         self
     }
 
+    /// Adds a host to try, in order, if connecting to the primary [`host`][Self::host] fails.
+    ///
+    /// A connection string may also list several hosts for failover, separated by commas:
+    /// `mysql://host1:3306,host2:3306/db`.
+    ///
+    /// Fallback hosts are ignored when connecting over a Unix socket.
+    pub fn fallback_host(mut self, host: &str, port: u16) -> Self {
+        self.fallback_hosts.push((host.to_owned(), port));
+        self
+    }
+
     /// Pass a path to a Unix socket. This changes the connection stream from
     /// TCP to UDS.
     ///
@@ -398,6 +423,28 @@ impl MySqlConnectOptions {
         self.set_names = flag_val;
         self
     }
+
+    /// Sets the flag that enables or disables the `CLIENT_MULTI_STATEMENTS` capability,
+    /// which allows executing multiple statements separated by semicolons (`;`) in one query
+    /// string, e.g. with [`raw_sql()`][sqlx_core::raw_sql::raw_sql].
+    ///
+    /// Each statement returns its own result set; these are all read, in order, by
+    /// [`Executor::fetch_many()`][sqlx_core::executor::Executor::fetch_many] and
+    /// [`Executor::execute_many()`][sqlx_core::executor::Executor::execute_many].
+    /// Results of stored procedures called with `CALL` are returned the same way, regardless
+    /// of this flag.
+    ///
+    /// Disabled by default, which limits the damage of SQL injection through queries built with
+    /// string formatting, since an injected statement can no longer be appended to the intended
+    /// one. Prepared statements, used by `query()` and the query macros, never allow multiple
+    /// statements.
+    ///
+    /// Migrations and test fixtures are always run with multiple statements allowed, regardless
+    /// of this flag.
+    pub fn multi_statements(mut self, flag_val: bool) -> Self {
+        self.multi_statements = flag_val;
+        self
+    }
 }
 
 impl MySqlConnectOptions {
@@ -429,6 +476,20 @@ impl MySqlConnectOptions {
         self.port
     }
 
+    /// Get the fallback hosts and their ports, in the order they will be tried.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_mysql::MySqlConnectOptions;
+    /// let options = MySqlConnectOptions::new()
+    ///     .fallback_host("replica.db", 3307);
+    /// assert_eq!(options.get_fallback_hosts(), [("replica.db".to_owned(), 3307)]);
+    /// ```
+    pub fn get_fallback_hosts(&self) -> &[(String, u16)] {
+        &self.fallback_hosts
+    }
+
     /// Get the socket path.
     ///
     /// # Example
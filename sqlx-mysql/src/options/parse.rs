@@ -1,6 +1,7 @@
 use std::str::FromStr;
 
 use percent_encoding::{percent_decode_str, utf8_percent_encode, NON_ALPHANUMERIC};
+use sqlx_core::net::split_url_hosts;
 use sqlx_core::Url;
 
 use crate::{error::Error, MySqlSslMode};
@@ -112,7 +113,7 @@ impl MySqlConnectOptions {
         url.query_pairs_mut().append_pair("charset", &self.charset);
 
         if let Some(collation) = &self.collation {
-            url.query_pairs_mut().append_pair("charset", &collation);
+            url.query_pairs_mut().append_pair("collation", &collation);
         }
 
         if let Some(ssl_client_cert) = &self.ssl_client_cert {
@@ -143,8 +144,18 @@ impl FromStr for MySqlConnectOptions {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Error> {
+        // `Url` only understands a single host, so split off any extra ones used for failover.
+        let (s, fallback_hosts) = split_url_hosts(s)?;
+
         let url: Url = s.parse().map_err(Error::config)?;
-        Self::parse_from_url(&url)
+        let mut options = Self::parse_from_url(&url)?;
+
+        for (host, port) in fallback_hosts {
+            let port = port.unwrap_or(options.port);
+            options = options.fallback_host(&host, port);
+        }
+
+        Ok(options)
     }
 }
 
@@ -176,3 +187,41 @@ fn it_returns_the_parsed_url() {
 
     assert_eq!(expected_url, opts.build_url());
 }
+
+#[test]
+fn it_parses_multiple_hosts_for_failover() {
+    let url = "mysql://username:p@ssw0rd@primary,replica:3307/database";
+    let opts = MySqlConnectOptions::from_str(url).unwrap();
+
+    assert_eq!("primary", &opts.host);
+    assert_eq!(Some("p@ssw0rd".into()), opts.password);
+    assert_eq!(opts.fallback_hosts, [("replica".to_owned(), 3307)]);
+}
+
+#[test]
+fn it_parses_socket_correctly_from_parameter() {
+    let url = "mysql:///database?socket=/var/run/mysqld/mysqld.sock";
+    let opts = MySqlConnectOptions::from_str(url).unwrap();
+
+    assert_eq!(Some("/var/run/mysqld/mysqld.sock".into()), opts.socket);
+    assert_eq!(Some("database".into()), opts.database);
+}
+
+#[test]
+fn it_parses_postgres_ssl_modes() {
+    use crate::MySqlSslMode;
+
+    for (mode, expected) in [
+        ("DISABLE", MySqlSslMode::Disabled),
+        ("prefer", MySqlSslMode::Preferred),
+        ("require", MySqlSslMode::Required),
+        ("verify-ca", MySqlSslMode::VerifyCa),
+        ("VERIFY_FULL", MySqlSslMode::VerifyIdentity),
+        ("VERIFY_IDENTITY", MySqlSslMode::VerifyIdentity),
+    ] {
+        let url = format!("mysql://localhost/database?ssl-mode={mode}");
+        let opts = MySqlConnectOptions::from_str(&url).unwrap();
+
+        assert_eq!(opts.ssl_mode, expected);
+    }
+}
@@ -4,7 +4,7 @@ use std::str::FromStr;
 /// Options for controlling the desired security state of the connection to the MySQL server.
 ///
 /// It is used by the [`ssl_mode`](super::MySqlConnectOptions::ssl_mode) method.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MySqlSslMode {
     /// Establish an unencrypted connection.
     Disabled,
@@ -27,6 +27,8 @@ pub enum MySqlSslMode {
     /// Like `VerifyCa`, but additionally perform host name identity verification by
     /// checking the host name the client uses for connecting to the server against the
     /// identity in the certificate that the server sends to the client.
+    ///
+    /// This is equivalent to `verify-full` for Postgres, which is also accepted when parsing.
     VerifyIdentity,
 }
 
@@ -40,12 +42,13 @@ impl FromStr for MySqlSslMode {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Error> {
-        Ok(match &*s.to_ascii_lowercase() {
-            "disabled" => MySqlSslMode::Disabled,
-            "preferred" => MySqlSslMode::Preferred,
-            "required" => MySqlSslMode::Required,
+        // the Postgres `sslmode` names are accepted as well
+        Ok(match &*s.to_ascii_lowercase().replace('-', "_") {
+            "disabled" | "disable" => MySqlSslMode::Disabled,
+            "preferred" | "prefer" => MySqlSslMode::Preferred,
+            "required" | "require" => MySqlSslMode::Required,
             "verify_ca" => MySqlSslMode::VerifyCa,
-            "verify_identity" => MySqlSslMode::VerifyIdentity,
+            "verify_identity" | "verify_full" => MySqlSslMode::VerifyIdentity,
 
             _ => {
                 return Err(Error::Configuration(
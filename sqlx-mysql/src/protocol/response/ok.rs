@@ -12,6 +12,7 @@ pub struct OkPacket {
     pub last_insert_id: u64,
     pub status: Status,
     pub warnings: u16,
+    pub info: String,
 }
 
 impl Decode<'_> for OkPacket {
@@ -29,11 +30,15 @@ impl Decode<'_> for OkPacket {
         let status = Status::from_bits_truncate(buf.get_u16_le());
         let warnings = buf.get_u16_le();
 
+        // without `CLIENT_SESSION_TRACK`, the rest of the packet is the human-readable info
+        let info = String::from_utf8_lossy(&buf).into_owned();
+
         Ok(Self {
             affected_rows,
             last_insert_id,
             status,
             warnings,
+            info,
         })
     }
 }
@@ -47,6 +52,18 @@ fn test_decode_ok_packet() {
     assert_eq!(p.affected_rows, 0);
     assert_eq!(p.last_insert_id, 0);
     assert_eq!(p.warnings, 0);
+    assert_eq!(p.info, "");
     assert!(p.status.contains(Status::SERVER_STATUS_AUTOCOMMIT));
     assert!(p.status.contains(Status::SERVER_SESSION_STATE_CHANGED));
 }
+
+#[test]
+fn test_decode_ok_packet_with_info() {
+    const DATA: &[u8] = b"\x00\x03\x00\x02\x00\x01\x00Records: 3  Duplicates: 0  Warnings: 1";
+
+    let p = OkPacket::decode(DATA.into()).unwrap();
+
+    assert_eq!(p.affected_rows, 3);
+    assert_eq!(p.warnings, 1);
+    assert_eq!(p.info, "Records: 3  Duplicates: 0  Warnings: 1");
+}
@@ -3,9 +3,11 @@ mod ping;
 mod query;
 mod quit;
 mod row;
+mod set_option;
 
 pub(crate) use column::{ColumnDefinition, ColumnFlags, ColumnType};
 pub(crate) use ping::Ping;
 pub(crate) use query::Query;
 pub(crate) use quit::Quit;
 pub(crate) use row::TextRow;
+pub(crate) use set_option::SetOption;
@@ -0,0 +1,22 @@
+use crate::io::Encode;
+use crate::protocol::Capabilities;
+
+// https://dev.mysql.com/doc/dev/mysql-server/8.0.12/page_protocol_com_set_option.html
+
+#[derive(Debug)]
+pub(crate) enum SetOption {
+    MultiStatements(bool),
+}
+
+impl Encode<'_, Capabilities> for SetOption {
+    fn encode_with(&self, buf: &mut Vec<u8>, _: Capabilities) {
+        buf.push(0x1b); // COM_SET_OPTION
+
+        let option: u16 = match self {
+            SetOption::MultiStatements(true) => 0, // MYSQL_OPTION_MULTI_STATEMENTS_ON
+            SetOption::MultiStatements(false) => 1, // MYSQL_OPTION_MULTI_STATEMENTS_OFF
+        };
+
+        buf.extend_from_slice(&option.to_le_bytes());
+    }
+}
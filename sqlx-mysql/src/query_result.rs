@@ -4,6 +4,8 @@ use std::iter::{Extend, IntoIterator};
 pub struct MySqlQueryResult {
     pub(super) rows_affected: u64,
     pub(super) last_insert_id: u64,
+    pub(super) warnings: u16,
+    pub(super) info: String,
 }
 
 impl MySqlQueryResult {
@@ -14,6 +16,19 @@ impl MySqlQueryResult {
     pub fn rows_affected(&self) -> u64 {
         self.rows_affected
     }
+
+    /// The number of warnings raised by the statement, e.g. for truncated values.
+    ///
+    /// The warnings themselves can be read with `SHOW WARNINGS`.
+    pub fn warnings(&self) -> u16 {
+        self.warnings
+    }
+
+    /// The human-readable information about the statement sent by the server, if any,
+    /// e.g. `Records: 3  Duplicates: 0  Warnings: 0` for a multi-row `INSERT`.
+    pub fn info(&self) -> &str {
+        &self.info
+    }
 }
 
 impl Extend<MySqlQueryResult> for MySqlQueryResult {
@@ -21,6 +36,8 @@ impl Extend<MySqlQueryResult> for MySqlQueryResult {
         for elem in iter {
             self.rows_affected += elem.rows_affected;
             self.last_insert_id = elem.last_insert_id;
+            self.warnings = self.warnings.saturating_add(elem.warnings);
+            self.info = elem.info;
         }
     }
 }
@@ -78,6 +78,10 @@ impl TestSupport for MySql {
         // but I'm keeping the code around for now because I plan to come back to it.
         todo!()
     }
+
+    fn setup_connect_options(opts: &MySqlConnectOptions) -> MySqlConnectOptions {
+        opts.clone().multi_statements(true)
+    }
 }
 
 async fn test_context(args: &TestArgs) -> Result<TestContext<MySql>, Error> {
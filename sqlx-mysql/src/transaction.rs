@@ -15,12 +15,55 @@ impl TransactionManager for MySqlTransactionManager {
     type Database = MySql;
 
     fn begin(conn: &mut MySqlConnection) -> BoxFuture<'_, Result<(), Error>> {
+        Self::begin_with(conn, TransactionOptions::default())
+    }
+
+    fn begin_with(
+        conn: &mut MySqlConnection,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<(), Error>> {
         Box::pin(async move {
             let depth = conn.transaction_depth;
 
-            conn.execute(&*begin_ansi_transaction_sql(depth)).await?;
+            check_transaction_options(depth, &options)?;
+
+            if options.deferrable {
+                return Err(Error::Configuration(
+                    "MySQL does not support deferrable transactions".into(),
+                ));
+            }
+
+            // applies to the next transaction only
+            if let Some(isolation) = options.isolation {
+                conn.execute(&*format!(
+                    "SET TRANSACTION ISOLATION LEVEL {}",
+                    isolation.as_sql()
+                ))
+                .await?;
+            }
+
+            let begin = if options.read_only {
+                conn.execute("START TRANSACTION READ ONLY").await
+            } else {
+                conn.execute(&*begin_ansi_transaction_sql(depth)).await
+            };
+
+            if let Err(error) = begin {
+                // don't leave the isolation level behind for whichever transaction comes next;
+                // a `ROLLBACK` outside of a transaction just clears it
+                if options.isolation.is_some() {
+                    let _ = conn.execute("ROLLBACK").await;
+                }
+
+                return Err(error);
+            }
+
             conn.transaction_depth = depth + 1;
 
+            if depth == 0 {
+                conn.stream.transaction_aborted = false;
+            }
+
             Ok(())
         })
     }
@@ -63,4 +106,18 @@ impl TransactionManager for MySqlTransactionManager {
             conn.transaction_depth = depth - 1;
         }
     }
+
+    fn write_count(conn: &MySqlConnection) -> Option<u64> {
+        Some(conn.write_count)
+    }
+
+    fn transaction_status(conn: &MySqlConnection) -> TransactionStatus {
+        if conn.transaction_depth == 0 {
+            TransactionStatus::Closed
+        } else if conn.stream.transaction_aborted {
+            TransactionStatus::Aborted
+        } else {
+            TransactionStatus::Active
+        }
+    }
 }
@@ -90,7 +90,7 @@ impl Encode<'_, MySql> for i64 {
     }
 }
 
-fn int_decode(value: MySqlValueRef<'_>) -> Result<i64, BoxDynError> {
+pub(super) fn int_decode(value: MySqlValueRef<'_>) -> Result<i64, BoxDynError> {
     Ok(match value.format() {
         MySqlValueFormat::Text => value.as_str()?.parse()?,
         MySqlValueFormat::Binary => {
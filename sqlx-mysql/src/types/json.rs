@@ -63,6 +63,9 @@ where
     T: 'r + Deserialize<'r>,
 {
     fn decode(value: MySqlValueRef<'r>) -> Result<Self, BoxDynError> {
-        Json::decode_from_string(value.as_str()?)
+        // `JSON` columns arrive with the `binary` character set in binary result sets,
+        // so decode from the raw bytes and let `serde_json` validate the UTF-8 itself.
+        // Any error is the `serde_json::Error`, which reports the line and column of the failure.
+        Json::decode_from_bytes(value.as_bytes()?)
     }
 }
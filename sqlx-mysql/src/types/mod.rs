@@ -23,6 +23,13 @@
 //! | [`MySqlTime`]                         | TIME (encode and decode full range)                  |
 //! | [`Duration`]                          | TIME (for decoding positive values only)             |
 //!
+//! ##### Note: Unsigned Integers
+//! The unsigned integer types encode as the corresponding `UNSIGNED` type, so values above
+//! `i64::MAX` survive a round-trip through `BIGINT UNSIGNED`.
+//!
+//! They may also be decoded from signed integer columns; decoding a negative value, or a value
+//! too large for the target type, is an error instead of a silent truncation.
+//!
 //! ##### Note: `BOOLEAN`/`BOOL` Type
 //! MySQL and MariaDB treat `BOOLEAN` as an alias of the `TINYINT` type:
 //!
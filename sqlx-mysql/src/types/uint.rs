@@ -2,6 +2,7 @@ use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
 use crate::protocol::text::{ColumnFlags, ColumnType};
+use crate::types::int::int_decode;
 use crate::types::Type;
 use crate::{MySql, MySqlTypeInfo, MySqlValueFormat, MySqlValueRef};
 use byteorder::{ByteOrder, LittleEndian};
@@ -25,7 +26,7 @@ fn uint_compatible(ty: &MySqlTypeInfo) -> bool {
             | ColumnType::LongLong
             | ColumnType::Year
             | ColumnType::Bit
-    ) && ty.flags.contains(ColumnFlags::UNSIGNED)
+    )
 }
 
 impl Type<MySql> for u8 {
@@ -114,6 +115,16 @@ fn uint_decode(value: MySqlValueRef<'_>) -> Result<u64, BoxDynError> {
         return Ok(value);
     }
 
+    if !value.type_info.flags.contains(ColumnFlags::UNSIGNED) {
+        // a signed column may hold negative values, which must not be reinterpreted
+        let value = int_decode(value)?;
+
+        return u64::try_from(value).map_err(|_| {
+            format!("value {value} from a signed column is out of range for an unsigned integer")
+                .into()
+        });
+    }
+
     Ok(match value.format() {
         MySqlValueFormat::Text => value.as_str()?.parse()?,
 
@@ -138,21 +149,33 @@ fn uint_decode(value: MySqlValueRef<'_>) -> Result<u64, BoxDynError> {
     })
 }
 
+fn uint_decode_into<T: TryFrom<u64>>(value: MySqlValueRef<'_>) -> Result<T, BoxDynError> {
+    let value = uint_decode(value)?;
+
+    T::try_from(value).map_err(|_| {
+        format!(
+            "value {value} is out of range for `{}`",
+            std::any::type_name::<T>()
+        )
+        .into()
+    })
+}
+
 impl Decode<'_, MySql> for u8 {
     fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
-        uint_decode(value)?.try_into().map_err(Into::into)
+        uint_decode_into(value)
     }
 }
 
 impl Decode<'_, MySql> for u16 {
     fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
-        uint_decode(value)?.try_into().map_err(Into::into)
+        uint_decode_into(value)
     }
 }
 
 impl Decode<'_, MySql> for u32 {
     fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
-        uint_decode(value)?.try_into().map_err(Into::into)
+        uint_decode_into(value)
     }
 }
 
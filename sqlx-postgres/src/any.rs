@@ -1,6 +1,6 @@
 use crate::{
-    Either, PgColumn, PgConnectOptions, PgConnection, PgQueryResult, PgRow, PgTransactionManager,
-    PgTypeInfo, Postgres,
+    Either, PgArguments, PgColumn, PgConnectOptions, PgConnection, PgQueryResult, PgRow,
+    PgTransactionManager, PgTypeInfo, PgValueFormat, Postgres,
 };
 use futures_core::future::BoxFuture;
 use futures_core::stream::BoxStream;
@@ -14,7 +14,8 @@ use sqlx_core::database::Database;
 use sqlx_core::describe::Describe;
 use sqlx_core::executor::Executor;
 use sqlx_core::ext::ustr::UStr;
-use sqlx_core::transaction::TransactionManager;
+use sqlx_core::transaction::{TransactionManager, TransactionOptions, TransactionStatus};
+use sqlx_core::type_info::TypeInfo;
 
 sqlx_core::declare_driver_with_optional_migrate!(DRIVER = Postgres);
 
@@ -39,6 +40,10 @@ impl AnyConnectionBackend for PgConnection {
         PgTransactionManager::begin(self)
     }
 
+    fn begin_with(&mut self, options: TransactionOptions) -> BoxFuture<'_, sqlx_core::Result<()>> {
+        PgTransactionManager::begin_with(self, options)
+    }
+
     fn commit(&mut self) -> BoxFuture<'_, sqlx_core::Result<()>> {
         PgTransactionManager::commit(self)
     }
@@ -51,6 +56,14 @@ impl AnyConnectionBackend for PgConnection {
         PgTransactionManager::start_rollback(self)
     }
 
+    fn transaction_status(&self) -> TransactionStatus {
+        PgTransactionManager::transaction_status(self)
+    }
+
+    fn write_count(&self) -> Option<u64> {
+        PgTransactionManager::write_count(self)
+    }
+
     fn shrink_buffers(&mut self) {
         Connection::shrink_buffers(self);
     }
@@ -76,17 +89,25 @@ impl AnyConnectionBackend for PgConnection {
         arguments: Option<AnyArguments<'q>>,
     ) -> BoxStream<'q, sqlx_core::Result<Either<AnyQueryResult, AnyRow>>> {
         let persistent = arguments.is_some();
-        let args = arguments.as_ref().map(AnyArguments::convert_to);
+        let args = arguments.as_ref().map(convert_arguments);
 
         Box::pin(
-            self.run(query, args, 0, persistent, None)
-                .try_flatten_stream()
-                .map(
-                    move |res: sqlx_core::Result<Either<PgQueryResult, PgRow>>| match res? {
-                        Either::Left(result) => Ok(Either::Left(map_result(result))),
-                        Either::Right(row) => Ok(Either::Right(AnyRow::try_from(&row)?)),
-                    },
-                ),
+            self.run(
+                query,
+                args,
+                0,
+                persistent,
+                None,
+                None,
+                PgValueFormat::Binary,
+            )
+            .try_flatten_stream()
+            .map(
+                move |res: sqlx_core::Result<Either<PgQueryResult, PgRow>>| match res? {
+                    Either::Left(result) => Ok(Either::Left(map_result(result))),
+                    Either::Right(row) => Ok(Either::Right(AnyRow::try_from(&row)?)),
+                },
+            ),
         )
     }
 
@@ -96,10 +117,20 @@ impl AnyConnectionBackend for PgConnection {
         arguments: Option<AnyArguments<'q>>,
     ) -> BoxFuture<'q, sqlx_core::Result<Option<AnyRow>>> {
         let persistent = arguments.is_some();
-        let args = arguments.as_ref().map(AnyArguments::convert_to);
+        let args = arguments.as_ref().map(convert_arguments);
 
         Box::pin(async move {
-            let stream = self.run(query, args, 1, persistent, None).await?;
+            let stream = self
+                .run(
+                    query,
+                    args,
+                    1,
+                    persistent,
+                    None,
+                    None,
+                    PgValueFormat::Binary,
+                )
+                .await?;
             futures_util::pin_mut!(stream);
 
             if let Some(Either::Right(row)) = stream.try_next().await? {
@@ -172,19 +203,18 @@ impl<'a> TryFrom<&'a PgTypeInfo> for AnyTypeInfo {
         Ok(AnyTypeInfo {
             kind: match &pg_type.0 {
                 PgType::Void => AnyTypeInfoKind::Null,
+                PgType::Bool => AnyTypeInfoKind::Bool,
                 PgType::Int2 => AnyTypeInfoKind::SmallInt,
                 PgType::Int4 => AnyTypeInfoKind::Integer,
                 PgType::Int8 => AnyTypeInfoKind::BigInt,
                 PgType::Float4 => AnyTypeInfoKind::Real,
                 PgType::Float8 => AnyTypeInfoKind::Double,
                 PgType::Bytea => AnyTypeInfoKind::Blob,
-                PgType::Text | PgType::Varchar => AnyTypeInfoKind::Text,
-                PgType::DeclareWithName(UStr::Static("citext")) => AnyTypeInfoKind::Text,
-                _ => {
-                    return Err(sqlx_core::Error::AnyDriverError(
-                        format!("Any driver does not support the Postgres type {pg_type:?}").into(),
-                    ))
+                PgType::Text | PgType::Varchar | PgType::Bpchar | PgType::Name => {
+                    AnyTypeInfoKind::Text
                 }
+                PgType::DeclareWithName(UStr::Static("citext")) => AnyTypeInfoKind::Text,
+                _ => AnyTypeInfoKind::Other(pg_type.name().to_owned()),
             },
         })
     }
@@ -216,16 +246,46 @@ impl<'a> TryFrom<&'a PgRow> for AnyRow {
     }
 }
 
+impl From<PgConnectOptions> for AnyConnectOptions {
+    fn from(options: PgConnectOptions) -> Self {
+        let mut any = AnyConnectOptions::from_driver_options(options.clone());
+        any.log_settings = options.log_settings;
+        any.after_connect_sql = options.after_connect_sql;
+        any.connect_timeout = options.connect_timeout;
+        any.tcp_options = options.tcp_options;
+        any
+    }
+}
+
 impl<'a> TryFrom<&'a AnyConnectOptions> for PgConnectOptions {
     type Error = sqlx_core::Error;
 
     fn try_from(value: &'a AnyConnectOptions) -> Result<Self, Self::Error> {
-        let mut opts = PgConnectOptions::parse_from_url(&value.database_url)?;
+        let mut opts = match value.downcast_ref::<PgConnectOptions>() {
+            Some(opts) => opts.clone(),
+            None => PgConnectOptions::parse_from_url(&value.database_url)?,
+        };
         opts.log_settings = value.log_settings.clone();
+        opts.after_connect_sql = value.after_connect_sql.clone();
+        opts.connect_timeout = value.connect_timeout;
+        opts.tcp_options = value.tcp_options.clone();
         Ok(opts)
     }
 }
 
+fn convert_arguments(arguments: &AnyArguments<'_>) -> PgArguments {
+    let mut args: PgArguments = arguments.convert_to();
+
+    // leave the type of a `NULL` without a known kind for Postgres to infer, like a `NULL` literal
+    for (ty, value) in args.types.iter_mut().zip(&arguments.values.0) {
+        if let AnyValueKind::Null(AnyTypeInfoKind::Null | AnyTypeInfoKind::Other(_)) = value {
+            *ty = PgTypeInfo::UNKNOWN;
+        }
+    }
+
+    args
+}
+
 fn map_result(res: PgQueryResult) -> AnyQueryResult {
     AnyQueryResult {
         rows_affected: res.rows_affected(),
@@ -81,26 +81,7 @@ impl PgArguments {
         conn: &mut PgConnection,
         parameters: &[PgTypeInfo],
     ) -> Result<(), Error> {
-        let PgArgumentBuffer {
-            ref patches,
-            ref type_holes,
-            ref mut buffer,
-            ..
-        } = self.buffer;
-
-        for (offset, ty, callback) in patches {
-            let buf = &mut buffer[*offset..];
-            let ty = &parameters[*ty];
-
-            callback(buf, ty);
-        }
-
-        for (offset, name) in type_holes {
-            let oid = conn.fetch_type_id_by_name(&*name).await?;
-            buffer[*offset..(*offset + 4)].copy_from_slice(&oid.0.to_be_bytes());
-        }
-
-        Ok(())
+        self.buffer.apply_patches(conn, parameters).await
     }
 }
 
@@ -129,12 +110,22 @@ impl PgArgumentBuffer {
     where
         T: Encode<'q, Postgres>,
     {
+        self.encode_with(|buf| value.encode(buf));
+    }
+
+    // Encodes one field of a binary `COPY` row, which has the same layout as a bind parameter
+    pub(crate) fn encode_field(&mut self, value: &dyn Encode<'_, Postgres>) {
+        self.encode_with(|buf| value.encode_by_ref(buf));
+        self.count += 1;
+    }
+
+    fn encode_with(&mut self, encode: impl FnOnce(&mut Self) -> IsNull) {
         // reserve space to write the prefixed length of the value
         let offset = self.len();
         self.extend(&[0; 4]);
 
         // encode the value into our buffer
-        let len = if let IsNull::No = value.encode(self) {
+        let len = if let IsNull::No = encode(self) {
             (self.len() - offset - 4) as i32
         } else {
             // Write a -1 to indicate NULL
@@ -147,6 +138,35 @@ impl PgArgumentBuffer {
         self[offset..(offset + 4)].copy_from_slice(&len.to_be_bytes());
     }
 
+    // Apply patches
+    // This should only go out and ask postgres if we have not seen the type name yet
+    pub(crate) async fn apply_patches(
+        &mut self,
+        conn: &mut PgConnection,
+        parameters: &[PgTypeInfo],
+    ) -> Result<(), Error> {
+        let PgArgumentBuffer {
+            ref patches,
+            ref type_holes,
+            ref mut buffer,
+            ..
+        } = *self;
+
+        for (offset, ty, callback) in patches {
+            let buf = &mut buffer[*offset..];
+            let ty = &parameters[*ty];
+
+            callback(buf, ty);
+        }
+
+        for (offset, name) in type_holes {
+            let oid = conn.fetch_type_id_by_name(&*name).await?;
+            buffer[*offset..(*offset + 4)].copy_from_slice(&oid.0.to_be_bytes());
+        }
+
+        Ok(())
+    }
+
     // Adds a callback to be invoked later when we know the parameter type
     #[allow(dead_code)]
     pub(crate) fn patch<F>(&mut self, callback: F)
@@ -0,0 +1,110 @@
+use crate::encode::{Encode, IsNull};
+use crate::types::array::encode_array;
+use crate::types::Type;
+use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, Postgres};
+
+/// A wrapper that binds the items of an iterator as a Postgres array,
+/// without first collecting them into a `Vec`.
+///
+/// Created by [`PgBindIterExt::bind_iter()`].
+///
+/// ```rust,ignore
+/// use sqlx::postgres::PgBindIterExt;
+///
+/// let users = sqlx::query("SELECT * FROM users WHERE id = ANY($1)")
+///     .bind(accounts.iter().map(|account| account.user_id).bind_iter())
+///     .fetch_all(&mut conn)
+///     .await?;
+/// ```
+///
+/// The iterator is cloned each time the value is encoded, so it must be cheap to clone, like
+/// most iterators over borrowed collections.
+pub struct PgBindIter<I>(I);
+
+/// Extension trait to bind any cloneable iterator as a Postgres array.
+pub trait PgBindIterExt: Iterator + Clone + Sized {
+    /// Wrap this iterator so that it can be bound as an array, e.g. for `= ANY($1)`.
+    fn bind_iter(self) -> PgBindIter<Self>;
+}
+
+impl<I: Iterator + Clone + Sized> PgBindIterExt for I {
+    fn bind_iter(self) -> PgBindIter<Self> {
+        PgBindIter(self)
+    }
+}
+
+impl<I, T> Type<Postgres> for PgBindIter<I>
+where
+    I: Iterator<Item = T>,
+    T: Type<Postgres> + PgHasArrayType,
+{
+    fn type_info() -> PgTypeInfo {
+        T::array_type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        T::array_compatible(ty)
+    }
+}
+
+impl<'q, I, T> Encode<'q, Postgres> for PgBindIter<I>
+where
+    I: Iterator<Item = T> + Clone,
+    T: Encode<'q, Postgres> + Type<Postgres>,
+{
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        encode_array(buf, T::type_info(), self.0.clone())
+    }
+
+    fn size_hint(&self) -> usize {
+        // the array header, and the length prefix of each element
+        20 + self.0.size_hint().0 * 4
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_encodes_the_same_as_a_slice() {
+        let ids = [1_i32, 2, 3];
+
+        let mut expected = PgArgumentBuffer::default();
+        let _ = (&ids[..]).encode_by_ref(&mut expected);
+
+        let mut buf = PgArgumentBuffer::default();
+        let _ = ids.iter().copied().bind_iter().encode_by_ref(&mut buf);
+
+        assert_eq!(*buf, *expected);
+    }
+
+    #[test]
+    fn it_encodes_an_empty_iterator() {
+        let mut buf = PgArgumentBuffer::default();
+        let _ = std::iter::empty::<i64>()
+            .bind_iter()
+            .encode_by_ref(&mut buf);
+
+        let mut expected = PgArgumentBuffer::default();
+        let _ = Vec::<i64>::new().encode_by_ref(&mut expected);
+
+        assert_eq!(*buf, *expected);
+        // dimensions, flags, element OID, length, lower bound
+        assert_eq!(buf.len(), 20);
+        assert_eq!(buf[12..16], 0_i32.to_be_bytes());
+    }
+
+    #[test]
+    fn it_encodes_more_than_once() {
+        let value = [1_i32, 2, 3].iter().copied().bind_iter();
+
+        let mut first = PgArgumentBuffer::default();
+        let _ = value.encode_by_ref(&mut first);
+
+        let mut second = PgArgumentBuffer::default();
+        let _ = value.encode_by_ref(&mut second);
+
+        assert_eq!(*first, *second);
+    }
+}
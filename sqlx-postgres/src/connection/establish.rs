@@ -141,6 +141,7 @@ impl PgConnection {
             secret_key,
             transaction_status,
             transaction_depth: 0,
+            write_count: 0,
             pending_ready_for_query_count: 0,
             next_statement_id: Oid(1),
             cache_statement: StatementCache::new(options.statement_cache_capacity),
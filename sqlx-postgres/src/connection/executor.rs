@@ -7,6 +7,7 @@ use crate::message::{
     RowDescription,
 };
 use crate::statement::PgStatementMetadata;
+use crate::transaction::is_read_only_sql;
 use crate::type_info::PgType;
 use crate::types::Oid;
 use crate::{
@@ -18,7 +19,7 @@ use futures_core::stream::BoxStream;
 use futures_core::Stream;
 use futures_util::{pin_mut, TryStreamExt};
 use sqlx_core::Either;
-use std::{borrow::Cow, sync::Arc};
+use std::{borrow::Cow, sync::Arc, time::Duration};
 
 async fn prepare(
     conn: &mut PgConnection,
@@ -100,6 +101,14 @@ async fn prepare(
     Ok((id, metadata))
 }
 
+fn result_format<'q>(query: &impl Execute<'q, Postgres>) -> PgValueFormat {
+    if query.text_results() {
+        PgValueFormat::Text
+    } else {
+        PgValueFormat::Binary
+    }
+}
+
 async fn recv_desc_params(conn: &mut PgConnection) -> Result<ParameterDescription, Error> {
     conn.stream
         .recv_expect(MessageFormat::ParameterDescription)
@@ -160,7 +169,16 @@ impl PgConnection {
         self.pending_ready_for_query_count += 1;
     }
 
-    async fn get_or_prepare<'a>(
+    // Close a statement that was prepared without being stored in the statement cache, so it
+    // does not stay allocated on the server. This is flushed the next time the connection is used.
+    fn queue_close_statement(&mut self, statement: Oid) {
+        self.stream.write(Close::Statement(statement));
+
+        // in its own sync so the statement is closed even if the query that used it failed
+        self.write_sync();
+    }
+
+    pub(crate) async fn get_or_prepare<'a>(
         &mut self,
         sql: &str,
         parameters: &[PgTypeInfo],
@@ -191,6 +209,7 @@ impl PgConnection {
         Ok(statement)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(crate) async fn run<'e, 'c: 'e, 'q: 'e>(
         &'c mut self,
         query: &'q str,
@@ -198,15 +217,34 @@ impl PgConnection {
         limit: u8,
         persistent: bool,
         metadata_opt: Option<Arc<PgStatementMetadata>>,
+        slow_statements_duration: Option<Duration>,
+        // format of the results of a prepared statement; simple queries always return text
+        result_format: PgValueFormat,
     ) -> Result<impl Stream<Item = Result<Either<PgQueryResult, PgRow>, Error>> + 'e, Error> {
         let mut logger = QueryLogger::new(query, self.log_settings.clone());
+        logger.override_slow_statements_duration(slow_statements_duration);
 
         // before we continue, wait until we are "ready" to accept more queries
         self.wait_until_ready().await?;
 
+        if !is_read_only_sql(query) {
+            self.write_count += 1;
+        }
+
         let mut metadata: Arc<PgStatementMetadata>;
 
         let format = if let Some(mut arguments) = arguments {
+            let cache_hit = self.cache_statement.contains_key(query);
+
+            if persistent && self.cache_statement.is_enabled() {
+                logger.set_cache_hit(cache_hit);
+            }
+
+            if !cache_hit {
+                // the statement must be prepared before it can be executed
+                logger.increment_round_trips();
+            }
+
             // prepare the statement if this our first time executing it
             // always return the statement ID here
             let (statement, metadata_) = self
@@ -218,6 +256,8 @@ impl PgConnection {
             // patch holes created during encoding
             arguments.apply_patches(self, &metadata.parameters).await?;
 
+            logger.set_param_count(arguments.types.len());
+
             // consume messages till `ReadyForQuery` before bind and execute
             self.wait_until_ready().await?;
 
@@ -228,7 +268,7 @@ impl PgConnection {
                 formats: &[PgValueFormat::Binary],
                 num_params: arguments.types.len() as i16,
                 params: &*arguments.buffer,
-                result_formats: &[PgValueFormat::Binary],
+                result_formats: &[result_format],
             });
 
             // executes the portal up to the passed limit
@@ -255,8 +295,12 @@ impl PgConnection {
             // termed batching might suit this.
             self.write_sync();
 
-            // prepared statements are binary
-            PgValueFormat::Binary
+            if !self.cache_statement.contains_key(query) {
+                self.queue_close_statement(statement);
+            }
+
+            // prepared statements are binary unless text was requested
+            result_format
         } else {
             // Query will trigger a ReadyForQuery
             self.stream.write(Query(query));
@@ -270,6 +314,7 @@ impl PgConnection {
         };
 
         self.stream.flush().await?;
+        logger.increment_round_trips();
 
         Ok(try_stream! {
             loop {
@@ -372,9 +417,11 @@ impl<'c> Executor<'c> for &'c mut PgConnection {
         let metadata = query.statement().map(|s| Arc::clone(&s.metadata));
         let arguments = query.take_arguments();
         let persistent = query.persistent();
+        let slow_statements_duration = query.slow_statements_duration();
+        let result_format = result_format(&query);
 
         Box::pin(try_stream! {
-            let s = self.run(sql, arguments, 0, persistent, metadata).await?;
+            let s = self.run(sql, arguments, 0, persistent, metadata, slow_statements_duration, result_format).await?;
             pin_mut!(s);
 
             while let Some(v) = s.try_next().await? {
@@ -397,9 +444,21 @@ impl<'c> Executor<'c> for &'c mut PgConnection {
         let metadata = query.statement().map(|s| Arc::clone(&s.metadata));
         let arguments = query.take_arguments();
         let persistent = query.persistent();
+        let slow_statements_duration = query.slow_statements_duration();
+        let result_format = result_format(&query);
 
         Box::pin(async move {
-            let s = self.run(sql, arguments, 1, persistent, metadata).await?;
+            let s = self
+                .run(
+                    sql,
+                    arguments,
+                    1,
+                    persistent,
+                    metadata,
+                    slow_statements_duration,
+                    result_format,
+                )
+                .await?;
             pin_mut!(s);
 
             // With deferred constraints we need to check all responses as we
@@ -428,7 +487,11 @@ impl<'c> Executor<'c> for &'c mut PgConnection {
         Box::pin(async move {
             self.wait_until_ready().await?;
 
-            let (_, metadata) = self.get_or_prepare(sql, parameters, true, None).await?;
+            let (statement, metadata) = self.get_or_prepare(sql, parameters, true, None).await?;
+
+            if !self.cache_statement.contains_key(sql) {
+                self.queue_close_statement(statement);
+            }
 
             Ok(PgStatement {
                 sql: Cow::Borrowed(sql),
@@ -451,6 +514,10 @@ impl<'c> Executor<'c> for &'c mut PgConnection {
 
             let nullable = self.get_nullable_for_columns(stmt_id, &metadata).await?;
 
+            if !self.cache_statement.contains_key(sql) {
+                self.queue_close_statement(stmt_id);
+            }
+
             Ok(Describe {
                 columns: metadata.columns.clone(),
                 nullable,
@@ -3,6 +3,7 @@ use std::sync::Arc;
 
 use crate::HashMap;
 use futures_core::future::BoxFuture;
+use futures_core::stream::BoxStream;
 use futures_util::FutureExt;
 
 use crate::common::StatementCache;
@@ -15,7 +16,7 @@ use crate::message::{
 use crate::statement::PgStatementMetadata;
 use crate::transaction::Transaction;
 use crate::types::Oid;
-use crate::{PgConnectOptions, PgTypeInfo, Postgres};
+use crate::{PgConnectOptions, PgDatabaseError, PgQueryResult, PgTypeInfo, Postgres};
 
 pub(crate) use sqlx_core::connection::*;
 
@@ -60,18 +61,84 @@ pub struct PgConnection {
     pub(crate) pending_ready_for_query_count: usize,
 
     // current transaction status
-    transaction_status: TransactionStatus,
+    pub(crate) transaction_status: TransactionStatus,
     pub(crate) transaction_depth: usize,
 
+    // number of statements run that may have written to the database
+    pub(crate) write_count: u64,
+
     log_settings: LogSettings,
 }
 
 impl PgConnection {
-    /// the version number of the server in `libpq` format
+    /// The version number of the server in `libpq` format, e.g. `140005` for 14.5.
+    ///
+    /// This is parsed from the `server_version` parameter sent by the server when the
+    /// connection was established, so it's available without a round-trip.
+    /// It is also available through a [`PoolConnection<Postgres>`][crate::pool::PoolConnection].
+    ///
+    /// ```rust,ignore
+    /// let sql = if conn.server_version_num().is_some_and(|v| v >= 150000) {
+    ///     "MERGE INTO ..."
+    /// } else {
+    ///     "INSERT INTO ... ON CONFLICT ..."
+    /// };
+    /// ```
+    ///
+    /// Returns `None` if the server did not report a version that could be parsed.
     pub fn server_version_num(&self) -> Option<u32> {
         self.stream.server_version_num
     }
 
+    /// Set a handler to be called with each notice or warning sent by the server, e.g. by
+    /// `RAISE NOTICE` in a PL/pgSQL function, instead of logging it.
+    ///
+    /// The [`PgDatabaseError`] gives access to the severity, message, detail and hint
+    /// of the notice.
+    ///
+    /// ```rust,ignore
+    /// let notices = Arc::new(Mutex::new(Vec::new()));
+    ///
+    /// conn.set_notice_handler(Arc::new({
+    ///     let notices = notices.clone();
+    ///     move |notice| notices.lock().unwrap().push(notice.message().to_owned())
+    /// }));
+    /// ```
+    ///
+    /// The handler stays set for the lifetime of the connection, including when it's returned to
+    /// a pool; use [`.clear_notice_handler()`][Self::clear_notice_handler] to remove it.
+    pub fn set_notice_handler(&mut self, handler: Arc<dyn Fn(PgDatabaseError) + Send + Sync>) {
+        self.stream.notice_handler = Some(handler);
+    }
+
+    /// Remove the handler set by [`.set_notice_handler()`][Self::set_notice_handler], so notices
+    /// are logged again.
+    pub fn clear_notice_handler(&mut self) {
+        self.stream.notice_handler = None;
+    }
+
+    /// Execute one or more statements, separated by semicolons (`;`), using the simple query
+    /// protocol, like `psql` does. This is useful for running DDL and schema scripts without
+    /// splitting them first.
+    ///
+    /// Returns a stream which gives the result of each statement in the string. If a statement
+    /// fails, the stream returns the error and the statements after it are not executed.
+    /// Unless the script contains explicit transaction control, all of its statements run in
+    /// a single implicit transaction, so a failure rolls back the statements before it.
+    ///
+    /// ### Note: no bind parameters
+    /// The simple query protocol cannot bind parameters, so any values must be written into the
+    /// SQL itself. Never use this with SQL built from untrusted input; use
+    /// [`query()`][sqlx_core::query::query] with bind parameters instead.
+    ///
+    /// This is the same as [`raw_sql(sql).execute_many(conn)`][sqlx_core::raw_sql::raw_sql].
+    pub fn execute_simple<'e>(
+        &'e mut self,
+        sql: &'e str,
+    ) -> BoxStream<'e, Result<PgQueryResult, Error>> {
+        sqlx_core::raw_sql::raw_sql(sql).execute_many(self)
+    }
+
     // will return when the connection is ready for another query
     pub(crate) async fn wait_until_ready(&mut self) -> Result<(), Error> {
         if !self.stream.write_buffer_mut().is_empty() {
@@ -89,7 +156,7 @@ impl PgConnection {
         Ok(())
     }
 
-    async fn recv_ready_for_query(&mut self) -> Result<(), Error> {
+    pub(crate) async fn recv_ready_for_query(&mut self) -> Result<(), Error> {
         let r: ReadyForQuery = self
             .stream
             .recv_expect(MessageFormat::ReadyForQuery)
@@ -158,6 +225,8 @@ impl Connection for PgConnection {
 
         Box::pin(async move {
             // The simplest call-and-response that's possible.
+            // If this is cancelled, e.g. by `ping_timeout()`, the pending `ReadyForQuery` is
+            // still consumed the next time the connection is used.
             self.write_sync();
             self.wait_until_ready().await
         })
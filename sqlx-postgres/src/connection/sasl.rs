@@ -6,12 +6,19 @@ use crate::message::{
 use crate::PgConnectOptions;
 use hmac::{Hmac, Mac};
 use rand::Rng;
-use sha2::{Digest, Sha256};
+use sha2::{Digest, Sha224, Sha256, Sha384, Sha512};
+use sqlx_core::net::Socket;
 use stringprep::saslprep;
 
 use base64::prelude::{Engine as _, BASE64_STANDARD};
 
+// gs2-header = gs2-cbind-flag "," [ authzid ] ","
+// the client does not support channel binding, or the server did not offer it without TLS
 const GS2_HEADER: &str = "n,,";
+// the client supports channel binding but thinks the server does not
+const GS2_HEADER_SUPPORTED: &str = "y,,";
+// the client requires channel binding using the hash of the server certificate
+const GS2_HEADER_PLUS: &str = "p=tls-server-end-point,,";
 const CHANNEL_ATTR: &str = "c";
 const USERNAME_ATTR: &str = "n";
 const CLIENT_PROOF_ATTR: &str = "p";
@@ -49,9 +56,37 @@ pub(crate) async fn authenticate(
         ));
     }
 
-    // channel-binding = "c=" base64
+    let server_certificate = stream.socket().peer_certificate();
+
+    // negotiate SCRAM-SHA-256-PLUS if the server offers it and we can bind to the TLS channel
+    let (gs2_header, cbind_data) = match (has_sasl_plus, server_certificate) {
+        (true, Some(cert)) => match tls_server_end_point(&cert) {
+            Ok(cbind_data) => (GS2_HEADER_PLUS, cbind_data),
+            Err(_) if has_sasl => (GS2_HEADER, Vec::new()),
+            Err(e) => return Err(e),
+        },
+
+        (true, None) if !has_sasl => {
+            return Err(Error::Tls(
+                "server requires SCRAM-SHA-256-PLUS (channel binding), \
+                 which is only available over TLS; enable it with `sslmode`"
+                    .into(),
+            ));
+        }
+
+        (false, Some(_)) => (GS2_HEADER_SUPPORTED, Vec::new()),
+
+        _ => (GS2_HEADER, Vec::new()),
+    };
+
+    let plus = gs2_header == GS2_HEADER_PLUS;
+
+    // channel-binding = "c=" base64(gs2-header [cbind-data])
     let mut channel_binding = format!("{CHANNEL_ATTR}=");
-    BASE64_STANDARD.encode_string(GS2_HEADER, &mut channel_binding);
+    BASE64_STANDARD.encode_string(
+        [gs2_header.as_bytes(), &cbind_data].concat(),
+        &mut channel_binding,
+    );
 
     // "n=" saslname ;; Usernames are prepared using SASLprep.
     let username = format!("{}={}", USERNAME_ATTR, options.username);
@@ -67,12 +102,12 @@ pub(crate) async fn authenticate(
     // client-first-message-bare = [reserved-mext ","] username "," nonce ["," extensions]
     let client_first_message_bare = format!("{username},{nonce}");
 
-    let client_first_message = format!("{GS2_HEADER}{client_first_message_bare}");
+    let client_first_message = format!("{gs2_header}{client_first_message_bare}");
 
     stream
         .send(SaslInitialResponse {
             response: &client_first_message,
-            plus: false,
+            plus,
         })
         .await?;
 
@@ -161,6 +196,81 @@ pub(crate) async fn authenticate(
     Ok(())
 }
 
+// tls-server-end-point channel binding data: the hash of the server certificate, using the hash
+// function of its signature algorithm, or SHA-256 if that is MD5 or SHA-1
+// https://www.rfc-editor.org/rfc/rfc5929#section-4.1
+fn tls_server_end_point(cert: &[u8]) -> Result<Vec<u8>, Error> {
+    // Certificate ::= SEQUENCE { tbsCertificate, signatureAlgorithm, signatureValue }
+    // AlgorithmIdentifier ::= SEQUENCE { algorithm OBJECT IDENTIFIER, parameters ANY OPTIONAL }
+    let algorithm = (|| {
+        let (cert, _) = der_read(cert, 0x30)?;
+        let (_tbs_certificate, rest) = der_read(cert, 0x30)?;
+        let (signature_algorithm, _) = der_read(rest, 0x30)?;
+        let (algorithm, _) = der_read(signature_algorithm, 0x06)?;
+
+        Some(algorithm)
+    })()
+    .ok_or_else(|| err_protocol!("failed to parse the server certificate for channel binding"))?;
+
+    const RSA: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01];
+    const ECDSA: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04];
+
+    let hash = match algorithm.split_at(algorithm.len().saturating_sub(1)) {
+        // md5WithRSAEncryption, sha1WithRSAEncryption, sha256WithRSAEncryption
+        (RSA, [0x04 | 0x05 | 0x0b]) => Sha256::digest(cert).to_vec(),
+        // sha384WithRSAEncryption
+        (RSA, [0x0c]) => Sha384::digest(cert).to_vec(),
+        // sha512WithRSAEncryption
+        (RSA, [0x0d]) => Sha512::digest(cert).to_vec(),
+        // sha224WithRSAEncryption
+        (RSA, [0x0e]) => Sha224::digest(cert).to_vec(),
+        // ecdsa-with-SHA1
+        (ECDSA, [0x01]) => Sha256::digest(cert).to_vec(),
+        _ => match algorithm.strip_prefix(ECDSA) {
+            // ecdsa-with-SHA224, -SHA256, -SHA384, -SHA512
+            Some([0x03, 0x01]) => Sha224::digest(cert).to_vec(),
+            Some([0x03, 0x02]) => Sha256::digest(cert).to_vec(),
+            Some([0x03, 0x03]) => Sha384::digest(cert).to_vec(),
+            Some([0x03, 0x04]) => Sha512::digest(cert).to_vec(),
+            _ => {
+                return Err(err_protocol!(
+                    "unsupported server certificate signature algorithm for channel binding"
+                ))
+            }
+        },
+    };
+
+    Ok(hash)
+}
+
+// read a DER value with the expected tag, returning its contents and the remaining input
+fn der_read(buf: &[u8], tag: u8) -> Option<(&[u8], &[u8])> {
+    let (&actual_tag, buf) = buf.split_first()?;
+    let (&len, mut buf) = buf.split_first()?;
+
+    if actual_tag != tag {
+        return None;
+    }
+
+    let len = if len & 0x80 == 0 {
+        usize::from(len)
+    } else {
+        // long form: the low bits are the number of length bytes that follow
+        let num_bytes = usize::from(len & 0x7f);
+
+        if num_bytes == 0 || num_bytes > 4 || buf.len() < num_bytes {
+            return None;
+        }
+
+        let (len, rest) = buf.split_at(num_bytes);
+        buf = rest;
+
+        len.iter().fold(0, |len, &b| (len << 8) | usize::from(b))
+    };
+
+    (buf.len() >= len).then(|| buf.split_at(len))
+}
+
 // nonce is a sequence of random printable bytes
 fn gen_nonce() -> String {
     let mut rng = rand::thread_rng();
@@ -225,3 +335,44 @@ fn bench_sasl_hi(b: &mut test::Bencher) {
         );
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server_certificate() -> Vec<u8> {
+        let pem = include_str!("../../../tests/certs/server.crt");
+        let base64: String = pem
+            .lines()
+            .filter(|line| !line.starts_with("-----"))
+            .collect();
+
+        BASE64_STANDARD.decode(base64).unwrap()
+    }
+
+    #[test]
+    fn test_tls_server_end_point() {
+        // signed with sha256WithRSAEncryption
+        let cert = server_certificate();
+
+        assert_eq!(
+            tls_server_end_point(&cert).unwrap(),
+            Sha256::digest(&cert).to_vec()
+        );
+        assert!(tls_server_end_point(&cert[..cert.len() / 2]).is_err());
+    }
+
+    #[test]
+    fn test_der_read() {
+        assert_eq!(
+            der_read(&[0x30, 0x02, 0x05, 0x00, 0xff], 0x30),
+            Some((&[0x05, 0x00][..], &[0xff][..]))
+        );
+        // long-form length
+        let long = [&[0x04, 0x81, 0x80][..], &[0; 0x80]].concat();
+        assert_eq!(der_read(&long, 0x04), Some((&[0; 0x80][..], &[][..])));
+        // wrong tag, truncated
+        assert_eq!(der_read(&[0x30, 0x00], 0x04), None);
+        assert_eq!(der_read(&[0x30, 0x02, 0x05], 0x30), None);
+    }
+}
@@ -1,6 +1,7 @@
 use std::collections::BTreeMap;
 use std::ops::{Deref, DerefMut};
 use std::str::FromStr;
+use std::sync::Arc;
 
 use futures_channel::mpsc::UnboundedSender;
 use futures_util::SinkExt;
@@ -36,13 +37,28 @@ pub struct PgStream {
     pub(crate) parameter_statuses: BTreeMap<String, String>,
 
     pub(crate) server_version_num: Option<u32>,
+
+    // called for each `NoticeResponse` instead of logging it
+    pub(crate) notice_handler: Option<Arc<dyn Fn(PgDatabaseError) + Send + Sync>>,
+
+    // whether an `ErrorResponse` was received since the last `ReadyForQuery`, which
+    // may not have been received yet when the error is returned
+    pub(crate) error_since_ready: bool,
 }
 
 impl PgStream {
     pub(super) async fn connect(options: &PgConnectOptions) -> Result<Self, Error> {
         let socket_future = match options.fetch_socket() {
             Some(ref path) => net::connect_uds(path, MaybeUpgradeTls(options)).await?,
-            None => net::connect_tcp(&options.host, options.port, MaybeUpgradeTls(options)).await?,
+            None => {
+                net::connect_tcp(
+                    &options.host,
+                    options.port,
+                    &options.tcp_options,
+                    MaybeUpgradeTls(options),
+                )
+                .await?
+            }
         };
 
         let socket = socket_future.await?;
@@ -52,6 +68,8 @@ impl PgStream {
             notifications: None,
             parameter_statuses: BTreeMap::default(),
             server_version_num: None,
+            notice_handler: None,
+            error_since_ready: false,
         })
     }
 
@@ -92,6 +110,12 @@ impl PgStream {
 
         let contents = self.inner.read(size).await?;
 
+        match format {
+            MessageFormat::ErrorResponse => self.error_since_ready = true,
+            MessageFormat::ReadyForQuery => self.error_since_ready = false,
+            _ => {}
+        }
+
         Ok(Message { format, contents })
     }
 
@@ -136,11 +160,14 @@ impl PgStream {
                 }
 
                 MessageFormat::NoticeResponse => {
-                    // do we need this to be more configurable?
-                    // if you are reading this comment and think so, open an issue
-
                     let notice: Notice = message.decode()?;
 
+                    if let Some(handler) = &self.notice_handler {
+                        handler(PgDatabaseError(notice));
+
+                        continue;
+                    }
+
                     let (log_level, tracing_level) = match notice.severity() {
                         PgSeverity::Fatal | PgSeverity::Panic | PgSeverity::Error => {
                             (Level::Error, tracing::Level::ERROR)
@@ -254,6 +281,11 @@ mod tests {
         // new style without minor version, e.g.  */
         assert_eq!(parse_server_version("10devel"), Some(100000));
         assert_eq!(parse_server_version("13devel87"), Some(130000));
+        // with a distribution suffix
+        assert_eq!(
+            parse_server_version("14.5 (Debian 14.5-1.pgdg110+1)"),
+            Some(140005)
+        );
         // unknown
         assert_eq!(parse_server_version("unknown"), None);
     }
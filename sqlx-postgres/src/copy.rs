@@ -4,8 +4,11 @@ use std::ops::{Deref, DerefMut};
 
 use futures_core::stream::BoxStream;
 use sqlx_core::bytes::{BufMut, Bytes};
+use sqlx_core::raw_sql::{SqlDialect, SqlLexer, SqlSpan};
 
+use crate::arguments::PgArgumentBuffer;
 use crate::connection::PgConnection;
+use crate::encode::Encode;
 use crate::error::{Error, Result};
 use crate::ext::async_stream::TryAsyncStream;
 use crate::io::{AsyncRead, AsyncReadExt};
@@ -13,7 +16,10 @@ use crate::message::{
     CommandComplete, CopyData, CopyDone, CopyFail, CopyResponse, MessageFormat, Query,
 };
 use crate::pool::{Pool, PoolConnection};
-use crate::Postgres;
+use crate::{PgTypeInfo, Postgres};
+
+/// The signature that starts the binary `COPY` format: `PGCOPY\n\377\r\n\0`.
+const BINARY_SIGNATURE: &[u8; 11] = b"PGCOPY\n\xff\r\n\0";
 
 impl PgConnection {
     /// Issue a `COPY FROM STDIN` statement and transition the connection to streaming data
@@ -50,6 +56,9 @@ impl PgConnection {
     /// If you don't read the stream to completion, the next time the connection is used it will
     /// need to read and discard all the remaining queued data, which could take some time.
     ///
+    /// If the server raises an error partway through the copy, the stream yields it as an
+    /// [`Error::Database`] and then ends. The connection remains usable afterwards.
+    ///
     /// Command examples and accepted formats for `COPY` data are shown here:
     /// https://www.postgresql.org/docs/current/sql-copy.html
     #[allow(clippy::needless_lifetimes)]
@@ -138,11 +147,34 @@ impl PgPoolCopyExt for Pool<Postgres> {
 pub struct PgCopyIn<C: DerefMut<Target = PgConnection>> {
     conn: Option<C>,
     response: CopyResponse,
+    // the types of the target columns of a binary `COPY`, if they could be determined
+    column_types: Option<Vec<PgTypeInfo>>,
+    binary_header_sent: bool,
+    raw_data_sent: bool,
 }
 
 impl<C: DerefMut<Target = PgConnection>> PgCopyIn<C> {
     async fn begin(mut conn: C, statement: &str) -> Result<Self> {
         conn.wait_until_ready().await?;
+
+        // `write_row()` needs the column types to encode values, but once the `COPY` has started
+        // we can't run any other statements to look them up.
+        let column_types = match binary_copy_in_columns(statement) {
+            Some(select) => {
+                let (_, metadata) = conn.get_or_prepare(&select, &[], true, None).await?;
+
+                Some(
+                    metadata
+                        .columns
+                        .iter()
+                        .map(|column| column.type_info.clone())
+                        .collect(),
+                )
+            }
+            None => None,
+        };
+
+        conn.write_count += 1;
         conn.stream.send(Query(statement)).await?;
 
         let response = match conn.stream.recv_expect(MessageFormat::CopyInResponse).await {
@@ -156,6 +188,9 @@ impl<C: DerefMut<Target = PgConnection>> PgCopyIn<C> {
         Ok(PgCopyIn {
             conn: Some(conn),
             response,
+            column_types,
+            binary_header_sent: false,
+            raw_data_sent: false,
         })
     }
 
@@ -186,6 +221,7 @@ impl<C: DerefMut<Target = PgConnection>> PgCopyIn<C> {
     ///
     /// If you're copying data from an `AsyncRead`, maybe consider [Self::read_from] instead.
     pub async fn send(&mut self, data: impl Deref<Target = [u8]>) -> Result<&mut Self> {
+        self.raw_data_sent = true;
         self.conn
             .as_deref_mut()
             .expect("send_data: conn taken")
@@ -196,6 +232,87 @@ impl<C: DerefMut<Target = PgConnection>> PgCopyIn<C> {
         Ok(self)
     }
 
+    /// Encode a row in the binary `COPY` format and send it.
+    ///
+    /// Each value is encoded the same way as a bind parameter of its Rust type, so they must
+    /// be given in the order of the columns in the `COPY` statement and have the binary format
+    /// those columns expect. A `None` is sent as `NULL`.
+    ///
+    /// Values are encoded for the type of their column, e.g. [`Json`][crate::types::Json] for
+    /// either `json` or `jsonb`. The column types are looked up when a
+    /// `COPY <table> [(<columns>)] FROM STDIN` statement in binary format is started; if the
+    /// table has generated columns, list the other columns in the statement.
+    ///
+    /// The 19-byte file header is sent before the first row, and the trailer is sent by
+    /// [Self::finish]. Don't mix this with [Self::send] or [Self::read_from].
+    ///
+    /// ```rust,ignore
+    /// let mut copy = conn
+    ///     .copy_in_raw("COPY users (id, name, email) FROM STDIN WITH (FORMAT BINARY)")
+    ///     .await?;
+    ///
+    /// for user in &users {
+    ///     copy.write_row(&[&user.id, &user.name, &user.email]).await?;
+    /// }
+    ///
+    /// copy.finish().await?;
+    /// ```
+    ///
+    /// ### Errors
+    /// An error is returned if the statement did not specify `FORMAT BINARY`.
+    pub async fn write_row(
+        &mut self,
+        row: &[&(dyn Encode<'_, Postgres> + Sync)],
+    ) -> Result<&mut Self> {
+        if self.is_textual() {
+            return Err(Error::Configuration(
+                "PgCopyIn::write_row requires `COPY ... FROM STDIN WITH (FORMAT BINARY)`".into(),
+            ));
+        }
+
+        if let Some(column_types) = &self.column_types {
+            if row.len() != column_types.len() {
+                return Err(Error::Protocol(format!(
+                    "COPY row has {} fields but the statement has {} columns",
+                    row.len(),
+                    column_types.len()
+                )));
+            }
+        }
+
+        let mut buf = PgArgumentBuffer::default();
+
+        if !self.binary_header_sent {
+            write_binary_header(&mut buf);
+        }
+
+        let num_fields = i16::try_from(row.len())
+            .map_err(|_| err_protocol!("too many fields in COPY row: {}", row.len()))?;
+
+        buf.extend(&num_fields.to_be_bytes());
+
+        let types = match &self.column_types {
+            Some(column_types) => column_types.clone(),
+            None => row
+                .iter()
+                .map(|value| value.produces().unwrap_or(PgTypeInfo::UNKNOWN))
+                .collect(),
+        };
+
+        for value in row {
+            buf.encode_field(*value);
+        }
+
+        let conn: &mut PgConnection = self.conn.as_deref_mut().expect("write_row: conn taken");
+
+        buf.apply_patches(conn, &types).await?;
+        conn.stream.send(CopyData(&buf[..])).await?;
+
+        self.binary_header_sent = true;
+
+        Ok(self)
+    }
+
     /// Copy data directly from `source` to the database without requiring an intermediate buffer.
     ///
     /// `source` will be read to the end.
@@ -220,6 +337,8 @@ impl<C: DerefMut<Target = PgConnection>> PgCopyIn<C> {
             }
         }
 
+        self.raw_data_sent = true;
+
         let conn: &mut PgConnection = self.conn.as_deref_mut().expect("copy_from: conn taken");
         loop {
             let buf = conn.stream.write_buffer_mut();
@@ -297,6 +416,21 @@ impl<C: DerefMut<Target = PgConnection>> PgCopyIn<C> {
             .take()
             .expect("CopyWriter::finish: conn taken illegally");
 
+        // even without any rows, binary data has to start with the header
+        if !self.binary_header_sent && !self.raw_data_sent && !self.is_textual() {
+            let mut header = Vec::new();
+            write_binary_header(&mut header);
+            conn.stream.send(CopyData(&header[..])).await?;
+            self.binary_header_sent = true;
+        }
+
+        if self.binary_header_sent {
+            // file trailer
+            conn.stream
+                .send(CopyData(&(-1_i16).to_be_bytes()[..]))
+                .await?;
+        }
+
         conn.stream.send(CopyDone).await?;
         let cc: CommandComplete = match conn
             .stream
@@ -328,6 +462,87 @@ impl<C: DerefMut<Target = PgConnection>> Drop for PgCopyIn<C> {
     }
 }
 
+fn write_binary_header(buf: &mut Vec<u8>) {
+    buf.extend_from_slice(BINARY_SIGNATURE);
+    // flags field
+    buf.extend(&0_i32.to_be_bytes());
+    // header extension area length
+    buf.extend(&0_i32.to_be_bytes());
+}
+
+/// If `statement` is a `COPY <table> [(<columns>)] FROM STDIN` in binary format, returns a
+/// `SELECT` of the same columns, which can be prepared to find out their types.
+fn binary_copy_in_columns(statement: &str) -> Option<String> {
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+
+    // words, quoted text and single punctuation characters
+    let mut tokens = Vec::new();
+
+    for (span, range) in SqlLexer::new(statement, SqlDialect::Postgres) {
+        let mut text = &statement[range];
+
+        match span {
+            SqlSpan::Comment => {}
+            SqlSpan::Quoted => tokens.push(text),
+            SqlSpan::Code => {
+                while let Some(c) = text.chars().next() {
+                    let len = if is_word_char(c) {
+                        text.find(|c| !is_word_char(c)).unwrap_or(text.len())
+                    } else {
+                        c.len_utf8()
+                    };
+
+                    if !c.is_whitespace() {
+                        tokens.push(&text[..len]);
+                    }
+
+                    text = &text[len..];
+                }
+            }
+        }
+    }
+
+    let is = |i: usize, keyword: &str| {
+        tokens
+            .get(i)
+            .is_some_and(|t| t.eq_ignore_ascii_case(keyword))
+    };
+
+    // `COPY (<query>) TO ...` has no table
+    if !is(0, "COPY") || tokens.len() < 2 || is(1, "(") {
+        return None;
+    }
+
+    // the table name may be qualified by a schema
+    let mut i = 2;
+
+    while is(i, ".") {
+        i += 2;
+    }
+
+    let table = tokens.get(1..i)?.concat();
+
+    let columns = if is(i, "(") {
+        let end = i + tokens[i..].iter().position(|t| *t == ")")?;
+        let columns = tokens[i + 1..end].join(" ");
+
+        i = end + 1;
+        columns
+    } else {
+        "*".to_owned()
+    };
+
+    // `FORMAT BINARY`, or `BINARY` before Postgres 9.0
+    if !is(i, "FROM")
+        || !is(i + 1, "STDIN")
+        || !tokens[i..].iter().any(|t| t.eq_ignore_ascii_case("BINARY"))
+    {
+        return None;
+    }
+
+    Some(format!("SELECT {columns} FROM {table}"))
+}
+
 async fn pg_begin_copy_out<'c, C: DerefMut<Target = PgConnection> + Send + 'c>(
     mut conn: C,
     statement: &str,
@@ -335,6 +550,10 @@ async fn pg_begin_copy_out<'c, C: DerefMut<Target = PgConnection> + Send + 'c>(
     conn.wait_until_ready().await?;
     conn.stream.send(Query(statement)).await?;
 
+    // if the copy fails or the stream is dropped early, the next use of the connection
+    // discards everything up to and including this `ReadyForQuery`
+    conn.pending_ready_for_query_count += 1;
+
     let _: CopyResponse = conn
         .stream
         .recv_expect(MessageFormat::CopyOutResponse)
@@ -348,7 +567,7 @@ async fn pg_begin_copy_out<'c, C: DerefMut<Target = PgConnection> + Send + 'c>(
                 MessageFormat::CopyDone => {
                     let _ = msg.decode::<CopyDone>()?;
                     conn.stream.recv_expect(MessageFormat::CommandComplete).await?;
-                    conn.stream.recv_expect(MessageFormat::ReadyForQuery).await?;
+                    conn.recv_ready_for_query().await?;
                     return Ok(())
                 },
                 _ => return Err(err_protocol!("unexpected message format during copy out: {:?}", msg.format))
@@ -358,3 +577,27 @@ async fn pg_begin_copy_out<'c, C: DerefMut<Target = PgConnection> + Send + 'c>(
 
     Ok(Box::pin(stream))
 }
+
+#[test]
+fn test_binary_copy_in_columns() {
+    assert_eq!(
+        binary_copy_in_columns("COPY foo (a, \"B\") FROM STDIN WITH (FORMAT BINARY)").as_deref(),
+        Some("SELECT a , \"B\" FROM foo")
+    );
+    assert_eq!(
+        binary_copy_in_columns("copy public.foo from stdin binary").as_deref(),
+        Some("SELECT * FROM public.foo")
+    );
+    assert_eq!(
+        binary_copy_in_columns("COPY foo FROM STDIN WITH (FORMAT CSV)"),
+        None
+    );
+    assert_eq!(
+        binary_copy_in_columns("COPY foo FROM STDIN /* BINARY */"),
+        None
+    );
+    assert_eq!(
+        binary_copy_in_columns("COPY (SELECT 1) TO STDOUT (FORMAT BINARY)"),
+        None
+    );
+}
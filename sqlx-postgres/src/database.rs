@@ -6,6 +6,7 @@ use crate::{
 };
 
 pub(crate) use sqlx_core::database::{Database, HasStatementCache};
+use sqlx_core::raw_sql::SqlDialect;
 
 /// PostgreSQL database driver.
 #[derive(Debug)]
@@ -35,6 +36,8 @@ impl Database for Postgres {
     const NAME: &'static str = "PostgreSQL";
 
     const URL_SCHEMES: &'static [&'static str] = &["postgres", "postgresql"];
+
+    const SQL_DIALECT: SqlDialect = SqlDialect::Postgres;
 }
 
 impl HasStatementCache for Postgres {}
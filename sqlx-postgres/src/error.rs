@@ -208,12 +208,25 @@ impl DatabaseError for PgDatabaseError {
         self.table()
     }
 
+    fn column(&self) -> Option<&str> {
+        self.column()
+    }
+
     fn kind(&self) -> ErrorKind {
         match self.code() {
             error_codes::UNIQUE_VIOLATION => ErrorKind::UniqueViolation,
             error_codes::FOREIGN_KEY_VIOLATION => ErrorKind::ForeignKeyViolation,
             error_codes::NOT_NULL_VIOLATION => ErrorKind::NotNullViolation,
             error_codes::CHECK_VIOLATION => ErrorKind::CheckViolation,
+            error_codes::SERIALIZATION_FAILURE => ErrorKind::Serialization,
+            error_codes::DEADLOCK_DETECTED => ErrorKind::Deadlock,
+            error_codes::LOCK_NOT_AVAILABLE => ErrorKind::Timeout,
+            error_codes::ADMIN_SHUTDOWN
+            | error_codes::CRASH_SHUTDOWN
+            | error_codes::CANNOT_CONNECT_NOW => ErrorKind::Connection,
+            code if code.starts_with(error_codes::CONNECTION_EXCEPTION_CLASS) => {
+                ErrorKind::Connection
+            }
             _ => ErrorKind::Other,
         }
     }
@@ -229,4 +242,19 @@ pub(crate) mod error_codes {
     pub const NOT_NULL_VIOLATION: &str = "23502";
     /// Caused when a check constraint is violated.
     pub const CHECK_VIOLATION: &str = "23514";
+
+    /// Caused when a transaction cannot be serialized with concurrent transactions.
+    pub const SERIALIZATION_FAILURE: &str = "40001";
+    /// Caused when a transaction is rolled back to resolve a deadlock.
+    pub const DEADLOCK_DETECTED: &str = "40P01";
+    /// Caused when a lock could not be acquired, e.g. within `lock_timeout`.
+    pub const LOCK_NOT_AVAILABLE: &str = "55P03";
+    /// Caused when the server is shut down, terminating the connection.
+    pub const ADMIN_SHUTDOWN: &str = "57P01";
+    /// Caused when the server crashed, terminating the connection.
+    pub const CRASH_SHUTDOWN: &str = "57P02";
+    /// Caused when the server is still starting up.
+    pub const CANNOT_CONNECT_NOW: &str = "57P03";
+    /// The class of errors with the connection, e.g. `08006` for `connection_failure`.
+    pub const CONNECTION_EXCEPTION_CLASS: &str = "08";
 }
@@ -1,11 +1,14 @@
+use std::cmp;
 use std::fmt::{self, Debug};
 use std::io;
 use std::str::from_utf8;
+use std::time::Duration;
 
 use futures_channel::mpsc;
 use futures_core::future::BoxFuture;
 use futures_core::stream::{BoxStream, Stream};
 use futures_util::{FutureExt, StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
 use sqlx_core::Either;
 
 use crate::describe::Describe;
@@ -29,11 +32,26 @@ pub struct PgListener {
     buffer_tx: Option<mpsc::UnboundedSender<Notification>>,
     channels: Vec<String>,
     ignore_close_event: bool,
+    reconnect_backoff: (Duration, Duration),
+    max_reconnect_retries: Option<u32>,
+    // set when a new connection is established, until `recv_event()` reports it
+    reconnected: bool,
 }
 
 /// An asynchronous notification from Postgres.
 pub struct PgNotification(Notification);
 
+/// An event received by [`PgListener::recv_event()`].
+#[derive(Debug)]
+pub enum PgListenerEvent {
+    /// A notification from one of the subscribed channels.
+    Notification(PgNotification),
+
+    /// The connection was lost and has been re-established, listening on all subscribed channels
+    /// again. Any notifications sent in the meantime were missed.
+    Reconnected,
+}
+
 impl PgListener {
     pub async fn connect(url: &str) -> Result<Self, Error> {
         // Create a pool of 1 without timeouts (as they don't apply here)
@@ -67,6 +85,9 @@ impl PgListener {
             buffer_tx: None,
             channels: Vec::new(),
             ignore_close_event: false,
+            reconnect_backoff: (Duration::from_millis(100), Duration::from_secs(10)),
+            max_reconnect_retries: Some(0),
+            reconnected: false,
         })
     }
 
@@ -93,6 +114,23 @@ impl PgListener {
         self.ignore_close_event = val;
     }
 
+    /// Set the delay before retrying a failed attempt to reconnect, which doubles with every
+    /// failed attempt up to `max`. Defaults to 100 milliseconds, up to 10 seconds.
+    ///
+    /// See [`Self::max_reconnect_retries()`].
+    pub fn reconnect_backoff(&mut self, initial: Duration, max: Duration) {
+        self.reconnect_backoff = (initial, max);
+    }
+
+    /// Set how many times to retry reconnecting when the first attempt fails, or `None` to
+    /// retry until it succeeds. Defaults to `Some(0)`.
+    ///
+    /// Once the retries are exhausted, the last error is returned from [`Self::recv()`],
+    /// [`Self::recv_event()`] or [`Self::try_recv()`]. [`Error::PoolClosed`] is never retried.
+    pub fn max_reconnect_retries(&mut self, retries: Option<u32>) {
+        self.max_reconnect_retries = retries;
+    }
+
     /// Starts listening for notifications on a channel.
     /// The channel name is quoted here to ensure case sensitivity.
     pub async fn listen(&mut self, channel: &str) -> Result<(), Error> {
@@ -153,22 +191,92 @@ impl PgListener {
 
     #[inline]
     async fn connect_if_needed(&mut self) -> Result<(), Error> {
-        if self.connection.is_none() {
-            let mut connection = self.pool.acquire().await?;
-            connection.stream.notifications = self.buffer_tx.take();
+        if self.connection.is_some() {
+            return Ok(());
+        }
 
-            connection
-                .execute(&*build_listen_all_query(&self.channels))
-                .await?;
+        let (mut backoff, max_backoff) = self.reconnect_backoff;
+        let mut retries = 0;
+
+        loop {
+            match self.reconnect().await {
+                Ok(()) => return Ok(()),
+                Err(Error::PoolClosed) => return Err(Error::PoolClosed),
+                Err(error) if !matches!(self.max_reconnect_retries, Some(max) if retries >= max) => {
+                    tracing::debug!(%error, retries, "failed to reconnect PgListener");
+
+                    crate::rt::sleep(backoff).await;
 
-            self.connection = Some(connection);
+                    retries += 1;
+                    backoff = cmp::min(backoff * 2, max_backoff);
+                }
+                Err(error) => return Err(error),
+            }
         }
+    }
+
+    async fn reconnect(&mut self) -> Result<(), Error> {
+        let mut connection = self.pool.acquire().await?;
+        connection.stream.notifications = self.buffer_tx.take();
+
+        let query = build_listen_all_query(&self.channels);
+
+        if let Err(error) = connection.execute(&*query).await {
+            // keep the buffer for the next attempt
+            self.buffer_tx = connection.stream.notifications.take();
+            return Err(error);
+        }
+
+        self.connection = Some(connection);
+        self.reconnected = true;
 
         Ok(())
     }
 
-    #[inline]
-    async fn connection(&mut self) -> Result<&mut PgConnection, Error> {
+    /// Returns the connection this listener is using, (re)connecting if needed.
+    ///
+    /// This lets you run queries on the same session that is listening, e.g. a catch-up query
+    /// right after subscribing, without opening a second connection. `&mut PgListener` can
+    /// also be used as an [`Executor`] directly.
+    ///
+    /// ```rust,no_run
+    /// # use sqlx_core::postgres::PgListener;
+    /// # use sqlx_core::error::Error;
+    /// #
+    /// # #[cfg(feature = "_rt")]
+    /// # sqlx::__rt::test_block_on(async move {
+    /// # let mut listener = PgListener::connect("postgres:// ...").await?;
+    /// listener.listen("orders").await?;
+    ///
+    /// // anything that happened before `LISTEN` took effect
+    /// let missed: Vec<(i64,)> = sqlx::query_as("SELECT id FROM orders WHERE NOT processed")
+    ///     .fetch_all(listener.connection().await?)
+    ///     .await?;
+    ///
+    /// loop {
+    ///     let notification = listener.recv().await?;
+    ///     // ...
+    /// }
+    /// # Result::<(), Error>::Ok(())
+    /// # }).unwrap();
+    /// ```
+    ///
+    /// ### Ordering
+    /// Notifications that arrive while a query runs on this connection are buffered, and
+    /// returned by the following calls to [`recv()`](Self::recv) before any notification that
+    /// arrives later, in the order Postgres sent them.
+    ///
+    /// `listen()` returns once `LISTEN` has taken effect, so every change committed afterwards
+    /// sends a notification, and every change committed before is seen by a query run after
+    /// `listen()`. Some changes may be both seen by the query and notified about, so handling
+    /// them should be idempotent.
+    ///
+    /// ### Note
+    /// Use [`listen()`](Self::listen) and [`unlisten()`](Self::unlisten) instead of running
+    /// `LISTEN` or `UNLISTEN` yourself, otherwise the channels will be out of sync after
+    /// a reconnect. Likewise, session state such as `SET` or temporary tables is lost when the
+    /// connection is re-established.
+    pub async fn connection(&mut self) -> Result<&mut PgConnection, Error> {
         // Ensure we have an active connection to work with.
         self.connect_if_needed().await?;
 
@@ -183,7 +291,8 @@ impl PgListener {
     ///
     /// As notifications are transient, any received while the connection was lost, will not
     /// be returned. If you'd prefer the reconnection to be explicit and have a chance to
-    /// do something before, please see [`try_recv`](Self::try_recv).
+    /// do something before, please see [`try_recv`](Self::try_recv), or
+    /// [`recv_event`](Self::recv_event) to be told after the fact.
     ///
     /// # Example
     ///
@@ -211,6 +320,92 @@ impl PgListener {
         }
     }
 
+    /// Receives the next notification available from any of the subscribed channels and
+    /// deserializes its payload from JSON.
+    ///
+    /// Reconnects like [`recv`](Self::recv). If the payload can't be deserialized, the
+    /// notification is consumed and [`Error::Decode`] is returned, with the channel name and the
+    /// raw payload in its message. To also get the channel name, use [`recv`](Self::recv) and
+    /// [`PgNotification::payload_as()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use sqlx_core::postgres::PgListener;
+    /// # use sqlx_core::error::Error;
+    /// #
+    /// #[derive(serde::Deserialize)]
+    /// struct OrderCreated {
+    ///     id: i64,
+    /// }
+    ///
+    /// # #[cfg(feature = "_rt")]
+    /// # sqlx::__rt::test_block_on(async move {
+    /// # let mut listener = PgListener::connect("postgres:// ...").await?;
+    /// listener.listen("order_created").await?;
+    ///
+    /// loop {
+    ///     // sent with e.g. `SELECT pg_notify('order_created', json_build_object('id', id)::text)`
+    ///     let order: OrderCreated = listener.recv_as().await?;
+    /// }
+    /// # Result::<(), Error>::Ok(())
+    /// # }).unwrap();
+    /// ```
+    pub async fn recv_as<T: DeserializeOwned>(&mut self) -> Result<T, Error> {
+        self.recv().await?.payload_as()
+    }
+
+    /// Receives the next notification available from any of the subscribed channels, or
+    /// [`PgListenerEvent::Reconnected`] if the connection to PostgreSQL was lost and has been
+    /// re-established.
+    ///
+    /// Unlike [`recv`](Self::recv), this tells when notifications may have been missed, e.g. to
+    /// re-read the state they were about. The event is returned once for every new connection,
+    /// however it was established, including by an earlier call that failed to reconnect or by
+    /// other methods such as [`connection()`](Self::connection).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use sqlx_core::postgres::{PgListener, PgListenerEvent};
+    /// # use sqlx_core::error::Error;
+    /// #
+    /// # #[cfg(feature = "_rt")]
+    /// # sqlx::__rt::test_block_on(async move {
+    /// # let mut listener = PgListener::connect("postgres:// ...").await?;
+    /// listener.max_reconnect_retries(None);
+    ///
+    /// loop {
+    ///     match listener.recv_event().await? {
+    ///         PgListenerEvent::Notification(notification) => {
+    ///             // handle notification
+    ///         }
+    ///         PgListenerEvent::Reconnected => {
+    ///             // notifications may have been missed, catch up
+    ///         }
+    ///     }
+    /// }
+    /// # Result::<(), Error>::Ok(())
+    /// # }).unwrap();
+    /// ```
+    pub async fn recv_event(&mut self) -> Result<PgListenerEvent, Error> {
+        // a previous call may have lost the connection without re-establishing it
+        self.connect_if_needed().await?;
+
+        if !self.reconnected {
+            if let Some(notification) = self.try_recv().await? {
+                return Ok(PgListenerEvent::Notification(notification));
+            }
+
+            // the connection was lost
+            self.connect_if_needed().await?;
+        }
+
+        self.reconnected = false;
+
+        Ok(PgListenerEvent::Reconnected)
+    }
+
     /// Receives the next notification available from any of the subscribed channels.
     ///
     /// If the connection to PostgreSQL is lost, `None` is returned, and the connection is
@@ -263,8 +458,13 @@ impl PgListener {
                 // The connection is dead, ensure that it is dropped,
                 // update self state, and loop to try again.
                 Err(Error::Io(err))
-                    if (err.kind() == io::ErrorKind::ConnectionAborted
-                        || err.kind() == io::ErrorKind::UnexpectedEof) =>
+                    if matches!(
+                        err.kind(),
+                        io::ErrorKind::ConnectionAborted
+                            | io::ErrorKind::ConnectionReset
+                            | io::ErrorKind::BrokenPipe
+                            | io::ErrorKind::UnexpectedEof
+                    ) =>
                 {
                     self.buffer_tx = self.connection().await?.stream.notifications.take();
                     self.connection = None;
@@ -409,6 +609,24 @@ impl PgNotification {
     pub fn payload(&self) -> &str {
         from_utf8(&self.0.payload).unwrap()
     }
+
+    /// Deserializes the payload from JSON.
+    ///
+    /// On failure, [`Error::Decode`] is returned, with the channel name and the raw payload in
+    /// its message.
+    pub fn payload_as<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        serde_json::from_str(self.payload()).map_err(|e| {
+            Error::Decode(
+                format!(
+                    "failed to deserialize payload of notification on channel {:?}: {e}; \
+                     payload: {:?}",
+                    self.channel(),
+                    self.payload()
+                )
+                .into(),
+            )
+        })
+    }
 }
 
 impl Debug for PgListener {
@@ -458,3 +676,24 @@ fn test_build_listen_all_query_with_multiple_channels() {
     let output = build_listen_all_query(&["channel.0", "channel.1"]);
     assert_eq!(output.as_str(), r#"LISTEN "channel.0";LISTEN "channel.1";"#);
 }
+
+#[test]
+fn test_notification_payload_as() {
+    let notification = |payload: &'static str| {
+        PgNotification(Notification {
+            process_id: 1,
+            channel: "orders".into(),
+            payload: payload.into(),
+        })
+    };
+
+    let ids: Vec<i64> = notification("[1, 2]").payload_as().unwrap();
+    assert_eq!(ids, [1, 2]);
+
+    let err = notification("not json")
+        .payload_as::<Vec<i64>>()
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains(r#"channel "orders""#), "{err}");
+    assert!(err.contains(r#"payload: "not json""#), "{err}");
+}
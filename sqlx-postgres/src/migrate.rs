@@ -1,3 +1,4 @@
+use std::cmp;
 use std::str::FromStr;
 use std::time::Duration;
 use std::time::Instant;
@@ -16,6 +17,9 @@ use crate::query_as::query_as;
 use crate::query_scalar::query_scalar;
 use crate::{PgConnectOptions, PgConnection, Postgres};
 
+/// How often to retry taking the migration lock when waiting with a timeout.
+const LOCK_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
 fn parse_for_maintenance(url: &str) -> Result<(PgConnectOptions, String), Error> {
     let mut options = PgConnectOptions::from_str(url)?;
 
@@ -91,11 +95,16 @@ impl MigrateDatabase for Postgres {
             let (options, database) = parse_for_maintenance(url)?;
             let mut conn = options.connect().await?;
 
-            let row: (String,) = query_as("SELECT current_setting('server_version_num')")
-                .fetch_one(&mut conn)
-                .await?;
+            let version = match conn.server_version_num() {
+                Some(version) => version,
+                None => {
+                    let row: (String,) = query_as("SELECT current_setting('server_version_num')")
+                        .fetch_one(&mut conn)
+                        .await?;
 
-            let version = row.0.parse::<i32>().unwrap();
+                    row.0.parse::<u32>().unwrap()
+                }
+            };
 
             let pid_type = if version >= 90200 { "pid" } else { "procpid" };
 
@@ -132,6 +141,17 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
+    fn migrations_table_exists(&mut self) -> BoxFuture<'_, Result<bool, MigrateError>> {
+        Box::pin(async move {
+            // language=SQL
+            let exists: bool = query_scalar("SELECT to_regclass('_sqlx_migrations') IS NOT NULL")
+                .fetch_one(self)
+                .await?;
+
+            Ok(exists)
+        })
+    }
+
     fn dirty_version(&mut self) -> BoxFuture<'_, Result<Option<i64>, MigrateError>> {
         Box::pin(async move {
             // language=SQL
@@ -150,17 +170,36 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
     ) -> BoxFuture<'_, Result<Vec<AppliedMigration>, MigrateError>> {
         Box::pin(async move {
             // language=SQL
-            let rows: Vec<(i64, Vec<u8>)> =
-                query_as("SELECT version, checksum FROM _sqlx_migrations ORDER BY version")
-                    .fetch_all(self)
-                    .await?;
+            let rows: Vec<(i64, Vec<u8>, String, i64, i64, bool)> = query_as(
+                r#"
+SELECT
+    version,
+    checksum,
+    description,
+    (extract(epoch FROM installed_on) * 1000000)::int8,
+    execution_time,
+    success
+FROM _sqlx_migrations
+ORDER BY version
+                "#,
+            )
+            .fetch_all(self)
+            .await?;
 
             let migrations = rows
                 .into_iter()
-                .map(|(version, checksum)| AppliedMigration {
-                    version,
-                    checksum: checksum.into(),
-                })
+                .map(
+                    |(version, checksum, description, installed_on, execution_time, success)| {
+                        AppliedMigration::from_row(
+                            version,
+                            checksum,
+                            description,
+                            installed_on,
+                            execution_time,
+                            success,
+                        )
+                    },
+                )
                 .collect();
 
             Ok(migrations)
@@ -168,30 +207,67 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
     }
 
     fn lock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
+        self.lock_with(None, None)
+    }
+
+    fn unlock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
+        self.unlock_with(None)
+    }
+
+    fn lock_with(
+        &mut self,
+        key: Option<i64>,
+        timeout: Option<Duration>,
+    ) -> BoxFuture<'_, Result<(), MigrateError>> {
         Box::pin(async move {
-            let database_name = current_database(self).await?;
-            let lock_id = generate_lock_id(&database_name);
+            let lock_id = lock_id(self, key).await?;
 
             // create an application lock over the database
-            // this function will not return until the lock is acquired
 
             // https://www.postgresql.org/docs/current/explicit-locking.html#ADVISORY-LOCKS
             // https://www.postgresql.org/docs/current/functions-admin.html#FUNCTIONS-ADVISORY-LOCKS-TABLE
 
-            // language=SQL
-            let _ = query("SELECT pg_advisory_lock($1)")
-                .bind(lock_id)
-                .execute(self)
-                .await?;
+            let Some(timeout) = timeout else {
+                // this function will not return until the lock is acquired
 
-            Ok(())
+                // language=SQL
+                let _ = query("SELECT pg_advisory_lock($1)")
+                    .bind(lock_id)
+                    .execute(self)
+                    .await?;
+
+                return Ok(());
+            };
+
+            // Poll rather than relying on `lock_timeout`, which would also apply to the
+            // migrations themselves if left set on the connection.
+            let deadline = Instant::now() + timeout;
+
+            loop {
+                // language=SQL
+                let locked: bool = query_scalar("SELECT pg_try_advisory_lock($1)")
+                    .bind(lock_id)
+                    .fetch_one(&mut *self)
+                    .await?;
+
+                if locked {
+                    return Ok(());
+                }
+
+                let now = Instant::now();
+
+                if now >= deadline {
+                    return Err(MigrateError::LockTimeout);
+                }
+
+                sqlx_core::rt::sleep(cmp::min(LOCK_POLL_INTERVAL, deadline - now)).await;
+            }
         })
     }
 
-    fn unlock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
+    fn unlock_with(&mut self, key: Option<i64>) -> BoxFuture<'_, Result<(), MigrateError>> {
         Box::pin(async move {
-            let database_name = current_database(self).await?;
-            let lock_id = generate_lock_id(&database_name);
+            let lock_id = lock_id(self, key).await?;
 
             // language=SQL
             let _ = query("SELECT pg_advisory_unlock($1)")
@@ -208,33 +284,21 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         migration: &'m Migration,
     ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
         Box::pin(async move {
-            let mut tx = self.begin().await?;
-            let start = Instant::now();
-
             // Use a single transaction for the actual migration script and the essential bookeeping so we never
             // execute migrations twice. See https://github.com/launchbadge/sqlx/issues/1966.
             // The `execution_time` however can only be measured for the whole transaction. This value _only_ exists for
             // data lineage and debugging reasons, so it is not super important if it is lost. So we initialize it to -1
             // and update it once the actual transaction completed.
-            let _ = tx
-                .execute(&*migration.sql)
-                .await
-                .map_err(|e| MigrateError::ExecuteMigration(e, migration.version))?;
-
-            // language=SQL
-            let _ = query(
-                r#"
-    INSERT INTO _sqlx_migrations ( version, description, success, checksum, execution_time )
-    VALUES ( $1, $2, TRUE, $3, -1 )
-                "#,
-            )
-            .bind(migration.version)
-            .bind(&*migration.description)
-            .bind(&*migration.checksum)
-            .execute(&mut *tx)
-            .await?;
+            let start = Instant::now();
 
-            tx.commit().await?;
+            if migration.no_tx() {
+                // opted out with `-- sqlx:no-transaction`, e.g. for `CREATE INDEX CONCURRENTLY`
+                execute_migration(&mut *self, migration).await?;
+            } else {
+                let mut tx = self.begin().await?;
+                execute_migration(&mut tx, migration).await?;
+                tx.commit().await?;
+            }
 
             // Update `elapsed_time`.
             // NOTE: The process may disconnect/die at this point, so the elapsed time value might be lost. We accept
@@ -266,26 +330,77 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         Box::pin(async move {
             // Use a single transaction for the actual migration script and the essential bookeeping so we never
             // execute migrations twice. See https://github.com/launchbadge/sqlx/issues/1966.
-            let mut tx = self.begin().await?;
             let start = Instant::now();
 
-            let _ = tx.execute(&*migration.sql).await?;
+            if migration.no_tx() {
+                // opted out with `-- sqlx:no-transaction`, e.g. for `CREATE INDEX CONCURRENTLY`
+                revert_migration(&mut *self, migration).await?;
+            } else {
+                let mut tx = self.begin().await?;
+                revert_migration(&mut tx, migration).await?;
+                tx.commit().await?;
+            }
 
+            let elapsed = start.elapsed();
+
+            Ok(elapsed)
+        })
+    }
+
+    fn update_checksum<'e: 'm, 'm>(
+        &'e mut self,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<(), MigrateError>> {
+        Box::pin(async move {
             // language=SQL
-            let _ = query(r#"DELETE FROM _sqlx_migrations WHERE version = $1"#)
+            let _ = query(r#"UPDATE _sqlx_migrations SET checksum = $1 WHERE version = $2"#)
+                .bind(&*migration.checksum)
                 .bind(migration.version)
-                .execute(&mut *tx)
+                .execute(self)
                 .await?;
 
-            tx.commit().await?;
-
-            let elapsed = start.elapsed();
-
-            Ok(elapsed)
+            Ok(())
         })
     }
 }
 
+async fn execute_migration(
+    conn: &mut PgConnection,
+    migration: &Migration,
+) -> Result<(), MigrateError> {
+    migration.execute(&mut *conn).await?;
+
+    // language=SQL
+    let _ = query(
+        r#"
+    INSERT INTO _sqlx_migrations ( version, description, success, checksum, execution_time )
+    VALUES ( $1, $2, TRUE, $3, -1 )
+            "#,
+    )
+    .bind(migration.version)
+    .bind(&*migration.description)
+    .bind(&*migration.checksum)
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+async fn revert_migration(
+    conn: &mut PgConnection,
+    migration: &Migration,
+) -> Result<(), MigrateError> {
+    migration.execute(&mut *conn).await?;
+
+    // language=SQL
+    let _ = query(r#"DELETE FROM _sqlx_migrations WHERE version = $1"#)
+        .bind(migration.version)
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(())
+}
+
 async fn current_database(conn: &mut PgConnection) -> Result<String, MigrateError> {
     // language=SQL
     Ok(query_scalar("SELECT current_database()")
@@ -293,6 +408,13 @@ async fn current_database(conn: &mut PgConnection) -> Result<String, MigrateErro
         .await?)
 }
 
+async fn lock_id(conn: &mut PgConnection, key: Option<i64>) -> Result<i64, MigrateError> {
+    match key {
+        Some(key) => Ok(key),
+        None => Ok(generate_lock_id(&current_database(conn).await?)),
+    }
+}
+
 // inspired from rails: https://github.com/rails/rails/blob/6e49cc77ab3d16c06e12f93158eaf3e507d4120e/activerecord/lib/active_record/migration.rb#L1308
 fn generate_lock_id(database_name: &str) -> i64 {
     const CRC_IEEE: crc::Crc<u32> = crc::Crc::<u32>::new(&crc::CRC_32_ISO_HDLC);
@@ -1,7 +1,11 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
 use futures_core::future::BoxFuture;
 
 use crate::error::Error;
 use crate::executor::Executor;
+use crate::message::TransactionStatus as PgTransactionStatus;
 
 use crate::{PgConnection, Postgres};
 
@@ -14,9 +18,18 @@ impl TransactionManager for PgTransactionManager {
     type Database = Postgres;
 
     fn begin(conn: &mut PgConnection) -> BoxFuture<'_, Result<(), Error>> {
+        Self::begin_with(conn, TransactionOptions::default())
+    }
+
+    fn begin_with(
+        conn: &mut PgConnection,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<(), Error>> {
         Box::pin(async move {
+            check_transaction_options(conn.transaction_depth, &options)?;
+
             let rollback = Rollback::new(conn);
-            let query = begin_ansi_transaction_sql(rollback.conn.transaction_depth);
+            let query = begin_transaction_sql(rollback.conn.transaction_depth, &options);
             rollback.conn.queue_simple_query(&query);
             rollback.conn.transaction_depth += 1;
             rollback.conn.wait_until_ready().await?;
@@ -59,6 +72,45 @@ impl TransactionManager for PgTransactionManager {
             conn.transaction_depth -= 1;
         }
     }
+
+    fn transaction_status(conn: &PgConnection) -> TransactionStatus {
+        // as of the last `ReadyForQuery` message
+        match conn.transaction_status {
+            // an error aborts the transaction, even if the `ReadyForQuery` saying so is pending
+            PgTransactionStatus::Transaction if conn.stream.error_since_ready => {
+                TransactionStatus::Aborted
+            }
+            PgTransactionStatus::Transaction => TransactionStatus::Active,
+            PgTransactionStatus::Error => TransactionStatus::Aborted,
+            PgTransactionStatus::Idle => TransactionStatus::Closed,
+        }
+    }
+
+    fn write_count(conn: &PgConnection) -> Option<u64> {
+        Some(conn.write_count)
+    }
+}
+
+fn begin_transaction_sql(depth: usize, options: &TransactionOptions) -> Cow<'static, str> {
+    if options.is_default() {
+        return begin_ansi_transaction_sql(depth);
+    }
+
+    let mut sql = String::from("BEGIN");
+
+    if let Some(isolation) = options.isolation {
+        write!(sql, " ISOLATION LEVEL {}", isolation.as_sql()).unwrap();
+    }
+
+    if options.read_only {
+        sql.push_str(" READ ONLY");
+    }
+
+    if options.deferrable {
+        sql.push_str(" DEFERRABLE");
+    }
+
+    Cow::Owned(sql)
 }
 
 struct Rollback<'c> {
@@ -85,3 +137,27 @@ impl<'c> Rollback<'c> {
         self.defuse = true;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_builds_begin_with_options() {
+        let options = TransactionOptions {
+            isolation: Some(IsolationLevel::Serializable),
+            read_only: true,
+            deferrable: false,
+        };
+
+        assert_eq!(
+            begin_transaction_sql(0, &options),
+            "BEGIN ISOLATION LEVEL SERIALIZABLE READ ONLY"
+        );
+        assert_eq!(
+            begin_transaction_sql(0, &TransactionOptions::default()),
+            "BEGIN"
+        );
+        assert!(check_transaction_options(1, &options).is_err());
+    }
+}
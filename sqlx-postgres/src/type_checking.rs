@@ -30,6 +30,10 @@ impl_type_checking!(
 
         sqlx::postgres::types::PgLQuery,
 
+        sqlx::postgres::types::PgPoint,
+
+        sqlx::postgres::types::PgBox,
+
         #[cfg(feature = "uuid")]
         sqlx::types::Uuid,
 
@@ -94,6 +98,8 @@ impl_type_checking!(
         Vec<f64> | &[f64],
         Vec<sqlx::postgres::types::Oid> | &[sqlx::postgres::types::Oid],
         Vec<sqlx::postgres::types::PgMoney> | &[sqlx::postgres::types::PgMoney],
+        Vec<sqlx::postgres::types::PgPoint> | &[sqlx::postgres::types::PgPoint],
+        Vec<sqlx::postgres::types::PgBox> | &[sqlx::postgres::types::PgBox],
 
         #[cfg(feature = "uuid")]
         Vec<sqlx::types::Uuid> | &[sqlx::types::Uuid],
@@ -48,6 +48,10 @@ use crate::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
 /// ```
 ///
 /// See [the documentation of `Type`][Type] for more details.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be used as the element of a Postgres array",
+    note = "implement `PgHasArrayType` for `{Self}` to bind it as `Vec<{Self}>` or `&[{Self}]`, e.g. for `= ANY($1)`"
+)]
 pub trait PgHasArrayType {
     fn array_type_info() -> PgTypeInfo;
     fn array_compatible(ty: &PgTypeInfo) -> bool {
@@ -139,6 +143,11 @@ where
     fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
         self.as_slice().encode_by_ref(buf)
     }
+
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.as_slice().size_hint()
+    }
 }
 
 impl<'q, T, const N: usize> Encode<'q, Postgres> for [T; N]
@@ -149,6 +158,10 @@ where
     fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
         self.as_slice().encode_by_ref(buf)
     }
+
+    fn size_hint(&self) -> usize {
+        self.as_slice().size_hint()
+    }
 }
 
 impl<'q, T> Encode<'q, Postgres> for &'_ [T]
@@ -156,33 +169,62 @@ where
     T: Encode<'q, Postgres> + Type<Postgres>,
 {
     fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
-        let type_info = if self.len() < 1 {
-            T::type_info()
-        } else {
-            self[0].produces().unwrap_or_else(T::type_info)
-        };
+        // pre-size the buffer so large arrays don't reallocate for every element
+        buf.reserve(self.size_hint());
 
-        buf.extend(&1_i32.to_be_bytes()); // number of dimensions
-        buf.extend(&0_i32.to_be_bytes()); // flags
+        encode_array(buf, T::type_info(), self.iter())
+    }
 
-        // element type
-        match type_info.0 {
-            PgType::DeclareWithName(name) => buf.patch_type_by_name(&name),
+    fn size_hint(&self) -> usize {
+        // 20 bytes of header, then each element is prefixed by its length
+        20 + self.iter().map(|e| 4 + e.size_hint()).sum::<usize>()
+    }
+}
 
-            ty => {
-                buf.extend(&ty.oid().0.to_be_bytes());
-            }
-        }
+/// Encode the elements of `iter` as a one-dimensional array.
+///
+/// The element type is taken from the first element, falling back to `type_info`
+/// when it does not produce one (or when there are no elements).
+pub(crate) fn encode_array<'q, T, I>(
+    buf: &mut PgArgumentBuffer,
+    type_info: PgTypeInfo,
+    iter: I,
+) -> IsNull
+where
+    T: Encode<'q, Postgres>,
+    I: IntoIterator<Item = T>,
+{
+    let mut iter = iter.into_iter().peekable();
+
+    let type_info = iter.peek().and_then(Encode::produces).unwrap_or(type_info);
 
-        buf.extend(&(self.len() as i32).to_be_bytes()); // len
-        buf.extend(&1_i32.to_be_bytes()); // lower bound
+    buf.extend(&1_i32.to_be_bytes()); // number of dimensions
+    buf.extend(&0_i32.to_be_bytes()); // flags
 
-        for element in self.iter() {
-            buf.encode(element);
+    // element type
+    match type_info.0 {
+        PgType::DeclareWithName(name) => buf.patch_type_by_name(&name),
+
+        ty => {
+            buf.extend(&ty.oid().0.to_be_bytes());
         }
+    }
 
-        IsNull::No
+    // the length is only known once the elements have been written
+    let len_offset = buf.len();
+    buf.extend(&0_i32.to_be_bytes()); // len
+    buf.extend(&1_i32.to_be_bytes()); // lower bound
+
+    let mut len = 0_i32;
+
+    for element in iter {
+        buf.encode(element);
+        len += 1;
     }
+
+    buf[len_offset..(len_offset + 4)].copy_from_slice(&len.to_be_bytes());
+
+    IsNull::No
 }
 
 impl<'r, T, const N: usize> Decode<'r, Postgres> for [T; N]
@@ -239,6 +281,18 @@ where
                         ))
                     })?;
 
+                // the element type is resolved from the array type when it is not a builtin,
+                // so make sure the two agree
+                if let Some(oid) = element_type_info.oid() {
+                    if oid != element_type_oid {
+                        return Err(format!(
+                            "array element type mismatch: expected {} (oid {}), got oid {}",
+                            element_type_info, oid.0, element_type_oid.0
+                        )
+                        .into());
+                    }
+                }
+
                 // length of the array axis
                 let len = buf.get_i32();
 
@@ -280,11 +334,11 @@ where
 
                 // NOTE: Nearly *all* types use ',' as the sequence delimiter. Yes, there is one
                 //       that does not. The BOX (not PostGIS) type uses ';' as a delimiter.
-
-                // TODO: When we add support for BOX we need to figure out some way to make the
-                //       delimiter selection
-
-                let delimiter = ',';
+                let delimiter = if element_type_info == PgTypeInfo::BOX {
+                    ';'
+                } else {
+                    ','
+                };
                 let mut done = false;
                 let mut in_quotes = false;
                 let mut in_escape = false;
@@ -6,7 +6,7 @@ use num_bigint::{BigInt, Sign};
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
-use crate::types::numeric::{PgNumeric, PgNumericSign};
+use crate::types::numeric::{nan_error, PgNumeric, PgNumericSign};
 use crate::types::Type;
 use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
 
@@ -35,7 +35,7 @@ impl TryFrom<PgNumeric> for BigDecimal {
             } => (digits, sign, weight),
 
             PgNumeric::NotANumber => {
-                return Err("BigDecimal does not support NaN values".into());
+                return Err(nan_error("BigDecimal"));
             }
         };
 
@@ -166,12 +166,16 @@ impl Encode<'_, Postgres> for BigDecimal {
 
 /// ### Note: `NaN`
 /// `BigDecimal` has a greater range than `NUMERIC` (see the corresponding `Encode` impl for details)
-/// but cannot represent `NaN`, so decoding may return an error.
+/// but cannot represent `NaN`, so decoding may return an error. Decode into
+/// [`PgNumericValue<BigDecimal>`][crate::types::PgNumericValue] to handle `NaN` instead.
 impl Decode<'_, Postgres> for BigDecimal {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
         match value.format() {
             PgValueFormat::Binary => PgNumeric::decode(value.as_bytes()?)?.try_into(),
-            PgValueFormat::Text => Ok(value.as_str()?.parse::<BigDecimal>()?),
+            PgValueFormat::Text => match value.as_str()? {
+                "NaN" => Err(nan_error("BigDecimal")),
+                s => Ok(s.parse::<BigDecimal>()?),
+            },
         }
     }
 }
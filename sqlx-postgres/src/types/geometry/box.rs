@@ -0,0 +1,160 @@
+use std::str::FromStr;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+
+use super::{parse_floats, read_floats};
+
+/// The PostgreSQL [`BOX`] type, a rectangle given by two opposite corners.
+///
+/// Postgres reorders the corners on input so that the upper right corner comes first; boxes
+/// decoded from the database are always in that form. The text format is `(x1,y1),(x2,y2)`.
+///
+/// [`BOX`]: https://www.postgresql.org/docs/current/datatype-geometric.html#DATATYPE-GEOMETRIC-BOXES
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgBox {
+    pub upper_right_x: f64,
+    pub upper_right_y: f64,
+    pub lower_left_x: f64,
+    pub lower_left_y: f64,
+}
+
+impl Type<Postgres> for PgBox {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::BOX
+    }
+}
+
+impl PgHasArrayType for PgBox {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::BOX_ARRAY
+    }
+}
+
+impl Encode<'_, Postgres> for PgBox {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        // https://github.com/postgres/postgres/blob/REL_16_0/src/backend/utils/adt/geo_ops.c#L487
+        buf.extend(&self.upper_right_x.to_be_bytes());
+        buf.extend(&self.upper_right_y.to_be_bytes());
+        buf.extend(&self.lower_left_x.to_be_bytes());
+        buf.extend(&self.lower_left_y.to_be_bytes());
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        32
+    }
+}
+
+impl Decode<'_, Postgres> for PgBox {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => {
+                let [upper_right_x, upper_right_y, lower_left_x, lower_left_y] =
+                    read_floats(value.as_bytes()?, "BOX")?;
+
+                Ok(PgBox {
+                    upper_right_x,
+                    upper_right_y,
+                    lower_left_x,
+                    lower_left_y,
+                })
+            }
+            PgValueFormat::Text => value.as_str()?.parse(),
+        }
+    }
+}
+
+impl FromStr for PgBox {
+    type Err = BoxDynError;
+
+    /// Parse a box in the Postgres text format, i.e. `(x1,y1),(x2,y2)` or `x1,y1,x2,y2`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner: String = s.chars().filter(|c| !matches!(c, '(' | ')')).collect();
+
+        let [upper_right_x, upper_right_y, lower_left_x, lower_left_y] =
+            parse_floats(&inner, "BOX")?;
+
+        Ok(PgBox {
+            upper_right_x,
+            upper_right_y,
+            lower_left_x,
+            lower_left_y,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOX: PgBox = PgBox {
+        upper_right_x: 3.0,
+        upper_right_y: 4.5,
+        lower_left_x: -1.0,
+        lower_left_y: 2.0,
+    };
+
+    #[test]
+    fn it_round_trips_binary() {
+        let mut buf = PgArgumentBuffer::default();
+        let _ = BOX.encode_by_ref(&mut buf);
+
+        assert_eq!(buf.len(), 32);
+        assert_eq!(&buf[16..24], &(-1.0_f64).to_be_bytes());
+
+        let [upper_right_x, upper_right_y, lower_left_x, lower_left_y] =
+            read_floats(&buf, "BOX").unwrap();
+
+        assert_eq!(
+            PgBox {
+                upper_right_x,
+                upper_right_y,
+                lower_left_x,
+                lower_left_y
+            },
+            BOX
+        );
+    }
+
+    #[test]
+    fn it_parses_text() {
+        assert_eq!("(3,4.5),(-1,2)".parse::<PgBox>().unwrap(), BOX);
+        assert_eq!("3, 4.5, -1, 2".parse::<PgBox>().unwrap(), BOX);
+        assert!("(3,4.5),(-1)".parse::<PgBox>().is_err());
+    }
+
+    #[test]
+    fn it_decodes_text_arrays() {
+        // unlike all other types, the elements of a `box[]` are delimited by `;`
+        let boxes = Vec::<PgBox>::decode(PgValueRef {
+            value: Some(b"{(3,4.5),(-1,2);(1,1),(0,0)}"),
+            row: None,
+            type_info: PgTypeInfo::BOX_ARRAY,
+            format: PgValueFormat::Text,
+        })
+        .unwrap();
+
+        assert_eq!(
+            boxes,
+            [
+                BOX,
+                PgBox {
+                    upper_right_x: 1.0,
+                    upper_right_y: 1.0,
+                    lower_left_x: 0.0,
+                    lower_left_y: 0.0,
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn it_rejects_short_binary() {
+        assert!(read_floats::<4>(&[0; 24], "BOX").is_err());
+    }
+}
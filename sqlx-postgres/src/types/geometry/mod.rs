@@ -0,0 +1,48 @@
+mod r#box;
+mod point;
+
+pub use point::PgPoint;
+pub use r#box::PgBox;
+
+use crate::error::BoxDynError;
+
+/// Parse a comma-separated list of `N` floats, e.g. `1.5,-2` from the text format of a
+/// geometric type, after its delimiters have been stripped.
+fn parse_floats<const N: usize>(s: &str, type_name: &str) -> Result<[f64; N], BoxDynError> {
+    let mut floats = [0.0; N];
+    let mut parts = s.split(',');
+
+    for float in &mut floats {
+        let part = parts
+            .next()
+            .ok_or_else(|| format!("expected {N} numbers in {type_name} value {s:?}"))?;
+
+        *float = part.trim().parse()?;
+    }
+
+    if parts.next().is_some() {
+        return Err(format!("expected {N} numbers in {type_name} value {s:?}").into());
+    }
+
+    Ok(floats)
+}
+
+/// Read `N` big-endian float8 values from the binary format of a geometric type.
+fn read_floats<const N: usize>(bytes: &[u8], type_name: &str) -> Result<[f64; N], BoxDynError> {
+    if bytes.len() != N * 8 {
+        return Err(format!(
+            "expected {} bytes for {type_name}, got {}",
+            N * 8,
+            bytes.len()
+        )
+        .into());
+    }
+
+    let mut floats = [0.0; N];
+
+    for (float, chunk) in floats.iter_mut().zip(bytes.chunks_exact(8)) {
+        *float = f64::from_be_bytes(chunk.try_into()?);
+    }
+
+    Ok(floats)
+}
@@ -0,0 +1,109 @@
+use std::str::FromStr;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+
+use super::{parse_floats, read_floats};
+
+/// The PostgreSQL [`POINT`] type, a point on a plane.
+///
+/// Stored as two `float8` coordinates; the text format is `(x,y)`.
+///
+/// [`POINT`]: https://www.postgresql.org/docs/current/datatype-geometric.html#DATATYPE-GEOMETRIC-POINTS
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgPoint {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Type<Postgres> for PgPoint {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::POINT
+    }
+}
+
+impl PgHasArrayType for PgPoint {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::POINT_ARRAY
+    }
+}
+
+impl Encode<'_, Postgres> for PgPoint {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        // https://github.com/postgres/postgres/blob/REL_16_0/src/backend/utils/adt/geo_ops.c#L1854
+        buf.extend(&self.x.to_be_bytes());
+        buf.extend(&self.y.to_be_bytes());
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        16
+    }
+}
+
+impl Decode<'_, Postgres> for PgPoint {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => {
+                let [x, y] = read_floats(value.as_bytes()?, "POINT")?;
+
+                Ok(PgPoint { x, y })
+            }
+            PgValueFormat::Text => value.as_str()?.parse(),
+        }
+    }
+}
+
+impl FromStr for PgPoint {
+    type Err = BoxDynError;
+
+    /// Parse a point in the Postgres text format, i.e. `(x,y)` or `x,y`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner = s
+            .trim()
+            .strip_prefix('(')
+            .and_then(|s| s.strip_suffix(')'))
+            .unwrap_or(s);
+
+        let [x, y] = parse_floats(inner, "POINT")?;
+
+        Ok(PgPoint { x, y })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_round_trips_binary() {
+        let point = PgPoint { x: 1.5, y: -2.25 };
+
+        let mut buf = PgArgumentBuffer::default();
+        let _ = point.encode_by_ref(&mut buf);
+
+        assert_eq!(buf.len(), 16);
+        assert_eq!(&buf[..8], &1.5_f64.to_be_bytes());
+
+        let [x, y] = read_floats(&buf, "POINT").unwrap();
+        assert_eq!(PgPoint { x, y }, point);
+    }
+
+    #[test]
+    fn it_parses_text() {
+        assert_eq!(
+            "(1.5,-2)".parse::<PgPoint>().unwrap(),
+            PgPoint { x: 1.5, y: -2.0 }
+        );
+        assert_eq!(
+            "3, 4".parse::<PgPoint>().unwrap(),
+            PgPoint { x: 3.0, y: 4.0 }
+        );
+        assert!("(1,2,3)".parse::<PgPoint>().is_err());
+        assert!("(1)".parse::<PgPoint>().is_err());
+    }
+}
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+
+// `hstore` maps text keys to nullable text values.
+//
+// https://www.postgresql.org/docs/current/hstore.html
+// https://github.com/postgres/postgres/blob/master/contrib/hstore/hstore_io.c (hstore_send)
+
+impl<S> Type<Postgres> for HashMap<String, Option<String>, S> {
+    fn type_info() -> PgTypeInfo {
+        // Since `hstore` is enabled by an extension, it does not have a stable OID.
+        PgTypeInfo::with_name("hstore")
+    }
+}
+
+impl<S> PgHasArrayType for HashMap<String, Option<String>, S> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_hstore")
+    }
+}
+
+impl<S> Encode<'_, Postgres> for HashMap<String, Option<String>, S> {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.extend(&(self.len() as i32).to_be_bytes());
+
+        for (key, value) in self {
+            buf.extend(&(key.len() as i32).to_be_bytes());
+            buf.extend(key.as_bytes());
+
+            match value {
+                Some(value) => {
+                    buf.extend(&(value.len() as i32).to_be_bytes());
+                    buf.extend(value.as_bytes());
+                }
+
+                None => buf.extend(&(-1_i32).to_be_bytes()),
+            }
+        }
+
+        IsNull::No
+    }
+}
+
+impl<S> Decode<'_, Postgres> for HashMap<String, Option<String>, S>
+where
+    S: BuildHasher + Default,
+{
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => decode_binary(value.as_bytes()?),
+            PgValueFormat::Text => decode_text(value.as_str()?),
+        }
+    }
+}
+
+fn decode_binary<S>(mut buf: &[u8]) -> Result<HashMap<String, Option<String>, S>, BoxDynError>
+where
+    S: BuildHasher + Default,
+{
+    let count = read_len(&mut buf)?.ok_or("negative `hstore` pair count")?;
+
+    let mut map = HashMap::with_capacity_and_hasher(count, S::default());
+
+    for _ in 0..count {
+        let key = read_str(&mut buf)?.ok_or("`hstore` key cannot be NULL")?;
+        let value = read_str(&mut buf)?;
+
+        map.insert(key, value);
+    }
+
+    Ok(map)
+}
+
+/// Read a length prefix, returning `None` for `-1` (NULL).
+fn read_len(buf: &mut &[u8]) -> Result<Option<usize>, BoxDynError> {
+    if buf.len() < 4 {
+        return Err("unexpected end of `hstore` value".into());
+    }
+
+    let (len, rest) = buf.split_at(4);
+    *buf = rest;
+
+    Ok(usize::try_from(i32::from_be_bytes(len.try_into()?)).ok())
+}
+
+fn read_str(buf: &mut &[u8]) -> Result<Option<String>, BoxDynError> {
+    let Some(len) = read_len(buf)? else {
+        return Ok(None);
+    };
+
+    if buf.len() < len {
+        return Err("unexpected end of `hstore` value".into());
+    }
+
+    let (s, rest) = buf.split_at(len);
+    *buf = rest;
+
+    Ok(Some(std::str::from_utf8(s)?.to_owned()))
+}
+
+/// Parse the text format, e.g. `"a"=>"1", "b"=>NULL`.
+fn decode_text<S>(s: &str) -> Result<HashMap<String, Option<String>, S>, BoxDynError>
+where
+    S: BuildHasher + Default,
+{
+    let mut map = HashMap::with_hasher(S::default());
+    let mut chars = s.chars().peekable();
+
+    loop {
+        while chars.next_if(|c| c.is_whitespace() || *c == ',').is_some() {}
+
+        if chars.peek().is_none() {
+            return Ok(map);
+        }
+
+        let key = parse_quoted(&mut chars)?;
+
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+
+        if chars.next() != Some('=') || chars.next() != Some('>') {
+            return Err(format!("expected `=>` after `hstore` key {key:?}").into());
+        }
+
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+
+        let value = if chars.peek() == Some(&'"') {
+            Some(parse_quoted(&mut chars)?)
+        } else {
+            let word: String =
+                std::iter::from_fn(|| chars.next_if(|c| c.is_alphabetic())).collect();
+
+            if !word.eq_ignore_ascii_case("null") {
+                return Err(format!("unexpected `hstore` value {word:?}").into());
+            }
+
+            None
+        };
+
+        map.insert(key, value);
+    }
+}
+
+fn parse_quoted(chars: &mut impl Iterator<Item = char>) -> Result<String, BoxDynError> {
+    if chars.next() != Some('"') {
+        return Err("expected `\"` in `hstore` value".into());
+    }
+
+    let mut s = String::new();
+
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(s),
+            Some('\\') => s.push(chars.next().ok_or("unexpected end of `hstore` value")?),
+            Some(c) => s.push(c),
+            None => return Err("unexpected end of `hstore` value".into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::RandomState;
+
+    use super::*;
+
+    fn map(pairs: &[(&str, Option<&str>)]) -> HashMap<String, Option<String>> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.map(str::to_owned)))
+            .collect()
+    }
+
+    #[test]
+    fn it_round_trips_binary() {
+        let expected = map(&[("a", Some("1")), ("b", None), ("", Some(""))]);
+
+        let mut buf = PgArgumentBuffer::default();
+        let _ = expected.encode_by_ref(&mut buf);
+
+        assert_eq!(decode_binary(&buf).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_decodes_text() {
+        let decoded: HashMap<String, Option<String>> =
+            decode_text(r#""a"=>"1", "b"=>NULL, "c \"d\""=>"e\\f""#).unwrap();
+
+        assert_eq!(
+            decoded,
+            map(&[("a", Some("1")), ("b", None), ("c \"d\"", Some("e\\f"))])
+        );
+
+        assert!(decode_text::<RandomState>("").unwrap().is_empty());
+        assert!(decode_text::<RandomState>(r#""a"=>"#).is_err());
+    }
+
+    #[test]
+    fn it_rejects_truncated_binary() {
+        let mut buf = Vec::new();
+        buf.extend(&1_i32.to_be_bytes());
+        buf.extend(&3_i32.to_be_bytes());
+        buf.extend(b"ab");
+
+        assert!(decode_binary::<RandomState>(&buf).is_err());
+    }
+}
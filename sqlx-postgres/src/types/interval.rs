@@ -94,6 +94,37 @@ impl Encode<'_, Postgres> for std::time::Duration {
     }
 }
 
+impl<'de> Decode<'de, Postgres> for std::time::Duration {
+    fn decode(value: PgValueRef<'de>) -> Result<Self, BoxDynError> {
+        std::time::Duration::try_from(PgInterval::decode(value)?)
+    }
+}
+
+impl TryFrom<PgInterval> for std::time::Duration {
+    type Error = BoxDynError;
+
+    /// Convert a `PgInterval` to a `std::time::Duration`, taking a day to be 24 hours.
+    ///
+    /// This returns an error if the interval has a month component, which has no fixed length,
+    /// or if the interval is negative.
+    fn try_from(value: PgInterval) -> Result<Self, BoxDynError> {
+        if value.months != 0 {
+            return Err(
+                "PostgreSQL `INTERVAL` with months or years cannot be converted to `std::time::Duration`"
+                    .into(),
+            );
+        }
+
+        let microseconds = i128::from(value.days) * 86_400_000_000 + i128::from(value.microseconds);
+
+        let microseconds = u64::try_from(microseconds).map_err(|_| {
+            "negative PostgreSQL `INTERVAL` cannot be converted to `std::time::Duration`"
+        })?;
+
+        Ok(std::time::Duration::from_micros(microseconds))
+    }
+}
+
 impl TryFrom<std::time::Duration> for PgInterval {
     type Error = BoxDynError;
 
@@ -332,6 +363,35 @@ fn test_pginterval_std() {
     assert!(PgInterval::try_from(std::time::Duration::from_secs(20_000_000_000_000)).is_err());
 }
 
+#[test]
+fn test_std_from_pginterval() {
+    let interval = PgInterval {
+        months: 0,
+        days: 2,
+        microseconds: 27_000,
+    };
+    assert_eq!(
+        std::time::Duration::try_from(interval).unwrap(),
+        std::time::Duration::from_secs(2 * 86_400) + std::time::Duration::from_micros(27_000)
+    );
+
+    // Case when the interval has months
+    let interval = PgInterval {
+        months: 1,
+        days: 0,
+        microseconds: 0,
+    };
+    assert!(std::time::Duration::try_from(interval).is_err());
+
+    // Case when the interval is negative
+    let interval = PgInterval {
+        months: 0,
+        days: 1,
+        microseconds: -86_400_000_001,
+    };
+    assert!(std::time::Duration::try_from(interval).is_err());
+}
+
 #[test]
 #[cfg(feature = "chrono")]
 fn test_pginterval_chrono() {
@@ -1,62 +1,151 @@
-use std::net::IpAddr;
-
-use ipnetwork::IpNetwork;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
 use crate::types::Type;
-use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueRef, Postgres};
+use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+
+// https://github.com/postgres/postgres/blob/574925bfd0a8175f6e161936ea11d9695677ba09/src/include/utils/inet.h#L39
 
-impl Type<Postgres> for IpAddr
-where
-    IpNetwork: Type<Postgres>,
-{
+// Technically this is a magic number here but it doesn't make sense to drag in the whole of `libc`
+// just for one constant.
+const PGSQL_AF_INET: u8 = 2; // AF_INET
+const PGSQL_AF_INET6: u8 = PGSQL_AF_INET + 1;
+
+impl Type<Postgres> for IpAddr {
     fn type_info() -> PgTypeInfo {
-        IpNetwork::type_info()
+        PgTypeInfo::INET
     }
 
     fn compatible(ty: &PgTypeInfo) -> bool {
-        IpNetwork::compatible(ty)
+        *ty == PgTypeInfo::CIDR || *ty == PgTypeInfo::INET
     }
 }
 
 impl PgHasArrayType for IpAddr {
     fn array_type_info() -> PgTypeInfo {
-        <IpNetwork as PgHasArrayType>::array_type_info()
+        PgTypeInfo::INET_ARRAY
     }
 
     fn array_compatible(ty: &PgTypeInfo) -> bool {
-        <IpNetwork as PgHasArrayType>::array_compatible(ty)
+        *ty == PgTypeInfo::CIDR_ARRAY || *ty == PgTypeInfo::INET_ARRAY
     }
 }
 
-impl<'db> Encode<'db, Postgres> for IpAddr
-where
-    IpNetwork: Encode<'db, Postgres>,
-{
+impl Encode<'_, Postgres> for IpAddr {
     fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
-        IpNetwork::from(*self).encode_by_ref(buf)
+        // https://github.com/postgres/postgres/blob/574925bfd0a8175f6e161936ea11d9695677ba09/src/backend/utils/adt/network.c#L293
+
+        // encoded as a host address, i.e. with a full-width prefix
+        match self {
+            IpAddr::V4(ip) => {
+                buf.push(PGSQL_AF_INET); // ip_family
+                buf.push(32); // ip_bits
+                buf.push(0); // is_cidr
+                buf.push(4); // nb (number of bytes)
+                buf.extend_from_slice(&ip.octets()) // address
+            }
+
+            IpAddr::V6(ip) => {
+                buf.push(PGSQL_AF_INET6); // ip_family
+                buf.push(128); // ip_bits
+                buf.push(0); // is_cidr
+                buf.push(16); // nb (number of bytes)
+                buf.extend_from_slice(&ip.octets()); // address
+            }
+        }
+
+        IsNull::No
     }
 
     fn size_hint(&self) -> usize {
-        IpNetwork::from(*self).size_hint()
+        match self {
+            IpAddr::V4(_) => 8,
+            IpAddr::V6(_) => 20,
+        }
     }
 }
 
-impl<'db> Decode<'db, Postgres> for IpAddr
-where
-    IpNetwork: Decode<'db, Postgres>,
-{
-    fn decode(value: PgValueRef<'db>) -> Result<Self, BoxDynError> {
-        let ipnetwork = IpNetwork::decode(value)?;
-
-        if ipnetwork.is_ipv4() && ipnetwork.prefix() != 32
-            || ipnetwork.is_ipv6() && ipnetwork.prefix() != 128
-        {
-            Err("lossy decode from inet/cidr")?
+impl Decode<'_, Postgres> for IpAddr {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let (ip, prefix) = match value.format() {
+            PgValueFormat::Binary => decode_binary(value.as_bytes()?)?,
+            PgValueFormat::Text => decode_text(value.as_str()?)?,
+        };
+
+        let width = if ip.is_ipv4() { 32 } else { 128 };
+
+        if prefix != width {
+            return Err(format!(
+                "lossy decode from inet/cidr: `{ip}/{prefix}` is not a host address, \
+                 decode as `ipnetwork::IpNetwork` instead"
+            )
+            .into());
         }
 
-        Ok(ipnetwork.ip())
+        Ok(ip)
+    }
+}
+
+fn decode_binary(bytes: &[u8]) -> Result<(IpAddr, u8), BoxDynError> {
+    // https://github.com/postgres/postgres/blob/574925bfd0a8175f6e161936ea11d9695677ba09/src/backend/utils/adt/network.c#L271
+
+    let [family, prefix, _is_cidr, len, address @ ..] = bytes else {
+        return Err("invalid data received when expecting an INET".into());
+    };
+
+    let ip = match (*family, *len, address.len()) {
+        (PGSQL_AF_INET, 4, 4) => IpAddr::V4(Ipv4Addr::from(<[u8; 4]>::try_from(address)?)),
+        (PGSQL_AF_INET6, 16, 16) => IpAddr::V6(Ipv6Addr::from(<[u8; 16]>::try_from(address)?)),
+        (PGSQL_AF_INET | PGSQL_AF_INET6, _, _) => {
+            return Err("invalid data received when expecting an INET".into());
+        }
+        (family, _, _) => return Err(format!("unknown ip family {family}").into()),
+    };
+
+    Ok((ip, *prefix))
+}
+
+fn decode_text(s: &str) -> Result<(IpAddr, u8), BoxDynError> {
+    let (ip, prefix) = match s.split_once('/') {
+        Some((ip, prefix)) => (ip.parse::<IpAddr>()?, Some(prefix.parse::<u8>()?)),
+        None => (s.parse::<IpAddr>()?, None),
+    };
+
+    let width = if ip.is_ipv4() { 32 } else { 128 };
+
+    Ok((ip, prefix.unwrap_or(width)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_round_trips_host_addresses() {
+        for ip in [
+            IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)),
+            IpAddr::V6(Ipv6Addr::LOCALHOST),
+        ] {
+            let mut buf = PgArgumentBuffer::default();
+            let _ = ip.encode_by_ref(&mut buf);
+
+            let width = if ip.is_ipv4() { 32 } else { 128 };
+            assert_eq!(decode_binary(&buf).unwrap(), (ip, width));
+        }
+    }
+
+    #[test]
+    fn it_decodes_text() {
+        let localhost = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+        assert_eq!(decode_text("127.0.0.1").unwrap(), (localhost, 32));
+        assert_eq!(decode_text("127.0.0.1/8").unwrap(), (localhost, 8));
+        assert_eq!(
+            decode_text("::1/128").unwrap(),
+            (IpAddr::V6(Ipv6Addr::LOCALHOST), 128)
+        );
+        assert!(decode_text("127.0.0.1/").is_err());
     }
 }
@@ -15,22 +15,36 @@
 //! | `&[u8]`, `Vec<u8>`                    | BYTEA                                                |
 //! | `()`                                  | VOID                                                 |
 //! | [`PgInterval`]                        | INTERVAL                                             |
+//! | `std::time::Duration`                 | INTERVAL<sup>2</sup>                                 |
 //! | [`PgRange<T>`](PgRange)               | INT8RANGE, INT4RANGE, TSRANGE, TSTZRANGE, DATERANGE, NUMRANGE |
+//! | [`PgRangeValue<T>`](PgRangeValue)     | INT8RANGE, INT4RANGE, TSRANGE, TSTZRANGE, DATERANGE, NUMRANGE |
 //! | [`PgMoney`]                           | MONEY                                                |
 //! | [`PgLTree`]                           | LTREE                                                |
 //! | [`PgLQuery`]                          | LQUERY                                               |
 //! | [`PgCiText`]                          | CITEXT<sup>1</sup>                                   |
+//! | `HashMap<String, Option<String>>`     | HSTORE                                               |
+//! | `std::net::IpAddr`                    | INET, CIDR<sup>3</sup>                               |
+//! | [`PgPoint`]                           | POINT                                                |
+//! | [`PgBox`]                             | BOX                                                  |
 //!
 //! <sup>1</sup> SQLx generally considers `CITEXT` to be compatible with `String`, `&str`, etc.,
 //! but this wrapper type is available for edge cases, such as `CITEXT[]` which Postgres
 //! does not consider to be compatible with `TEXT[]`.
 //!
+//! <sup>2</sup> Only intervals without a month or year component can be decoded, taking a day
+//! to be 24 hours; negative intervals are also an error. Encoding requires microsecond precision.
+//!
+//! <sup>3</sup> `IpAddr` is encoded as a host address (`/32` or `/128`). Because it does not
+//! support network prefixes, it is an error to decode an `IpAddr` from a value with a shorter
+//! prefix; use `ipnetwork::IpNetwork` for those.
+//!
 //! ### [`bigdecimal`](https://crates.io/crates/bigdecimal)
 //! Requires the `bigdecimal` Cargo feature flag.
 //!
 //! | Rust type                             | Postgres type(s)                                        |
 //! |---------------------------------------|------------------------------------------------------|
 //! | `bigdecimal::BigDecimal`              | NUMERIC                                              |
+//! | `PgNumericValue<BigDecimal>`          | NUMERIC, including `'NaN'`                           |
 //!
 #![doc=include_str!("bigdecimal-range.md")]
 //!
@@ -40,9 +54,13 @@
 //! | Rust type                             | Postgres type(s)                                        |
 //! |---------------------------------------|------------------------------------------------------|
 //! | `rust_decimal::Decimal`               | NUMERIC                                              |
+//! | `PgNumericValue<Decimal>`             | NUMERIC, including `'NaN'`                           |
 //!
 #![doc=include_str!("rust_decimal-range.md")]
 //!
+//! Neither type can represent `'NaN'`, so decoding it is an error unless wrapped in
+//! `PgNumericValue`.
+//!
 //! ### [`chrono`](https://crates.io/crates/chrono)
 //!
 //! Requires the `chrono` Cargo feature flag.
@@ -83,13 +101,8 @@
 //! | Rust type                             | Postgres type(s)                                     |
 //! |---------------------------------------|------------------------------------------------------|
 //! | `ipnetwork::IpNetwork`                | INET, CIDR                                           |
-//! | `std::net::IpAddr`                    | INET, CIDR                                           |
 //!
-//! Note that because `IpAddr` does not support network prefixes, it is an error to attempt to decode
-//! an `IpAddr` from a `INET` or `CIDR` value with a network prefix smaller than the address' full width:
-//! `/32` for IPv4 addresses and `/128` for IPv6 addresses.
-//!
-//! `IpNetwork` does not have this limitation.
+//! `IpNetwork` supports network prefixes, unlike `std::net::IpAddr` (see above).
 //!
 //! ### [`mac_address`](https://crates.io/crates/mac_address)
 //!
@@ -144,6 +157,14 @@
 //! }
 //! ```
 //!
+//! Fields are decoded in order and may be `NULL` if declared as `Option<T>`. Decoding fails with
+//! an error naming the field if its type differs from the one declared in Postgres, or if the
+//! composite type has a different number of fields than the struct.
+//!
+//! With `#[sqlx(pg_array)]`, the derive also implements [`PgHasArrayType`] using the array type
+//! Postgres creates for the composite type (`_inventory_item` here), so `Vec<InventoryItem>` maps
+//! to `inventory_item[]`.
+//!
 //! Anonymous composite types are represented as tuples. Note that anonymous composites may only
 //! be returned and not sent to Postgres (this is a limitation of postgres).
 //!
@@ -151,6 +172,22 @@
 //!
 //! One-dimensional arrays are supported as `Vec<T>` or `&[T]` where `T` implements `Type`.
 //!
+//! Postgres has no `IN` list parameter, so the idiomatic way to match against a list of values
+//! is to bind an array and compare with `= ANY($1)`:
+//!
+//! ```rust,ignore
+//! let user_ids: Vec<i64> = vec![1, 2, 3];
+//!
+//! let users = sqlx::query("SELECT * FROM users WHERE id = ANY($1)")
+//!     .bind(&user_ids[..])
+//!     .fetch_all(&mut conn)
+//!     .await?;
+//! ```
+//!
+//! Any iterator can be bound this way without collecting it first, using
+//! [`PgBindIterExt::bind_iter()`][crate::PgBindIterExt::bind_iter].
+//! The element type must implement [`PgHasArrayType`].
+//!
 //! # [Enumerations](https://www.postgresql.org/docs/current/datatype-enum.html)
 //!
 //! User-defined enumerations are supported through a derive for `Type`.
@@ -181,13 +218,16 @@ use crate::{PgTypeInfo, Postgres};
 
 pub(crate) use sqlx_core::types::{Json, Type};
 
-mod array;
+pub(crate) mod array;
 mod bool;
 mod bytes;
 mod citext;
 mod float;
+mod geometry;
+mod hstore;
 mod int;
 mod interval;
+mod ipaddr;
 mod lquery;
 mod ltree;
 // Not behind a Cargo feature because we require JSON in the driver implementation.
@@ -225,9 +265,6 @@ mod uuid;
 #[cfg(feature = "ipnetwork")]
 mod ipnetwork;
 
-#[cfg(feature = "ipnetwork")]
-mod ipaddr;
-
 #[cfg(feature = "mac_address")]
 mod mac_address;
 
@@ -236,6 +273,7 @@ mod bit_vec;
 
 pub use array::PgHasArrayType;
 pub use citext::PgCiText;
+pub use geometry::{PgBox, PgPoint};
 pub use interval::PgInterval;
 pub use lquery::PgLQuery;
 pub use lquery::PgLQueryLevel;
@@ -246,11 +284,14 @@ pub use ltree::PgLTreeLabel;
 pub use ltree::PgLTreeParseError;
 pub use money::PgMoney;
 pub use oid::Oid;
-pub use range::PgRange;
+pub use range::{PgRange, PgRangeValue};
 
 #[cfg(any(feature = "chrono", feature = "time"))]
 pub use time_tz::PgTimeTz;
 
+#[cfg(any(feature = "bigdecimal", feature = "rust_decimal"))]
+pub use numeric::PgNumericValue;
+
 // used in derive(Type) for `struct`
 // but the interface is not considered part of the public API
 #[doc(hidden)]
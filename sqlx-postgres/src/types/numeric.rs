@@ -1,7 +1,111 @@
 use sqlx_core::bytes::Buf;
 
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
-use crate::PgArgumentBuffer;
+use crate::types::Type;
+use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+
+/// A `NUMERIC` value which may be `'NaN'`.
+///
+/// Neither `rust_decimal::Decimal` nor `bigdecimal::BigDecimal` can represent `'NaN'`, so
+/// decoding it into either of them is an error. Decode into `PgNumericValue<T>` instead to handle
+/// `'NaN'` as a value:
+///
+/// ```rust,ignore
+/// let value: PgNumericValue<Decimal> = sqlx::query_scalar("SELECT 'NaN'::numeric")
+///     .fetch_one(&mut conn)
+///     .await?;
+///
+/// assert_eq!(value, PgNumericValue::NotANumber);
+/// ```
+///
+/// This also allows encoding `'NaN'`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PgNumericValue<T> {
+    /// A value representable by `T`.
+    Number(T),
+
+    /// The `'NaN'` value in Postgres.
+    NotANumber,
+}
+
+impl<T> PgNumericValue<T> {
+    /// Returns the number, or `None` if the value is `'NaN'`.
+    pub fn number(self) -> Option<T> {
+        match self {
+            PgNumericValue::Number(number) => Some(number),
+            PgNumericValue::NotANumber => None,
+        }
+    }
+}
+
+impl<T> From<T> for PgNumericValue<T> {
+    fn from(number: T) -> Self {
+        PgNumericValue::Number(number)
+    }
+}
+
+/// The error returned when decoding `'NaN'` into a type which cannot represent it.
+pub(crate) fn nan_error(type_name: &str) -> BoxDynError {
+    format!(
+        "numeric value is NaN, which `{type_name}` cannot represent; \
+         decode as `PgNumericValue<{type_name}>` instead"
+    )
+    .into()
+}
+
+macro_rules! impl_numeric_value {
+    ($ty:ty) => {
+        impl Type<Postgres> for PgNumericValue<$ty> {
+            fn type_info() -> PgTypeInfo {
+                PgTypeInfo::NUMERIC
+            }
+        }
+
+        impl PgHasArrayType for PgNumericValue<$ty> {
+            fn array_type_info() -> PgTypeInfo {
+                PgTypeInfo::NUMERIC_ARRAY
+            }
+        }
+
+        impl Encode<'_, Postgres> for PgNumericValue<$ty> {
+            fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+                match self {
+                    PgNumericValue::Number(number) => number.encode_by_ref(buf),
+                    PgNumericValue::NotANumber => {
+                        PgNumeric::NotANumber.encode(buf);
+
+                        IsNull::No
+                    }
+                }
+            }
+        }
+
+        impl Decode<'_, Postgres> for PgNumericValue<$ty> {
+            fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+                let is_nan = match value.format() {
+                    PgValueFormat::Binary => {
+                        PgNumeric::decode(value.as_bytes()?)? == PgNumeric::NotANumber
+                    }
+                    PgValueFormat::Text => value.as_str()? == "NaN",
+                };
+
+                if is_nan {
+                    return Ok(PgNumericValue::NotANumber);
+                }
+
+                <$ty as Decode<'_, Postgres>>::decode(value).map(PgNumericValue::Number)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "bigdecimal")]
+impl_numeric_value!(bigdecimal::BigDecimal);
+
+#[cfg(feature = "rust_decimal")]
+impl_numeric_value!(rust_decimal::Decimal);
 
 /// Represents a `NUMERIC` value in the **Postgres** wire protocol.
 #[derive(Debug, PartialEq, Eq)]
@@ -134,3 +238,25 @@ impl PgNumeric {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_round_trips_nan() {
+        let mut buf = PgArgumentBuffer::default();
+        PgNumeric::NotANumber.encode(&mut buf);
+
+        assert_eq!(PgNumeric::decode(&buf).unwrap(), PgNumeric::NotANumber);
+    }
+
+    #[test]
+    fn nan_error_names_the_type() {
+        assert_eq!(
+            nan_error("Decimal").to_string(),
+            "numeric value is NaN, which `Decimal` cannot represent; \
+             decode as `PgNumericValue<Decimal>` instead"
+        );
+    }
+}
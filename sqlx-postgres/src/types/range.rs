@@ -94,6 +94,38 @@ impl<T> From<RangeToInclusive<T>> for PgRange<T> {
     }
 }
 
+/// A Postgres range which may be empty.
+///
+/// The empty range contains no values and is distinct from the unbounded range `(,)`, but it
+/// cannot be represented by [`PgRange`], which fails to decode it. Use this type instead where
+/// a range may be `'empty'`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum PgRangeValue<T> {
+    Empty,
+    Range(PgRange<T>),
+}
+
+impl<T> PgRangeValue<T> {
+    /// Returns `true` if this is the empty range.
+    pub fn is_empty(&self) -> bool {
+        matches!(self, PgRangeValue::Empty)
+    }
+
+    /// Returns the bounds of the range, or `None` if it is empty.
+    pub fn as_range(&self) -> Option<&PgRange<T>> {
+        match self {
+            PgRangeValue::Empty => None,
+            PgRangeValue::Range(range) => Some(range),
+        }
+    }
+}
+
+impl<T> From<PgRange<T>> for PgRangeValue<T> {
+    fn from(range: PgRange<T>) -> Self {
+        PgRangeValue::Range(range)
+    }
+}
+
 impl<T> RangeBounds<T> for PgRange<T> {
     fn start_bound(&self) -> Bound<&T> {
         match self.start {
@@ -324,7 +356,60 @@ where
     }
 }
 
+impl<T> Type<Postgres> for PgRangeValue<T>
+where
+    PgRange<T>: Type<Postgres>,
+{
+    fn type_info() -> PgTypeInfo {
+        PgRange::<T>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        PgRange::<T>::compatible(ty)
+    }
+}
+
+impl<T> PgHasArrayType for PgRangeValue<T>
+where
+    PgRange<T>: PgHasArrayType,
+{
+    fn array_type_info() -> PgTypeInfo {
+        PgRange::<T>::array_type_info()
+    }
+}
+
+impl<'q, T> Encode<'q, Postgres> for PgRangeValue<T>
+where
+    T: Encode<'q, Postgres>,
+{
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        match self {
+            PgRangeValue::Empty => {
+                buf.push(RangeFlags::EMPTY.bits());
+
+                IsNull::No
+            }
+
+            PgRangeValue::Range(range) => range.encode_by_ref(buf),
+        }
+    }
+}
+
 impl<'r, T> Decode<'r, Postgres> for PgRange<T>
+where
+    T: Type<Postgres> + for<'a> Decode<'a, Postgres>,
+{
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        match PgRangeValue::decode(value)? {
+            PgRangeValue::Range(range) => Ok(range),
+            PgRangeValue::Empty => {
+                Err("cannot decode an empty range as `PgRange`, use `PgRangeValue` instead".into())
+            }
+        }
+    }
+}
+
+impl<'r, T> Decode<'r, Postgres> for PgRangeValue<T>
 where
     T: Type<Postgres> + for<'a> Decode<'a, Postgres>,
 {
@@ -345,7 +430,7 @@ where
                 let flags = RangeFlags::from_bits_truncate(buf.get_u8());
 
                 if flags.contains(RangeFlags::EMPTY) {
-                    return Ok(PgRange { start, end });
+                    return Ok(PgRangeValue::Empty);
                 }
 
                 if !flags.contains(RangeFlags::LB_INF) {
@@ -370,7 +455,7 @@ where
                     };
                 }
 
-                Ok(PgRange { start, end })
+                Ok(PgRangeValue::Range(PgRange { start, end }))
             }
 
             PgValueFormat::Text => {
@@ -381,6 +466,10 @@ where
 
                 let s = value.as_str()?;
 
+                if s == "empty" {
+                    return Ok(PgRangeValue::Empty);
+                }
+
                 // remember the bounds
                 let sb = s.as_bytes();
                 let lower = sb[0] as char;
@@ -466,7 +555,7 @@ where
                 let start = parse_bound(lower, start)?;
                 let end = parse_bound(upper, end)?;
 
-                Ok(PgRange { start, end })
+                Ok(PgRangeValue::Range(PgRange { start, end }))
             }
         }
     }
@@ -511,6 +600,18 @@ where
     }
 }
 
+impl<T> Display for PgRangeValue<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PgRangeValue::Empty => f.write_str("empty"),
+            PgRangeValue::Range(range) => Display::fmt(range, f),
+        }
+    }
+}
+
 fn range_compatible<E: Type<Postgres>>(ty: &PgTypeInfo) -> bool {
     // we require the declared type to be a _range_ with an
     // element type that is acceptable
@@ -520,3 +621,30 @@ fn range_compatible<E: Type<Postgres>>(ty: &PgTypeInfo) -> bool {
 
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_keeps_empty_distinct_from_unbounded() {
+        let unbounded =
+            PgRangeValue::from(PgRange::<i32>::from((Bound::Unbounded, Bound::Unbounded)));
+
+        assert!(PgRangeValue::<i32>::Empty.is_empty());
+        assert!(!unbounded.is_empty());
+        assert_ne!(PgRangeValue::Empty, unbounded);
+        assert_eq!(PgRangeValue::<i32>::Empty.as_range(), None);
+
+        assert_eq!(PgRangeValue::<i32>::Empty.to_string(), "empty");
+        assert_eq!(unbounded.to_string(), "(,)");
+    }
+
+    #[test]
+    fn it_encodes_empty_as_flags_only() {
+        let mut buf = PgArgumentBuffer::default();
+        let _ = PgRangeValue::<i32>::Empty.encode_by_ref(&mut buf);
+
+        assert_eq!(&**buf, [RangeFlags::EMPTY.bits()]);
+    }
+}
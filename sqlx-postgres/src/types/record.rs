@@ -97,7 +97,7 @@ impl<'r> PgRecordDecoder<'r> {
         T: for<'a> Decode<'a, Postgres> + Type<Postgres>,
     {
         if self.buf.is_empty() {
-            return Err(format!("no field `{0}` found on record", self.ind).into());
+            return Err(self.too_few_fields());
         }
 
         match self.fmt {
@@ -109,12 +109,22 @@ impl<'r> PgRecordDecoder<'r> {
                     }
 
                     PgTypeKind::Composite(fields) => {
-                        let ty = fields[self.ind].1.clone();
+                        let (name, ty) =
+                            fields.get(self.ind).ok_or_else(|| self.too_few_fields())?;
+
                         if ty.0.oid() != element_type_oid {
-                            return Err("unexpected mismatch of composite type information".into());
+                            return Err(format!(
+                                "field `{name}` of composite type `{}` was expected to have type `{}` (oid {}), \
+                                 but the server sent a value with type oid {}",
+                                self.typ.name(),
+                                ty.name(),
+                                ty.0.oid().0,
+                                element_type_oid.0,
+                            )
+                            .into());
                         }
 
-                        Some(ty)
+                        Some(ty.clone())
                     }
 
                     _ => {
@@ -188,6 +198,8 @@ impl<'r> PgRecordDecoder<'r> {
                     Some(element.as_bytes())
                 };
 
+                self.ind += 1;
+
                 // NOTE: we do not call [`accepts`] or give a chance to from a user as
                 //       TEXT sequences are not strongly typed
 
@@ -202,4 +214,109 @@ impl<'r> PgRecordDecoder<'r> {
             }
         }
     }
+
+    /// Check that every field of the record was decoded.
+    #[doc(hidden)]
+    pub fn finish(&self) -> Result<(), BoxDynError> {
+        if !self.buf.is_empty() {
+            return Err(format!(
+                "record type `{}` has more fields than the {} that were decoded",
+                self.typ.name(),
+                self.ind
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    fn too_few_fields(&self) -> BoxDynError {
+        format!(
+            "record type `{}` has {} fields, but field #{} was requested",
+            self.typ.name(),
+            self.ind,
+            self.ind + 1
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::type_info::PgCustomType;
+
+    fn point(x: f64, y_oid: u32) -> (Vec<u8>, PgTypeInfo) {
+        let mut buf = Vec::new();
+        buf.extend(&2_u32.to_be_bytes());
+        buf.extend(&701_u32.to_be_bytes());
+        buf.extend(&8_i32.to_be_bytes());
+        buf.extend(&x.to_be_bytes());
+        buf.extend(&y_oid.to_be_bytes());
+        buf.extend(&(-1_i32).to_be_bytes());
+
+        let typ = PgTypeInfo(PgType::Custom(Arc::new(PgCustomType {
+            oid: Oid(100_000),
+            name: "point2d".into(),
+            kind: PgTypeKind::Composite(Arc::from(vec![
+                ("x".to_owned(), PgTypeInfo::FLOAT8),
+                ("y".to_owned(), PgTypeInfo::FLOAT8),
+            ])),
+        })));
+
+        (buf, typ)
+    }
+
+    fn value(buf: &[u8], typ: PgTypeInfo) -> PgValueRef<'_> {
+        PgValueRef {
+            value: Some(buf),
+            row: None,
+            type_info: typ,
+            format: PgValueFormat::Binary,
+        }
+    }
+
+    #[test]
+    fn it_decodes_composite_with_null_field() -> Result<(), BoxDynError> {
+        let (buf, typ) = point(1.5, 701);
+        let mut decoder = PgRecordDecoder::new(value(&buf, typ))?;
+
+        assert_eq!(decoder.try_decode::<f64>()?, 1.5);
+        assert_eq!(decoder.try_decode::<Option<f64>>()?, None);
+        decoder.finish()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_rejects_mismatched_field_count() -> Result<(), BoxDynError> {
+        let (buf, typ) = point(1.5, 701);
+
+        let mut decoder = PgRecordDecoder::new(value(&buf, typ.clone()))?;
+        decoder.try_decode::<f64>()?;
+        assert!(decoder.finish().is_err());
+
+        let mut decoder = PgRecordDecoder::new(value(&buf, typ))?;
+        decoder.try_decode::<f64>()?;
+        decoder.try_decode::<Option<f64>>()?;
+        assert!(decoder.try_decode::<Option<f64>>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_rejects_mismatched_field_type() -> Result<(), BoxDynError> {
+        let (buf, typ) = point(1.5, 23);
+        let mut decoder = PgRecordDecoder::new(value(&buf, typ))?;
+
+        decoder.try_decode::<f64>()?;
+        let err = decoder.try_decode::<Option<f64>>().unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("field `y` of composite type `point2d`"));
+
+        Ok(())
+    }
 }
@@ -3,7 +3,7 @@ use rust_decimal::{prelude::Zero, Decimal};
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
-use crate::types::numeric::{PgNumeric, PgNumericSign};
+use crate::types::numeric::{nan_error, PgNumeric, PgNumericSign};
 use crate::types::Type;
 use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
 
@@ -34,7 +34,7 @@ impl TryFrom<PgNumeric> for Decimal {
             } => (digits, sign, weight, scale),
 
             PgNumeric::NotANumber => {
-                return Err("Decimal does not support NaN values".into());
+                return Err(nan_error("Decimal"));
             }
         };
 
@@ -158,7 +158,10 @@ impl Decode<'_, Postgres> for Decimal {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
         match value.format() {
             PgValueFormat::Binary => PgNumeric::decode(value.as_bytes()?)?.try_into(),
-            PgValueFormat::Text => Ok(value.as_str()?.parse::<Decimal>()?),
+            PgValueFormat::Text => match value.as_str()? {
+                "NaN" => Err(nan_error("Decimal")),
+                s => Ok(s.parse::<Decimal>()?),
+            },
         }
     }
 }
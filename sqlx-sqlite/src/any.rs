@@ -16,7 +16,8 @@ use sqlx_core::connection::{ConnectOptions, Connection};
 use sqlx_core::database::Database;
 use sqlx_core::describe::Describe;
 use sqlx_core::executor::Executor;
-use sqlx_core::transaction::TransactionManager;
+use sqlx_core::transaction::{TransactionManager, TransactionOptions, TransactionStatus};
+use sqlx_core::type_info::TypeInfo;
 
 sqlx_core::declare_driver_with_optional_migrate!(DRIVER = Sqlite);
 
@@ -41,6 +42,10 @@ impl AnyConnectionBackend for SqliteConnection {
         SqliteTransactionManager::begin(self)
     }
 
+    fn begin_with(&mut self, options: TransactionOptions) -> BoxFuture<'_, sqlx_core::Result<()>> {
+        SqliteTransactionManager::begin_with(self, options)
+    }
+
     fn commit(&mut self) -> BoxFuture<'_, sqlx_core::Result<()>> {
         SqliteTransactionManager::commit(self)
     }
@@ -53,6 +58,14 @@ impl AnyConnectionBackend for SqliteConnection {
         SqliteTransactionManager::start_rollback(self)
     }
 
+    fn transaction_status(&self) -> TransactionStatus {
+        SqliteTransactionManager::transaction_status(self)
+    }
+
+    fn write_count(&self) -> Option<u64> {
+        SqliteTransactionManager::write_count(self)
+    }
+
     fn shrink_buffers(&mut self) {
         // NO-OP.
     }
@@ -82,7 +95,7 @@ impl AnyConnectionBackend for SqliteConnection {
 
         Box::pin(
             self.worker
-                .execute(query, args, self.row_channel_size, persistent)
+                .execute(query, args, self.row_channel_size, persistent, None)
                 .map_ok(flume::Receiver::into_stream)
                 .try_flatten_stream()
                 .map(
@@ -105,7 +118,7 @@ impl AnyConnectionBackend for SqliteConnection {
         Box::pin(async move {
             let stream = self
                 .worker
-                .execute(query, args, self.row_channel_size, persistent)
+                .execute(query, args, self.row_channel_size, persistent, None)
                 .map_ok(flume::Receiver::into_stream)
                 .await?;
             futures_util::pin_mut!(stream);
@@ -141,17 +154,13 @@ impl<'a> TryFrom<&'a SqliteTypeInfo> for AnyTypeInfo {
         Ok(AnyTypeInfo {
             kind: match &sqlite_type.0 {
                 DataType::Null => AnyTypeInfoKind::Null,
+                DataType::Bool => AnyTypeInfoKind::Bool,
                 DataType::Int => AnyTypeInfoKind::Integer,
                 DataType::Int64 => AnyTypeInfoKind::BigInt,
                 DataType::Float => AnyTypeInfoKind::Double,
                 DataType::Blob => AnyTypeInfoKind::Blob,
                 DataType::Text => AnyTypeInfoKind::Text,
-                _ => {
-                    return Err(sqlx_core::Error::AnyDriverError(
-                        format!("Any driver does not support the SQLite type {sqlite_type:?}")
-                            .into(),
-                    ))
-                }
+                _ => AnyTypeInfoKind::Other(sqlite_type.name().to_owned()),
             },
         })
     }
@@ -183,12 +192,27 @@ impl<'a> TryFrom<&'a SqliteRow> for AnyRow {
     }
 }
 
+impl From<SqliteConnectOptions> for AnyConnectOptions {
+    fn from(options: SqliteConnectOptions) -> Self {
+        let mut any = AnyConnectOptions::from_driver_options(options.clone());
+        any.log_settings = options.log_settings;
+        any.after_connect_sql = options.after_connect_sql;
+        any.connect_timeout = options.connect_timeout;
+        any
+    }
+}
+
 impl<'a> TryFrom<&'a AnyConnectOptions> for SqliteConnectOptions {
     type Error = sqlx_core::Error;
 
     fn try_from(opts: &'a AnyConnectOptions) -> Result<Self, Self::Error> {
-        let mut opts_out = SqliteConnectOptions::from_url(&opts.database_url)?;
+        let mut opts_out = match opts.downcast_ref::<SqliteConnectOptions>() {
+            Some(opts_out) => opts_out.clone(),
+            None => SqliteConnectOptions::from_url(&opts.database_url)?,
+        };
         opts_out.log_settings = opts.log_settings.clone();
+        opts_out.after_connect_sql = opts.after_connect_sql.clone();
+        opts_out.connect_timeout = opts.connect_timeout;
         Ok(opts_out)
     }
 }
@@ -201,7 +225,7 @@ fn map_arguments(args: AnyArguments<'_>) -> SqliteArguments<'_> {
             .0
             .into_iter()
             .map(|val| match val {
-                AnyValueKind::Null => SqliteArgumentValue::Null,
+                AnyValueKind::Null(_) => SqliteArgumentValue::Null,
                 AnyValueKind::Bool(b) => SqliteArgumentValue::Int(b as i32),
                 AnyValueKind::SmallInt(i) => SqliteArgumentValue::Int(i as i32),
                 AnyValueKind::Integer(i) => SqliteArgumentValue::Int(i),
@@ -0,0 +1,146 @@
+use std::os::raw::c_int;
+use std::path::Path;
+use std::ptr::NonNull;
+use std::time::Duration;
+
+use libsqlite3_sys::{
+    sqlite3, sqlite3_backup, sqlite3_backup_finish, sqlite3_backup_init, sqlite3_backup_pagecount,
+    sqlite3_backup_remaining, sqlite3_backup_step, SQLITE_BUSY, SQLITE_DONE, SQLITE_LOCKED,
+    SQLITE_OK,
+};
+use sqlx_core::connection::{ConnectOptions, Connection};
+use sqlx_core::rt;
+
+use crate::error::Error;
+use crate::{SqliteConnectOptions, SqliteConnection, SqliteError};
+
+const MAIN: &[u8] = b"main\0";
+
+// The number of pages copied before yielding to the runtime.
+const PAGES_PER_STEP: c_int = 128;
+
+// How long to wait before retrying when the source database is locked by another connection.
+const BUSY_RETRY_DELAY: Duration = Duration::from_millis(10);
+
+/// An in-progress `sqlite3_backup`, finished when dropped so that a cancelled backup
+/// releases its locks on both databases.
+struct Backup {
+    handle: NonNull<sqlite3_backup>,
+    dest: *mut sqlite3,
+}
+
+// SAFETY: the backup is only used while both connection handles are locked.
+unsafe impl Send for Backup {}
+
+impl Backup {
+    fn finish(self) -> Result<(), Error> {
+        let dest = self.dest;
+        let handle = self.handle;
+        std::mem::forget(self);
+
+        if unsafe { sqlite3_backup_finish(handle.as_ptr()) } == SQLITE_OK {
+            Ok(())
+        } else {
+            Err(SqliteError::new(dest).into())
+        }
+    }
+}
+
+impl Drop for Backup {
+    fn drop(&mut self) {
+        unsafe {
+            sqlite3_backup_finish(self.handle.as_ptr());
+        }
+    }
+}
+
+impl SqliteConnection {
+    /// Copy the contents of the `main` database of this connection into `dest`, replacing
+    /// its contents, using the [Online Backup API](https://www.sqlite.org/backup.html).
+    ///
+    /// Pages are copied a few at a time, yielding to the runtime in between. Other connections
+    /// can keep writing to the database during the backup; if they do, it is restarted to
+    /// produce a consistent snapshot. Writes made through this connection are not possible
+    /// while the backup holds it, so they never cause a restart.
+    pub async fn backup_to(&mut self, dest: &mut SqliteConnection) -> Result<(), Error> {
+        self.backup_to_with_progress(dest, |_, _| {}).await
+    }
+
+    /// Like [`backup_to()`][Self::backup_to], but calls `progress` with the number of pages
+    /// remaining and the total number of pages after each step.
+    pub async fn backup_to_with_progress(
+        &mut self,
+        dest: &mut SqliteConnection,
+        mut progress: impl FnMut(u32, u32) + Send,
+    ) -> Result<(), Error> {
+        let mut source = self.lock_handle().await?;
+        let mut dest = dest.lock_handle().await?;
+
+        let dest_ptr = dest.as_raw_handle().as_ptr();
+
+        let handle = unsafe {
+            sqlite3_backup_init(
+                dest_ptr,
+                MAIN.as_ptr().cast(),
+                source.as_raw_handle().as_ptr(),
+                MAIN.as_ptr().cast(),
+            )
+        };
+
+        let Some(handle) = NonNull::new(handle) else {
+            return Err(SqliteError::new(dest_ptr).into());
+        };
+
+        let backup = Backup {
+            handle,
+            dest: dest_ptr,
+        };
+
+        loop {
+            match unsafe { sqlite3_backup_step(backup.handle.as_ptr(), PAGES_PER_STEP) } {
+                SQLITE_OK => {}
+                SQLITE_DONE => break,
+                SQLITE_BUSY | SQLITE_LOCKED => {
+                    rt::sleep(BUSY_RETRY_DELAY).await;
+                    continue;
+                }
+                // `sqlite3_backup_finish()` returns the error and sets it on the destination
+                _ => return backup.finish(),
+            }
+
+            let (remaining, total) = unsafe {
+                (
+                    sqlite3_backup_remaining(backup.handle.as_ptr()),
+                    sqlite3_backup_pagecount(backup.handle.as_ptr()),
+                )
+            };
+
+            progress(remaining as u32, total as u32);
+
+            rt::yield_now().await;
+        }
+
+        let total = unsafe { sqlite3_backup_pagecount(backup.handle.as_ptr()) };
+
+        backup.finish()?;
+        progress(0, total as u32);
+
+        Ok(())
+    }
+
+    /// Back up the `main` database of this connection into a database file at `path`,
+    /// creating it if it does not exist.
+    ///
+    /// See [`backup_to()`][Self::backup_to] for details.
+    pub async fn backup_to_file(&mut self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let mut dest = SqliteConnectOptions::new()
+            .filename(path)
+            .create_if_missing(true)
+            .connect()
+            .await?;
+
+        self.backup_to(&mut dest).await?;
+
+        dest.close().await
+    }
+}
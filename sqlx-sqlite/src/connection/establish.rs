@@ -250,6 +250,7 @@ impl EstablishParams {
                     };
                     return Err(Error::Database(Box::new(SqliteError::extension(
                         handle.as_ptr(),
+                        &ext.0,
                         &err_msg,
                     ))));
                 }
@@ -294,6 +295,7 @@ impl EstablishParams {
             transaction_depth: 0,
             log_settings: self.log_settings.clone(),
             progress_handler_callback: None,
+            update_hook_callback: None,
         })
     }
 }
@@ -4,6 +4,7 @@ use crate::logger::QueryLogger;
 use crate::statement::{StatementHandle, VirtualStatement};
 use crate::{SqliteArguments, SqliteQueryResult, SqliteRow};
 use sqlx_core::Either;
+use std::time::Duration;
 
 pub struct ExecuteIter<'a> {
     handle: &'a mut ConnectionHandle,
@@ -16,6 +17,9 @@ pub struct ExecuteIter<'a> {
     args_used: usize,
 
     goto_next: bool,
+
+    /// number of statements prepared so far that are not read-only
+    writes: u64,
 }
 
 pub(crate) fn iter<'a>(
@@ -36,6 +40,7 @@ pub(crate) fn iter<'a>(
         args,
         args_used: 0,
         goto_next: true,
+        writes: 0,
     })
 }
 
@@ -54,6 +59,19 @@ fn bind(
 }
 
 impl ExecuteIter<'_> {
+    pub fn override_slow_statements_duration(&mut self, duration: Option<Duration>) {
+        self.logger.override_slow_statements_duration(duration);
+    }
+
+    /// Returns the number of statements that are not read-only since the last call.
+    pub(crate) fn take_writes(&mut self) -> u64 {
+        std::mem::take(&mut self.writes)
+    }
+
+    pub(crate) fn handle_mut(&mut self) -> &mut ConnectionHandle {
+        self.handle
+    }
+
     pub fn finish(&mut self) -> Result<(), Error> {
         for res in self {
             let _ = res?;
@@ -76,6 +94,10 @@ impl Iterator for ExecuteIter<'_> {
 
             self.goto_next = false;
 
+            if !statement.handle.read_only() {
+                self.writes += 1;
+            }
+
             // sanity check: ensure the VM is reset and the bindings are cleared
             if let Err(e) = statement.handle.reset() {
                 return Some(Err(e.into()));
@@ -84,7 +106,10 @@ impl Iterator for ExecuteIter<'_> {
             statement.handle.clear_bindings();
 
             match bind(&mut statement.handle, &self.args, self.args_used) {
-                Ok(args_used) => self.args_used += args_used,
+                Ok(args_used) => {
+                    self.args_used += args_used;
+                    self.logger.set_param_count(self.args_used);
+                }
                 Err(e) => return Some(Err(e)),
             }
 
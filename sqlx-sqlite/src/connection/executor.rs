@@ -23,10 +23,17 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
         let sql = query.sql();
         let arguments = query.take_arguments();
         let persistent = query.persistent() && arguments.is_some();
+        let slow_statements_duration = query.slow_statements_duration();
 
         Box::pin(
             self.worker
-                .execute(sql, arguments, self.row_channel_size, persistent)
+                .execute(
+                    sql,
+                    arguments,
+                    self.row_channel_size,
+                    persistent,
+                    slow_statements_duration,
+                )
                 .map_ok(flume::Receiver::into_stream)
                 .try_flatten_stream(),
         )
@@ -43,11 +50,18 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
         let sql = query.sql();
         let arguments = query.take_arguments();
         let persistent = query.persistent() && arguments.is_some();
+        let slow_statements_duration = query.slow_statements_duration();
 
         Box::pin(async move {
             let stream = self
                 .worker
-                .execute(sql, arguments, self.row_channel_size, persistent)
+                .execute(
+                    sql,
+                    arguments,
+                    self.row_channel_size,
+                    persistent,
+                    slow_statements_duration,
+                )
                 .map_ok(flume::Receiver::into_stream)
                 .try_flatten_stream();
 
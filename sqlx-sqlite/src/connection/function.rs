@@ -0,0 +1,274 @@
+use std::any::Any;
+use std::ffi::CString;
+use std::fmt::{self, Debug, Formatter};
+use std::os::raw::{c_char, c_int, c_void};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+use std::sync::Arc;
+
+use libsqlite3_sys::{
+    sqlite3_aggregate_context, sqlite3_context, sqlite3_create_function_v2, sqlite3_result_blob64,
+    sqlite3_result_double, sqlite3_result_error, sqlite3_result_int, sqlite3_result_int64,
+    sqlite3_result_null, sqlite3_result_text64, sqlite3_user_data, sqlite3_value,
+    sqlite3_value_type, SQLITE_OK, SQLITE_TRANSIENT, SQLITE_UTF8,
+};
+
+use crate::arguments::SqliteArgumentValue;
+use crate::connection::handle::ConnectionHandle;
+use crate::encode::{Encode, IsNull};
+use crate::error::{BoxDynError, Error};
+use crate::type_info::DataType;
+use crate::{Sqlite, SqliteError, SqliteTypeInfo, SqliteValue};
+
+type ScalarFn =
+    dyn Fn(&[SqliteValue]) -> Result<SqliteArgumentValue<'static>, BoxDynError> + Send + Sync;
+
+type AggregateState = Box<dyn Any + Send>;
+
+struct AggregateFns {
+    init: Box<dyn Fn() -> AggregateState + Send + Sync>,
+    step: Box<dyn Fn(&mut AggregateState, &[SqliteValue]) -> Result<(), BoxDynError> + Send + Sync>,
+    finalize: Box<
+        dyn Fn(AggregateState) -> Result<SqliteArgumentValue<'static>, BoxDynError> + Send + Sync,
+    >,
+}
+
+#[derive(Clone)]
+enum FunctionKind {
+    Scalar(Arc<ScalarFn>),
+    Aggregate(Arc<AggregateFns>),
+}
+
+/// A user-defined SQL function, registered on a connection with
+/// [`sqlite3_create_function_v2()`](https://www.sqlite.org/c3ref/create_function.html).
+#[derive(Clone)]
+pub struct Function {
+    name: Arc<str>,
+    n_args: c_int,
+    kind: FunctionKind,
+}
+
+impl Function {
+    pub fn scalar<N, F, T>(name: N, n_args: i32, func: F) -> Self
+    where
+        N: Into<Arc<str>>,
+        F: Fn(&[SqliteValue]) -> Result<T, BoxDynError> + Send + Sync + 'static,
+        T: Encode<'static, Sqlite>,
+    {
+        Function {
+            name: name.into(),
+            n_args,
+            kind: FunctionKind::Scalar(Arc::new(move |args| func(args).map(encode))),
+        }
+    }
+
+    pub fn aggregate<N, S, I, St, Fi, T>(
+        name: N,
+        n_args: i32,
+        init: I,
+        step: St,
+        finalize: Fi,
+    ) -> Self
+    where
+        N: Into<Arc<str>>,
+        S: Send + 'static,
+        I: Fn() -> S + Send + Sync + 'static,
+        St: Fn(&mut S, &[SqliteValue]) -> Result<(), BoxDynError> + Send + Sync + 'static,
+        Fi: Fn(S) -> Result<T, BoxDynError> + Send + Sync + 'static,
+        T: Encode<'static, Sqlite>,
+    {
+        // the state is only ever created by `init`, so the downcasts cannot fail
+        let fns = AggregateFns {
+            init: Box::new(move || Box::new(init())),
+            step: Box::new(move |state, args| step(state.downcast_mut().unwrap(), args)),
+            finalize: Box::new(move |state| finalize(*state.downcast().unwrap()).map(encode)),
+        };
+
+        Function {
+            name: name.into(),
+            n_args,
+            kind: FunctionKind::Aggregate(Arc::new(fns)),
+        }
+    }
+
+    pub(crate) fn create(&self, handle: &mut ConnectionHandle) -> Result<(), Error> {
+        let c_name = CString::new(&*self.name)
+            .map_err(|_| err_protocol!("invalid function name: {:?}", self.name))?;
+
+        let r = unsafe {
+            match &self.kind {
+                FunctionKind::Scalar(func) => sqlite3_create_function_v2(
+                    handle.as_ptr(),
+                    c_name.as_ptr(),
+                    self.n_args,
+                    SQLITE_UTF8,
+                    Box::into_raw(Box::new(Arc::clone(func))) as *mut c_void,
+                    Some(call_scalar),
+                    None,
+                    None,
+                    Some(drop_boxed::<Arc<ScalarFn>>),
+                ),
+                FunctionKind::Aggregate(fns) => sqlite3_create_function_v2(
+                    handle.as_ptr(),
+                    c_name.as_ptr(),
+                    self.n_args,
+                    SQLITE_UTF8,
+                    Box::into_raw(Box::new(Arc::clone(fns))) as *mut c_void,
+                    None,
+                    Some(call_step),
+                    Some(call_final),
+                    Some(drop_boxed::<Arc<AggregateFns>>),
+                ),
+            }
+        };
+
+        // unlike `sqlite3_create_collation_v2()`, xDestroy is called if this fails
+        if r == SQLITE_OK {
+            Ok(())
+        } else {
+            Err(Error::Database(Box::new(SqliteError::new(handle.as_ptr()))))
+        }
+    }
+}
+
+impl Debug for Function {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Function")
+            .field("name", &self.name)
+            .field("n_args", &self.n_args)
+            .finish_non_exhaustive()
+    }
+}
+
+fn encode<T: Encode<'static, Sqlite>>(value: T) -> SqliteArgumentValue<'static> {
+    let mut buf = Vec::with_capacity(1);
+
+    match value.encode(&mut buf) {
+        IsNull::Yes => SqliteArgumentValue::Null,
+        IsNull::No => buf.pop().unwrap_or(SqliteArgumentValue::Null),
+    }
+}
+
+// the user data is a thin pointer to the boxed `Arc`, as `Arc<dyn Fn>` is a fat pointer
+unsafe extern "C" fn drop_boxed<T>(p: *mut c_void) {
+    drop(Box::from_raw(p as *mut T));
+}
+
+unsafe fn args(argc: c_int, argv: *mut *mut sqlite3_value) -> Vec<SqliteValue> {
+    (0..argc as usize)
+        .map(|i| {
+            let value = *argv.add(i);
+            let type_info = SqliteTypeInfo(DataType::from_code(sqlite3_value_type(value)));
+
+            SqliteValue::new(value, type_info)
+        })
+        .collect()
+}
+
+unsafe fn set_result(
+    ctx: *mut sqlite3_context,
+    result: Result<Result<SqliteArgumentValue<'static>, BoxDynError>, Box<dyn Any + Send>>,
+) {
+    let error = match result {
+        Ok(Ok(value)) => {
+            match value {
+                SqliteArgumentValue::Null => sqlite3_result_null(ctx),
+                SqliteArgumentValue::Text(v) => sqlite3_result_text64(
+                    ctx,
+                    v.as_ptr() as *const c_char,
+                    v.len() as u64,
+                    SQLITE_TRANSIENT(),
+                    SQLITE_UTF8 as u8,
+                ),
+                SqliteArgumentValue::Blob(v) => sqlite3_result_blob64(
+                    ctx,
+                    v.as_ptr() as *const c_void,
+                    v.len() as u64,
+                    SQLITE_TRANSIENT(),
+                ),
+                SqliteArgumentValue::Double(v) => sqlite3_result_double(ctx, v),
+                SqliteArgumentValue::Int(v) => sqlite3_result_int(ctx, v),
+                SqliteArgumentValue::Int64(v) => sqlite3_result_int64(ctx, v),
+            }
+
+            return;
+        }
+        Ok(Err(error)) => error.to_string(),
+        Err(_) => "user-defined function panicked".to_owned(),
+    };
+
+    sqlite3_result_error(ctx, error.as_ptr() as *const c_char, error.len() as c_int);
+}
+
+unsafe extern "C" fn call_scalar(
+    ctx: *mut sqlite3_context,
+    argc: c_int,
+    argv: *mut *mut sqlite3_value,
+) {
+    let func = &*(sqlite3_user_data(ctx) as *const Arc<ScalarFn>);
+    let args = args(argc, argv);
+
+    set_result(ctx, catch_unwind(AssertUnwindSafe(|| func(&args))));
+}
+
+/// Returns the slot in the aggregate context holding this invocation's state, if allocated.
+unsafe fn aggregate_state(ctx: *mut sqlite3_context, alloc: bool) -> *mut *mut AggregateState {
+    let size = if alloc {
+        std::mem::size_of::<*mut AggregateState>() as c_int
+    } else {
+        0
+    };
+
+    // the context is zeroed when allocated, so the slot starts out null
+    sqlite3_aggregate_context(ctx, size) as *mut *mut AggregateState
+}
+
+unsafe extern "C" fn call_step(
+    ctx: *mut sqlite3_context,
+    argc: c_int,
+    argv: *mut *mut sqlite3_value,
+) {
+    let fns = &*(sqlite3_user_data(ctx) as *const Arc<AggregateFns>);
+    let args = args(argc, argv);
+
+    let slot = aggregate_state(ctx, true);
+
+    if slot.is_null() {
+        let error = "out of memory";
+        sqlite3_result_error(ctx, error.as_ptr() as *const c_char, error.len() as c_int);
+        return;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        if (*slot).is_null() {
+            *slot = Box::into_raw(Box::new((fns.init)()));
+        }
+
+        (fns.step)(&mut **slot, &args)
+    }));
+
+    match result {
+        Ok(Ok(())) => {}
+        Ok(Err(e)) => set_result(ctx, Ok(Err(e))),
+        Err(e) => set_result(ctx, Err(e)),
+    }
+}
+
+unsafe extern "C" fn call_final(ctx: *mut sqlite3_context) {
+    let fns = &*(sqlite3_user_data(ctx) as *const Arc<AggregateFns>);
+
+    // no context was allocated if `xStep` was never called, i.e. there were no rows
+    let slot = aggregate_state(ctx, false);
+    let state = if slot.is_null() || (*slot).is_null() {
+        None
+    } else {
+        Some(*Box::from_raw(ptr::replace(slot, ptr::null_mut())))
+    };
+
+    set_result(
+        ctx,
+        catch_unwind(AssertUnwindSafe(|| {
+            (fns.finalize)(state.unwrap_or_else(|| (fns.init)()))
+        })),
+    );
+}
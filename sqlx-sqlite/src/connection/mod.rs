@@ -1,14 +1,19 @@
 use std::cmp::Ordering;
+use std::ffi::CStr;
 use std::fmt::Write;
 use std::fmt::{self, Debug, Formatter};
-use std::os::raw::{c_int, c_void};
+use std::os::raw::{c_char, c_int, c_void};
 use std::panic::catch_unwind;
 use std::ptr::NonNull;
+use std::sync::Arc;
 
 use futures_core::future::BoxFuture;
 use futures_intrusive::sync::MutexGuard;
 use futures_util::future;
-use libsqlite3_sys::{sqlite3, sqlite3_progress_handler};
+use libsqlite3_sys::{
+    sqlite3, sqlite3_progress_handler, sqlite3_update_hook, SQLITE_DELETE, SQLITE_INSERT,
+    SQLITE_UPDATE,
+};
 
 pub(crate) use handle::ConnectionHandle;
 use sqlx_core::common::StatementCache;
@@ -18,17 +23,22 @@ use sqlx_core::executor::Executor;
 use sqlx_core::transaction::Transaction;
 
 use crate::connection::establish::EstablishParams;
+use crate::connection::function::Function;
 use crate::connection::worker::ConnectionWorker;
+use crate::encode::Encode;
+use crate::error::BoxDynError;
 use crate::options::OptimizeOnClose;
 use crate::statement::VirtualStatement;
-use crate::{Sqlite, SqliteConnectOptions};
+use crate::{Sqlite, SqliteConnectOptions, SqliteValue};
 
+mod backup;
 pub(crate) mod collation;
 pub(crate) mod describe;
 pub(crate) mod establish;
 pub(crate) mod execute;
 mod executor;
 mod explain;
+pub(crate) mod function;
 mod handle;
 mod intmap;
 
@@ -58,6 +68,39 @@ pub struct LockedSqliteHandle<'a> {
 pub(crate) struct Handler(NonNull<dyn FnMut() -> bool + Send + 'static>);
 unsafe impl Send for Handler {}
 
+/// The kind of change reported to an [update hook][LockedSqliteHandle::set_update_hook].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SqliteOperation {
+    Insert,
+    Update,
+    Delete,
+    Unknown(i32),
+}
+
+impl From<c_int> for SqliteOperation {
+    fn from(value: c_int) -> Self {
+        match value {
+            SQLITE_INSERT => SqliteOperation::Insert,
+            SQLITE_UPDATE => SqliteOperation::Update,
+            SQLITE_DELETE => SqliteOperation::Delete,
+            code => SqliteOperation::Unknown(code),
+        }
+    }
+}
+
+/// A row change reported to an [update hook][LockedSqliteHandle::set_update_hook].
+#[derive(Debug)]
+pub struct UpdateHookResult<'a> {
+    pub operation: SqliteOperation,
+    pub database: &'a str,
+    pub table: &'a str,
+    pub rowid: i64,
+}
+
+pub(crate) struct UpdateHookHandler(NonNull<dyn FnMut(UpdateHookResult) + Send + 'static>);
+unsafe impl Send for UpdateHookHandler {}
+
 pub(crate) struct ConnectionState {
     pub(crate) handle: ConnectionHandle,
 
@@ -71,6 +114,8 @@ pub(crate) struct ConnectionState {
     /// Stores the progress handler set on the current connection. If the handler returns `false`,
     /// the query is interrupted.
     progress_handler_callback: Option<Handler>,
+
+    update_hook_callback: Option<UpdateHookHandler>,
 }
 
 impl ConnectionState {
@@ -83,6 +128,16 @@ impl ConnectionState {
             }
         }
     }
+
+    /// Drops the `update_hook_callback` if it exists.
+    pub(crate) fn remove_update_hook(&mut self) {
+        if let Some(mut handler) = self.update_hook_callback.take() {
+            unsafe {
+                sqlite3_update_hook(self.handle.as_ptr(), None, std::ptr::null_mut());
+                let _ = { Box::from_raw(handler.0.as_mut()) };
+            }
+        }
+    }
 }
 
 pub(crate) struct Statements {
@@ -112,6 +167,49 @@ impl SqliteConnection {
 
         Ok(LockedSqliteHandle { guard })
     }
+
+    /// Register a user-defined scalar SQL function on this connection only.
+    ///
+    /// See [`SqliteConnectOptions::scalar_function()`] to register it on every new connection.
+    pub async fn create_scalar_function<N, F, T>(
+        &mut self,
+        name: N,
+        n_args: i32,
+        func: F,
+    ) -> Result<(), Error>
+    where
+        N: Into<Arc<str>>,
+        F: Fn(&[SqliteValue]) -> Result<T, BoxDynError> + Send + Sync + 'static,
+        T: Encode<'static, Sqlite>,
+    {
+        self.lock_handle()
+            .await?
+            .create_scalar_function(name, n_args, func)
+    }
+
+    /// Register a user-defined aggregate SQL function on this connection only.
+    ///
+    /// See [`SqliteConnectOptions::aggregate_function()`] to register it on every new connection.
+    pub async fn create_aggregate_function<N, S, I, St, Fi, T>(
+        &mut self,
+        name: N,
+        n_args: i32,
+        init: I,
+        step: St,
+        finalize: Fi,
+    ) -> Result<(), Error>
+    where
+        N: Into<Arc<str>>,
+        S: Send + 'static,
+        I: Fn() -> S + Send + Sync + 'static,
+        St: Fn(&mut S, &[SqliteValue]) -> Result<(), BoxDynError> + Send + Sync + 'static,
+        Fi: Fn(S) -> Result<T, BoxDynError> + Send + Sync + 'static,
+        T: Encode<'static, Sqlite>,
+    {
+        self.lock_handle()
+            .await?
+            .create_aggregate_function(name, n_args, init, step, finalize)
+    }
 }
 
 impl Debug for SqliteConnection {
@@ -215,6 +313,32 @@ where
     }
 }
 
+extern "C" fn update_hook<F>(
+    callback: *mut c_void,
+    op_code: c_int,
+    database: *const c_char,
+    table: *const c_char,
+    rowid: i64,
+) where
+    F: FnMut(UpdateHookResult),
+{
+    unsafe {
+        let _ = catch_unwind(|| {
+            let callback: *mut F = callback.cast::<F>();
+            let operation: SqliteOperation = op_code.into();
+            let database = CStr::from_ptr(database).to_str().unwrap_or_default();
+            let table = CStr::from_ptr(table).to_str().unwrap_or_default();
+
+            (*callback)(UpdateHookResult {
+                operation,
+                database,
+                table,
+                rowid,
+            })
+        });
+    }
+}
+
 impl LockedSqliteHandle<'_> {
     /// Returns the underlying sqlite3* connection handle.
     ///
@@ -245,6 +369,45 @@ impl LockedSqliteHandle<'_> {
         collation::create_collation(&mut self.guard.handle, name, compare)
     }
 
+    /// Register a user-defined scalar SQL function on the open database.
+    ///
+    /// See [`SqliteConnectOptions::scalar_function()`] for details.
+    pub fn create_scalar_function<N, F, T>(
+        &mut self,
+        name: N,
+        n_args: i32,
+        func: F,
+    ) -> Result<(), Error>
+    where
+        N: Into<Arc<str>>,
+        F: Fn(&[SqliteValue]) -> Result<T, BoxDynError> + Send + Sync + 'static,
+        T: Encode<'static, Sqlite>,
+    {
+        Function::scalar(name, n_args, func).create(&mut self.guard.handle)
+    }
+
+    /// Register a user-defined aggregate SQL function on the open database.
+    ///
+    /// See [`SqliteConnectOptions::aggregate_function()`] for details.
+    pub fn create_aggregate_function<N, S, I, St, Fi, T>(
+        &mut self,
+        name: N,
+        n_args: i32,
+        init: I,
+        step: St,
+        finalize: Fi,
+    ) -> Result<(), Error>
+    where
+        N: Into<Arc<str>>,
+        S: Send + 'static,
+        I: Fn() -> S + Send + Sync + 'static,
+        St: Fn(&mut S, &[SqliteValue]) -> Result<(), BoxDynError> + Send + Sync + 'static,
+        Fi: Fn(S) -> Result<T, BoxDynError> + Send + Sync + 'static,
+        T: Encode<'static, Sqlite>,
+    {
+        Function::aggregate(name, n_args, init, step, finalize).create(&mut self.guard.handle)
+    }
+
     /// Sets a progress handler that is invoked periodically during long running calls. If the progress callback
     /// returns `false`, then the operation is interrupted.
     ///
@@ -283,6 +446,45 @@ impl LockedSqliteHandle<'_> {
     pub fn remove_progress_handler(&mut self) {
         self.guard.remove_progress_handler();
     }
+
+    /// Sets a callback that is invoked whenever a row is inserted, updated or deleted in a
+    /// rowid table through this connection, with the name of the database and table and the
+    /// rowid of the row.
+    ///
+    /// The callback runs synchronously on the worker thread while the statement is executing,
+    /// so it must not block or use the connection. To react to changes, send them over a
+    /// channel instead.
+    ///
+    /// Changes to `WITHOUT ROWID` tables, and those made by `REPLACE` conflict resolution,
+    /// truncating deletes and other connections, are not reported.
+    /// See [`sqlite3_update_hook()`](https://www.sqlite.org/c3ref/update_hook.html) for details.
+    ///
+    /// Only a single update hook may be defined at one time per database connection; setting a
+    /// new one replaces the old one.
+    pub fn set_update_hook<F>(&mut self, callback: F)
+    where
+        F: FnMut(UpdateHookResult) + Send + 'static,
+    {
+        unsafe {
+            let callback_boxed = Box::new(callback);
+            // SAFETY: `Box::into_raw()` always returns a non-null pointer.
+            let callback = NonNull::new_unchecked(Box::into_raw(callback_boxed));
+            let handler = callback.as_ptr() as *mut _;
+            self.guard.remove_update_hook();
+            self.guard.update_hook_callback = Some(UpdateHookHandler(callback));
+
+            sqlite3_update_hook(
+                self.as_raw_handle().as_mut(),
+                Some(update_hook::<F>),
+                handler,
+            );
+        }
+    }
+
+    /// Removes the update hook on a database connection. The method does nothing if no hook was set.
+    pub fn remove_update_hook(&mut self) {
+        self.guard.remove_update_hook();
+    }
 }
 
 impl Drop for ConnectionState {
@@ -290,6 +492,7 @@ impl Drop for ConnectionState {
         // explicitly drop statements before the connection handle is dropped
         self.statements.clear();
         self.remove_progress_handler();
+        self.remove_update_hook();
     }
 }
 
@@ -1,8 +1,9 @@
 use std::borrow::Cow;
 use std::future::Future;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::thread;
+use std::time::Duration;
 
 use futures_channel::oneshot;
 use futures_intrusive::sync::{Mutex, MutexGuard};
@@ -35,6 +36,10 @@ pub(crate) struct ConnectionWorker {
 
 pub(crate) struct WorkerSharedState {
     pub(crate) cached_statements_size: AtomicUsize,
+    /// Set when SQLite rolled back the active transaction on its own because of an error.
+    pub(crate) transaction_aborted: AtomicBool,
+    /// The number of statements run that may have written to the database.
+    pub(crate) write_count: AtomicU64,
     pub(crate) conn: Mutex<ConnectionState>,
 }
 
@@ -51,6 +56,7 @@ enum Command {
         query: Box<str>,
         arguments: Option<SqliteArguments<'static>>,
         persistent: bool,
+        slow_statements_duration: Option<Duration>,
         tx: flume::Sender<Result<Either<SqliteQueryResult, SqliteRow>, Error>>,
     },
     Begin {
@@ -93,6 +99,8 @@ impl ConnectionWorker {
 
                 let shared = Arc::new(WorkerSharedState {
                     cached_statements_size: AtomicUsize::new(0),
+                    transaction_aborted: AtomicBool::new(false),
+                    write_count: AtomicU64::new(0),
                     // note: must be fair because in `Command::UnlockDb` we unlock the mutex
                     // and then immediately try to relock it; an unfair mutex would immediately
                     // grant us the lock even if another task is waiting.
@@ -135,9 +143,12 @@ impl ConnectionWorker {
                             query,
                             arguments,
                             persistent,
+                            slow_statements_duration,
                             tx,
                         } => {
-                            let iter = match execute::iter(&mut conn, &query, arguments, persistent)
+                            let in_transaction = conn.transaction_depth > 0;
+
+                            let mut iter = match execute::iter(&mut conn, &query, arguments, persistent)
                             {
                                 Ok(iter) => iter,
                                 Err(e) => {
@@ -146,12 +157,30 @@ impl ConnectionWorker {
                                 }
                             };
 
-                            for res in iter {
+                            iter.override_slow_statements_duration(slow_statements_duration);
+
+                            while let Some(res) = iter.next() {
+                                // counted before sending the result so `Transaction::was_dirty()`
+                                // is up to date when the caller sees it
+                                shared
+                                    .write_count
+                                    .fetch_add(iter.take_writes(), Ordering::Release);
+
+                                // SQLite rolls back the whole transaction on some errors, e.g.
+                                // `SQLITE_FULL`; this is checked before sending the error so
+                                // `Transaction::status()` is up to date when the caller sees it.
+                                // https://www.sqlite.org/lang_transaction.html#response_to_errors_within_a_transaction
+                                if res.is_err() && in_transaction && iter.handle_mut().in_autocommit()
+                                {
+                                    shared.transaction_aborted.store(true, Ordering::Release);
+                                }
+
                                 if tx.send(res).is_err() {
                                     break;
                                 }
                             }
 
+                            drop(iter);
                             update_cached_statements_size(&conn, &shared.cached_statements_size);
                         }
                         Command::Begin { tx } => {
@@ -164,6 +193,10 @@ impl ConnectionWorker {
                                     });
                             let res_ok = res.is_ok();
 
+                            if res_ok && depth == 0 {
+                                shared.transaction_aborted.store(false, Ordering::Release);
+                            }
+
                             if tx.blocking_send(res).is_err() && res_ok {
                                 // The BEGIN was processed but not acknowledged. This means no
                                 // `Transaction` was created and so there is no way to commit /
@@ -213,7 +246,12 @@ impl ConnectionWorker {
 
                             let depth = conn.transaction_depth;
 
-                            let res = if depth > 0 {
+                            let res = if depth > 0 && shared.transaction_aborted.load(Ordering::Acquire) {
+                                // SQLite already rolled back the whole transaction, including
+                                // any savepoints; there is nothing left to roll back.
+                                conn.transaction_depth -= 1;
+                                Ok(())
+                            } else if depth > 0 {
                                 conn.handle
                                     .exec(rollback_ansi_transaction_sql(depth))
                                     .map(|_| {
@@ -284,6 +322,7 @@ impl ConnectionWorker {
         args: Option<SqliteArguments<'_>>,
         chan_size: usize,
         persistent: bool,
+        slow_statements_duration: Option<Duration>,
     ) -> Result<flume::Receiver<Result<Either<SqliteQueryResult, SqliteRow>, Error>>, Error> {
         let (tx, rx) = flume::bounded(chan_size);
 
@@ -293,6 +332,7 @@ impl ConnectionWorker {
                     query: query.into(),
                     arguments: args.map(SqliteArguments::into_static),
                     persistent,
+                    slow_statements_duration,
                     tx,
                 },
                 Span::current(),
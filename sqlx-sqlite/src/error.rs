@@ -5,7 +5,8 @@ use std::os::raw::c_int;
 use std::{borrow::Cow, str::from_utf8_unchecked};
 
 use libsqlite3_sys::{
-    sqlite3, sqlite3_errmsg, sqlite3_extended_errcode, SQLITE_CONSTRAINT_CHECK,
+    sqlite3, sqlite3_errmsg, sqlite3_extended_errcode, SQLITE_BUSY, SQLITE_BUSY_RECOVERY,
+    SQLITE_BUSY_SNAPSHOT, SQLITE_BUSY_TIMEOUT, SQLITE_CONSTRAINT_CHECK,
     SQLITE_CONSTRAINT_FOREIGNKEY, SQLITE_CONSTRAINT_NOTNULL, SQLITE_CONSTRAINT_PRIMARYKEY,
     SQLITE_CONSTRAINT_UNIQUE,
 };
@@ -41,13 +42,47 @@ impl SqliteError {
     }
 
     /// For errors during extension load, the error message is supplied via a separate pointer
-    pub(crate) fn extension(handle: *mut sqlite3, error_msg: &CStr) -> Self {
+    pub(crate) fn extension(handle: *mut sqlite3, name: &CStr, error_msg: &CStr) -> Self {
         let mut err = Self::new(handle);
-        err.message = unsafe { from_utf8_unchecked(error_msg.to_bytes()).to_owned() };
+        err.message = format!(
+            "error loading extension {:?}: {}",
+            name.to_string_lossy(),
+            error_msg.to_string_lossy()
+        );
         err
     }
 }
 
+impl SqliteError {
+    /// The part of a constraint violation message after the kind of constraint, e.g.
+    /// `users.email` for `UNIQUE constraint failed: users.email`.
+    fn constraint_detail(&self) -> Option<&str> {
+        match self.code {
+            SQLITE_CONSTRAINT_UNIQUE
+            | SQLITE_CONSTRAINT_PRIMARYKEY
+            | SQLITE_CONSTRAINT_NOTNULL
+            | SQLITE_CONSTRAINT_CHECK => self
+                .message
+                .split_once("constraint failed: ")
+                .map(|(_, detail)| detail),
+            _ => None,
+        }
+    }
+
+    /// The first `table.column` of a violation, if the message lists them.
+    fn constraint_column(&self) -> Option<(&str, &str)> {
+        match self.code {
+            SQLITE_CONSTRAINT_UNIQUE | SQLITE_CONSTRAINT_PRIMARYKEY | SQLITE_CONSTRAINT_NOTNULL => {
+                let detail = self.constraint_detail()?;
+                let first = detail.split_once(", ").map_or(detail, |(first, _)| first);
+
+                first.split_once('.')
+            }
+            _ => None,
+        }
+    }
+}
+
 impl Display for SqliteError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         // We include the code as some produce ambiguous messages:
@@ -87,12 +122,38 @@ impl DatabaseError for SqliteError {
         self
     }
 
+    /// Parsed from the message. SQLite only names the constraint for `CHECK` constraints,
+    /// and for unique indexes on expressions.
+    fn constraint(&self) -> Option<&str> {
+        let detail = self.constraint_detail()?;
+
+        match self.code {
+            SQLITE_CONSTRAINT_CHECK => Some(detail),
+            // UNIQUE constraint failed: index 'name'
+            _ => detail.strip_prefix("index '")?.strip_suffix('\''),
+        }
+    }
+
+    /// Parsed from the message.
+    fn table(&self) -> Option<&str> {
+        self.constraint_column().map(|(table, _)| table)
+    }
+
+    /// Parsed from the message.
+    fn column(&self) -> Option<&str> {
+        self.constraint_column().map(|(_, column)| column)
+    }
+
     fn kind(&self) -> ErrorKind {
         match self.code {
             SQLITE_CONSTRAINT_UNIQUE | SQLITE_CONSTRAINT_PRIMARYKEY => ErrorKind::UniqueViolation,
             SQLITE_CONSTRAINT_FOREIGNKEY => ErrorKind::ForeignKeyViolation,
             SQLITE_CONSTRAINT_NOTNULL => ErrorKind::NotNullViolation,
             SQLITE_CONSTRAINT_CHECK => ErrorKind::CheckViolation,
+            // a snapshot of a read transaction can no longer be upgraded to write
+            SQLITE_BUSY_SNAPSHOT => ErrorKind::Serialization,
+            // another connection held a lock for longer than the busy timeout
+            SQLITE_BUSY | SQLITE_BUSY_RECOVERY | SQLITE_BUSY_TIMEOUT => ErrorKind::Timeout,
             _ => ErrorKind::Other,
         }
     }
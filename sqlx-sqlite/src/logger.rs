@@ -26,7 +26,7 @@ impl<'q, O: Debug + Hash + Eq, R: Debug, P: Debug> QueryPlanLogger<'q, O, R, P>
 
     pub fn log_enabled(&self) -> bool {
         if let Some((tracing_level, log_level)) =
-            logger::private_level_filter_to_levels(self.settings.statements_level)
+            logger::private_level_filter_to_levels(self.settings.trace_level())
         {
             log::log_enabled!(log_level)
                 || sqlx_core::private_tracing_dynamic_enabled!(tracing_level)
@@ -44,27 +44,13 @@ impl<'q, O: Debug + Hash + Eq, R: Debug, P: Debug> QueryPlanLogger<'q, O, R, P>
     }
 
     pub fn finish(&self) {
-        let lvl = self.settings.statements_level;
+        let lvl = self.settings.trace_level();
 
         if let Some((tracing_level, log_level)) = logger::private_level_filter_to_levels(lvl) {
             let log_is_enabled = log::log_enabled!(target: "sqlx::explain", log_level)
                 || private_tracing_dynamic_enabled!(target: "sqlx::explain", tracing_level);
             if log_is_enabled {
-                let mut summary = parse_query_summary(&self.sql);
-
-                let sql = if summary != self.sql {
-                    summary.push_str(" …");
-                    format!(
-                        "\n\n{}\n",
-                        sqlformat::format(
-                            &self.sql,
-                            &sqlformat::QueryParams::None,
-                            sqlformat::FormatOptions::default()
-                        )
-                    )
-                } else {
-                    String::new()
-                };
+                let (summary, sql) = summarize_statement(self.sql, &self.settings);
 
                 let message = format!(
                     "{}; program:{:?}, unknown_operations:{:?}, results: {:?}{}",
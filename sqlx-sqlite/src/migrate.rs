@@ -7,6 +7,7 @@ use crate::migrate::{AppliedMigration, Migration};
 use crate::migrate::{Migrate, MigrateDatabase};
 use crate::query::query;
 use crate::query_as::query_as;
+use crate::query_scalar::query_scalar;
 use crate::{Sqlite, SqliteConnectOptions, SqliteConnection, SqliteJournalMode};
 use futures_core::future::BoxFuture;
 use std::str::FromStr;
@@ -86,6 +87,19 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
+    fn migrations_table_exists(&mut self) -> BoxFuture<'_, Result<bool, MigrateError>> {
+        Box::pin(async move {
+            // language=SQLite
+            let exists: bool = query_scalar(
+                "SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = '_sqlx_migrations')",
+            )
+            .fetch_one(self)
+            .await?;
+
+            Ok(exists)
+        })
+    }
+
     fn dirty_version(&mut self) -> BoxFuture<'_, Result<Option<i64>, MigrateError>> {
         Box::pin(async move {
             // language=SQLite
@@ -104,17 +118,36 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
     ) -> BoxFuture<'_, Result<Vec<AppliedMigration>, MigrateError>> {
         Box::pin(async move {
             // language=SQLite
-            let rows: Vec<(i64, Vec<u8>)> =
-                query_as("SELECT version, checksum FROM _sqlx_migrations ORDER BY version")
-                    .fetch_all(self)
-                    .await?;
+            let rows: Vec<(i64, Vec<u8>, String, i64, i64, bool)> = query_as(
+                r#"
+SELECT
+    version,
+    checksum,
+    description,
+    CAST(strftime('%s', installed_on) AS INTEGER) * 1000000,
+    execution_time,
+    success
+FROM _sqlx_migrations
+ORDER BY version
+                "#,
+            )
+            .fetch_all(self)
+            .await?;
 
             let migrations = rows
                 .into_iter()
-                .map(|(version, checksum)| AppliedMigration {
-                    version,
-                    checksum: checksum.into(),
-                })
+                .map(
+                    |(version, checksum, description, installed_on, execution_time, success)| {
+                        AppliedMigration::from_row(
+                            version,
+                            checksum,
+                            description,
+                            installed_on,
+                            execution_time,
+                            success,
+                        )
+                    },
+                )
                 .collect();
 
             Ok(migrations)
@@ -134,33 +167,21 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         migration: &'m Migration,
     ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
         Box::pin(async move {
-            let mut tx = self.begin().await?;
-            let start = Instant::now();
-
             // Use a single transaction for the actual migration script and the essential bookeeping so we never
             // execute migrations twice. See https://github.com/launchbadge/sqlx/issues/1966.
             // The `execution_time` however can only be measured for the whole transaction. This value _only_ exists for
             // data lineage and debugging reasons, so it is not super important if it is lost. So we initialize it to -1
             // and update it once the actual transaction completed.
-            let _ = tx
-                .execute(&*migration.sql)
-                .await
-                .map_err(|e| MigrateError::ExecuteMigration(e, migration.version))?;
-
-            // language=SQL
-            let _ = query(
-                r#"
-    INSERT INTO _sqlx_migrations ( version, description, success, checksum, execution_time )
-    VALUES ( ?1, ?2, TRUE, ?3, -1 )
-                "#,
-            )
-            .bind(migration.version)
-            .bind(&*migration.description)
-            .bind(&*migration.checksum)
-            .execute(&mut *tx)
-            .await?;
+            let start = Instant::now();
 
-            tx.commit().await?;
+            if migration.no_tx() {
+                // opted out with `-- sqlx:no-transaction`, e.g. for `VACUUM`
+                execute_migration(&mut *self, migration).await?;
+            } else {
+                let mut tx = self.begin().await?;
+                execute_migration(&mut tx, migration).await?;
+                tx.commit().await?;
+            }
 
             // Update `elapsed_time`.
             // NOTE: The process may disconnect/die at this point, so the elapsed time value might be lost. We accept
@@ -192,22 +213,73 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         Box::pin(async move {
             // Use a single transaction for the actual migration script and the essential bookeeping so we never
             // execute migrations twice. See https://github.com/launchbadge/sqlx/issues/1966.
-            let mut tx = self.begin().await?;
             let start = Instant::now();
 
-            let _ = tx.execute(&*migration.sql).await?;
+            if migration.no_tx() {
+                // opted out with `-- sqlx:no-transaction`, e.g. for `VACUUM`
+                revert_migration(&mut *self, migration).await?;
+            } else {
+                let mut tx = self.begin().await?;
+                revert_migration(&mut tx, migration).await?;
+                tx.commit().await?;
+            }
 
+            let elapsed = start.elapsed();
+
+            Ok(elapsed)
+        })
+    }
+
+    fn update_checksum<'e: 'm, 'm>(
+        &'e mut self,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<(), MigrateError>> {
+        Box::pin(async move {
             // language=SQL
-            let _ = query(r#"DELETE FROM _sqlx_migrations WHERE version = ?1"#)
+            let _ = query(r#"UPDATE _sqlx_migrations SET checksum = ?1 WHERE version = ?2"#)
+                .bind(&*migration.checksum)
                 .bind(migration.version)
-                .execute(&mut *tx)
+                .execute(self)
                 .await?;
 
-            tx.commit().await?;
-
-            let elapsed = start.elapsed();
-
-            Ok(elapsed)
+            Ok(())
         })
     }
 }
+
+async fn execute_migration(
+    conn: &mut SqliteConnection,
+    migration: &Migration,
+) -> Result<(), MigrateError> {
+    migration.execute(&mut *conn).await?;
+
+    // language=SQL
+    let _ = query(
+        r#"
+    INSERT INTO _sqlx_migrations ( version, description, success, checksum, execution_time )
+    VALUES ( ?1, ?2, TRUE, ?3, -1 )
+            "#,
+    )
+    .bind(migration.version)
+    .bind(&*migration.description)
+    .bind(&*migration.checksum)
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+async fn revert_migration(
+    conn: &mut SqliteConnection,
+    migration: &Migration,
+) -> Result<(), MigrateError> {
+    let _ = (&mut *conn).execute(&*migration.sql).await?;
+
+    // language=SQL
+    let _ = query(r#"DELETE FROM _sqlx_migrations WHERE version = ?1"#)
+        .bind(migration.version)
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(())
+}
@@ -1,10 +1,14 @@
 use crate::{SqliteConnectOptions, SqliteConnection};
 use futures_core::future::BoxFuture;
 use log::LevelFilter;
-use sqlx_core::connection::ConnectOptions;
+use sqlx_core::connection::{connect_with_timeout, ConnectOptions};
 use sqlx_core::error::Error;
 use sqlx_core::executor::Executor;
+use sqlx_core::logger::QueryExecuted;
+use std::borrow::Cow;
 use std::fmt::Write;
+use std::io;
+use std::net::SocketAddr;
 use std::str::FromStr;
 use std::time::Duration;
 use url::Url;
@@ -32,22 +36,30 @@ impl ConnectOptions for SqliteConnectOptions {
     where
         Self::Connection: Sized,
     {
-        Box::pin(async move {
+        Box::pin(connect_with_timeout(self.connect_timeout, async move {
             let mut conn = SqliteConnection::establish(self).await?;
 
             // Execute PRAGMAs
             conn.execute(&*self.pragma_string()).await?;
 
-            if !self.collations.is_empty() {
+            if !self.collations.is_empty() || !self.functions.is_empty() {
                 let mut locked = conn.lock_handle().await?;
 
                 for collation in &self.collations {
                     collation.create(&mut locked.guard.handle)?;
                 }
+
+                for function in &self.functions {
+                    function.create(&mut locked.guard.handle)?;
+                }
+            }
+
+            for sql in &self.after_connect_sql {
+                conn.execute(sql.as_str()).await?;
             }
 
             Ok(conn)
-        })
+        }))
     }
 
     fn log_statements(mut self, level: LevelFilter) -> Self {
@@ -59,6 +71,63 @@ impl ConnectOptions for SqliteConnectOptions {
         self.log_settings.log_slow_statements(level, duration);
         self
     }
+
+    fn log_statements_trace(mut self, level: LevelFilter) -> Self {
+        self.log_settings.log_statements_trace(level);
+        self
+    }
+
+    fn log_summary_word_count(mut self, count: usize) -> Self {
+        self.log_settings.log_summary_word_count(count);
+        self
+    }
+
+    fn log_max_statement_length(mut self, len: usize) -> Self {
+        self.log_settings.log_max_statement_length(len);
+        self
+    }
+
+    fn redact_statements<F>(mut self, redactor: F) -> Self
+    where
+        F: Fn(&str) -> Cow<'_, str> + Send + Sync + 'static,
+    {
+        self.log_settings.redact_statements(redactor);
+        self
+    }
+
+    fn on_query_executed<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&QueryExecuted<'_>) + Send + Sync + 'static,
+    {
+        self.log_settings.on_query_executed(callback);
+        self
+    }
+
+    fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    fn tcp_keepalive(self, _time: Duration) -> Self {
+        // SQLite does not use TCP.
+        self
+    }
+
+    fn tcp_user_timeout(self, _timeout: Duration) -> Self {
+        self
+    }
+
+    fn resolver<F>(self, _resolver: F) -> Self
+    where
+        F: Fn(&str) -> BoxFuture<'static, io::Result<Vec<SocketAddr>>> + Send + Sync + 'static,
+    {
+        self
+    }
+
+    fn after_connect_sql(mut self, sql: impl Into<String>) -> Self {
+        self.after_connect_sql.push(sql.into());
+        self
+    }
 }
 
 impl SqliteConnectOptions {
@@ -18,6 +18,10 @@ pub use synchronous::SqliteSynchronous;
 
 use crate::common::DebugFn;
 use crate::connection::collation::Collation;
+use crate::connection::function::Function;
+use crate::encode::Encode;
+use crate::error::BoxDynError;
+use crate::{Sqlite, SqliteValue};
 use sqlx_core::IndexMap;
 
 /// Options and flags which can be used to configure a SQLite connection.
@@ -35,6 +39,19 @@ use sqlx_core::IndexMap;
 /// `sqlite://data.db` | Open the file `data.db` in the current directory. |
 /// `sqlite:///data.db` | Open the file `data.db` from the root (`/`) directory. |
 /// `sqlite://data.db?mode=ro` | Open the file `data.db` for read-only access. |
+/// `sqlite:file:name?mode=memory&cache=shared` | Open the in-memory database `name`, shared by every connection using this URL. |
+///
+/// # In-memory Databases
+///
+/// All connections created from the same `SqliteConnectOptions` open the same in-memory
+/// database, so it can be used with a pool. For `sqlite::memory:`, a unique name is generated
+/// when the URL is parsed; to share one database between separately parsed options, name it
+/// with `mode=memory` instead, as above.
+///
+/// SQLite destroys an in-memory database when its last connection is closed, taking the schema
+/// and data with it. When pooling connections, set
+/// [`min_connections`](crate::pool::PoolOptions::min_connections) to at least 1 to keep it
+/// alive while the pool is idle.
 ///
 /// # Example
 ///
@@ -62,6 +79,8 @@ pub struct SqliteConnectOptions {
     pub(crate) statement_cache_capacity: usize,
     pub(crate) busy_timeout: Duration,
     pub(crate) log_settings: LogSettings,
+    pub(crate) after_connect_sql: Vec<String>,
+    pub(crate) connect_timeout: Option<Duration>,
     pub(crate) immutable: bool,
     pub(crate) vfs: Option<Cow<'static, str>>,
 
@@ -76,6 +95,7 @@ pub struct SqliteConnectOptions {
     pub(crate) row_channel_size: usize,
 
     pub(crate) collations: Vec<Collation>,
+    pub(crate) functions: Vec<Function>,
 
     pub(crate) serialized: bool,
     pub(crate) thread_name: Arc<DebugFn<dyn Fn(u64) -> String + Send + Sync + 'static>>,
@@ -178,6 +198,9 @@ impl SqliteConnectOptions {
 
         pragmas.insert("auto_vacuum".into(), None);
 
+        // A positive value is a number of pages, a negative one a size in KiB.
+        pragmas.insert("cache_size".into(), None);
+
         // Soft limit on the number of rows that `ANALYZE` touches per index.
         pragmas.insert("analysis_limit".into(), None);
 
@@ -190,11 +213,14 @@ impl SqliteConnectOptions {
             statement_cache_capacity: 100,
             busy_timeout: Duration::from_secs(5),
             log_settings: Default::default(),
+            after_connect_sql: Vec::new(),
+            connect_timeout: None,
             immutable: false,
             vfs: None,
             pragmas,
             extensions: Default::default(),
             collations: Default::default(),
+            functions: Default::default(),
             serialized: false,
             thread_name: Arc::new(DebugFn(|id| format!("sqlx-sqlite-worker-{id}"))),
             command_channel_size: 50,
@@ -329,6 +355,14 @@ impl SqliteConnectOptions {
         self.pragma("page_size", page_size.to_string())
     }
 
+    /// Sets the [cache_size](https://www.sqlite.org/pragma.html#pragma_cache_size) setting for the database connection.
+    ///
+    /// A positive value is the maximum number of database pages held in memory, while a negative
+    /// value limits the cache to that many KiB instead. The default is -2000 (about 2 MB).
+    pub fn cache_size(self, cache_size: i64) -> Self {
+        self.pragma("cache_size", cache_size.to_string())
+    }
+
     /// Sets custom initial pragma for the database connection.
     pub fn pragma<K, V>(mut self, key: K, value: V) -> Self
     where
@@ -364,6 +398,75 @@ impl SqliteConnectOptions {
         self
     }
 
+    /// Add a user-defined scalar SQL function, registered on every connection opened with these
+    /// options.
+    ///
+    /// `func` is called with the arguments of each invocation and its result is encoded like a
+    /// bound parameter; an error is raised from the statement calling the function. `n_args` is
+    /// the number of arguments the function takes, or -1 for any number. Functions can be
+    /// overloaded by the number of arguments.
+    ///
+    /// If a function with the same name and number of arguments already exists, it will be
+    /// replaced.
+    ///
+    /// See [`sqlite3_create_function_v2()`](https://www.sqlite.org/c3ref/create_function.html) for details.
+    ///
+    /// ```rust,no_run
+    /// # async fn example() -> sqlx::Result<()> {
+    /// use sqlx::ConnectOptions;
+    /// use sqlx::sqlite::SqliteConnectOptions;
+    /// use sqlx::Value;
+    ///
+    /// let mut conn = SqliteConnectOptions::new()
+    ///     .scalar_function("reverse", 1, |args| {
+    ///         let s: String = args[0].try_decode()?;
+    ///         Ok(s.chars().rev().collect::<String>())
+    ///     })
+    ///     .connect()
+    ///     .await?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn scalar_function<N, F, T>(mut self, name: N, n_args: i32, func: F) -> Self
+    where
+        N: Into<Arc<str>>,
+        F: Fn(&[SqliteValue]) -> Result<T, BoxDynError> + Send + Sync + 'static,
+        T: Encode<'static, Sqlite>,
+    {
+        self.functions.push(Function::scalar(name, n_args, func));
+        self
+    }
+
+    /// Add a user-defined aggregate SQL function, registered on every connection opened with
+    /// these options.
+    ///
+    /// For each group, a state is created with `init`, then passed to `step` with the arguments
+    /// for each row, and finally to `finalize` to compute the result. Groups without any rows
+    /// go straight from `init` to `finalize`.
+    ///
+    /// See [`scalar_function()`][Self::scalar_function] for details.
+    pub fn aggregate_function<N, S, I, St, Fi, T>(
+        mut self,
+        name: N,
+        n_args: i32,
+        init: I,
+        step: St,
+        finalize: Fi,
+    ) -> Self
+    where
+        N: Into<Arc<str>>,
+        S: Send + 'static,
+        I: Fn() -> S + Send + Sync + 'static,
+        St: Fn(&mut S, &[SqliteValue]) -> Result<(), BoxDynError> + Send + Sync + 'static,
+        Fi: Fn(S) -> Result<T, BoxDynError> + Send + Sync + 'static,
+        T: Encode<'static, Sqlite>,
+    {
+        self.functions
+            .push(Function::aggregate(name, n_args, init, step, finalize));
+        self
+    }
+
     /// Set to `true` to signal to SQLite that the database file is on read-only media.
     ///
     /// If enabled, SQLite assumes the database file _cannot_ be modified, even by higher
@@ -180,6 +180,11 @@ fn test_parse_in_memory() -> Result<(), Error> {
     assert!(options.in_memory);
     assert!(!options.shared_cache);
 
+    let options: SqliteConnectOptions = "sqlite:file:shared?mode=memory&cache=shared".parse()?;
+    assert!(options.in_memory);
+    assert!(options.shared_cache);
+    assert_eq!(&*options.filename.to_string_lossy(), "file:shared");
+
     Ok(())
 }
 
@@ -2,7 +2,11 @@ use futures_core::future::BoxFuture;
 
 use crate::{Sqlite, SqliteConnection};
 use sqlx_core::error::Error;
-use sqlx_core::transaction::TransactionManager;
+use sqlx_core::transaction::{
+    check_transaction_options, IsolationLevel, TransactionManager, TransactionOptions,
+    TransactionStatus,
+};
+use std::sync::atomic::Ordering;
 
 /// Implementation of [`TransactionManager`] for SQLite.
 pub struct SqliteTransactionManager;
@@ -14,6 +18,40 @@ impl TransactionManager for SqliteTransactionManager {
         Box::pin(conn.worker.begin())
     }
 
+    fn begin_with(
+        conn: &mut SqliteConnection,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<(), Error>> {
+        // SQLite transactions are always serializable, so `Serializable` is accepted as a no-op
+        let unsupported = if options.read_only {
+            Some("read-only")
+        } else if options.deferrable {
+            Some("deferrable")
+        } else {
+            options
+                .isolation
+                .filter(|isolation| *isolation != IsolationLevel::Serializable)
+                .map(|isolation| isolation.as_sql())
+        };
+
+        if let Some(unsupported) = unsupported {
+            return Box::pin(futures_util::future::err(Error::Configuration(
+                format!("SQLite does not support {unsupported} transactions").into(),
+            )));
+        }
+
+        if options.is_default() {
+            return Box::pin(conn.worker.begin());
+        }
+
+        Box::pin(async move {
+            let depth = conn.lock_handle().await?.guard.transaction_depth;
+            check_transaction_options(depth, &options)?;
+
+            conn.worker.begin().await
+        })
+    }
+
     fn commit(conn: &mut SqliteConnection) -> BoxFuture<'_, Result<(), Error>> {
         Box::pin(conn.worker.commit())
     }
@@ -25,4 +63,21 @@ impl TransactionManager for SqliteTransactionManager {
     fn start_rollback(conn: &mut SqliteConnection) {
         conn.worker.start_rollback().ok();
     }
+
+    fn write_count(conn: &SqliteConnection) -> Option<u64> {
+        Some(conn.worker.shared.write_count.load(Ordering::Acquire))
+    }
+
+    fn transaction_status(conn: &SqliteConnection) -> TransactionStatus {
+        if conn
+            .worker
+            .shared
+            .transaction_aborted
+            .load(Ordering::Acquire)
+        {
+            TransactionStatus::Aborted
+        } else {
+            TransactionStatus::Active
+        }
+    }
 }
@@ -0,0 +1,29 @@
+use bigdecimal::BigDecimal;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::{decimal, Type};
+use crate::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef};
+
+impl Type<Sqlite> for BigDecimal {
+    fn type_info() -> SqliteTypeInfo {
+        decimal::type_info()
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        decimal::compatible(ty)
+    }
+}
+
+impl<'q> Encode<'q, Sqlite> for BigDecimal {
+    fn encode_by_ref(&self, args: &mut Vec<SqliteArgumentValue<'q>>) -> IsNull {
+        decimal::encode(self, args)
+    }
+}
+
+impl Decode<'_, Sqlite> for BigDecimal {
+    fn decode(value: SqliteValueRef<'_>) -> Result<Self, BoxDynError> {
+        decimal::decode(value)
+    }
+}
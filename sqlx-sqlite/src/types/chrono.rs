@@ -43,7 +43,7 @@ impl Type<Sqlite> for NaiveDate {
     }
 
     fn compatible(ty: &SqliteTypeInfo) -> bool {
-        matches!(ty.0, DataType::Date | DataType::Text)
+        matches!(ty.0, DataType::Date | DataType::Text | DataType::Float)
     }
 }
 
@@ -177,10 +177,34 @@ impl<'r> Decode<'r, Sqlite> for NaiveDateTime {
 
 impl<'r> Decode<'r, Sqlite> for NaiveDate {
     fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
-        Ok(NaiveDate::parse_from_str(value.text()?, "%F")?)
+        match value.type_info().0 {
+            DataType::Float => {
+                let value = value.double();
+
+                decode_date_from_julian_day(value)
+                    .ok_or_else(|| format!("invalid julian day: {value}").into())
+            }
+            _ => Ok(NaiveDate::parse_from_str(value.text()?, "%F")?),
+        }
     }
 }
 
+/// Convert a (possibly fractional) Julian day, as returned from the `julianday()` function,
+/// into the calendar date it falls on.
+fn decode_date_from_julian_day(value: f64) -> Option<NaiveDate> {
+    // Julian days start at noon, so e.g. 2000-01-01 spans [2451544.5, 2451545.5)
+    let julian_day_number = (value + 0.5).floor();
+
+    if !julian_day_number.is_finite() {
+        return None;
+    }
+
+    // 0001-01-01 (day 1 of the Common Era) is Julian day number 1721426
+    let days_from_ce = i32::try_from(julian_day_number as i64 - 1_721_425).ok()?;
+
+    NaiveDate::from_num_days_from_ce_opt(days_from_ce)
+}
+
 impl<'r> Decode<'r, Sqlite> for NaiveTime {
     fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
         let value = value.text()?;
@@ -0,0 +1,52 @@
+//! Shared `TEXT` storage for the `bigdecimal` and `rust_decimal` types.
+
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::encode::IsNull;
+use crate::error::BoxDynError;
+use crate::type_info::DataType;
+use crate::value::ValueRef;
+use crate::{SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef};
+
+pub(crate) fn type_info() -> SqliteTypeInfo {
+    SqliteTypeInfo(DataType::Text)
+}
+
+pub(crate) fn compatible(ty: &SqliteTypeInfo) -> bool {
+    matches!(
+        ty.0,
+        DataType::Text | DataType::Int | DataType::Int64 | DataType::Float | DataType::Numeric
+    )
+}
+
+pub(crate) fn encode<'q>(value: &impl Display, args: &mut Vec<SqliteArgumentValue<'q>>) -> IsNull {
+    args.push(SqliteArgumentValue::Text(Cow::Owned(value.to_string())));
+
+    IsNull::No
+}
+
+pub(crate) fn decode<T>(value: SqliteValueRef<'_>) -> Result<T, BoxDynError>
+where
+    T: FromStr + From<i64>,
+    T::Err: Display,
+{
+    match value.type_info().0 {
+        DataType::Int | DataType::Int64 => Ok(T::from(value.int64())),
+        // e.g. the result of arithmetic; goes through the shortest representation that
+        // round-trips the `f64` so `0.1` does not decode as `0.1000000000000000055511151231...`
+        DataType::Float => parse(&value.double().to_string()),
+        _ => parse(value.text()?),
+    }
+}
+
+fn parse<T>(s: &str) -> Result<T, BoxDynError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    s.trim()
+        .parse()
+        .map_err(|e| format!("invalid decimal value {s:?}: {e}").into())
+}
@@ -41,7 +41,7 @@
 //! | `chrono::DateTime<Utc>`               | DATETIME (TEXT, INTEGER, REAL)                       |
 //! | `chrono::DateTime<Local>`             | DATETIME (TEXT, INTEGER, REAL)                       |
 //! | `chrono::DateTime<FixedOffset>`       | DATETIME (TEXT, INTEGER, REAL)                       |
-//! | `chrono::NaiveDate`                   | DATE (TEXT only)                                     |
+//! | `chrono::NaiveDate`                   | DATE (TEXT, REAL)                                    |
 //! | `chrono::NaiveTime`                   | TIME (TEXT only)                                     |
 //!
 //! ##### NOTE: `DATETIME` conversions
@@ -62,6 +62,9 @@
 //! These types will always encode to a datetime string, either
 //! with (`DateTime<Tz>` for any `Tz: TimeZone`) or without (`NaiveDateTime`) a timezone offset.
 //!
+//! `NaiveDate` always encodes to a `YYYY-MM-DD` string, and decodes from either that or a `REAL`
+//! Julian day as returned from `julianday()`, taking the date that the instant falls on.
+//!
 //! ### [`time`](https://crates.io/crates/time)
 //!
 //! Requires the `time` Cargo feature flag.
@@ -87,6 +90,11 @@
 //! | `uuid::fmt::Hyphenated`               | TEXT                                                 |
 //! | `uuid::fmt::Simple`                   | TEXT                                                 |
 //!
+//! `Uuid` is stored as a 16-byte BLOB, which is less than half the size of its text form.
+//! It can still be decoded from TEXT columns holding any format accepted by
+//! `Uuid::parse_str()`, so existing data keeps working. To store UUIDs as text instead,
+//! bind them as `Hyphenated` or `Simple`.
+//!
 //! ### [`json`](https://crates.io/crates/serde_json)
 //!
 //! Requires the `json` Cargo feature flag.
@@ -97,16 +105,27 @@
 //! | `serde_json::JsonValue`               | TEXT                                                 |
 //! | `&serde_json::value::RawValue`        | TEXT                                                 |
 //!
-//! # Nullable
+//! ### [`bigdecimal`](https://crates.io/crates/bigdecimal)
 //!
-//! In addition, `Option<T>` is supported where `T` implements `Type`. An `Option<T>` represents
-//! a potentially `NULL` value from SQLite.
+//! Requires the `bigdecimal` Cargo feature flag.
+//!
+//! | Rust type                             | Sqlite type(s)                                       |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `bigdecimal::BigDecimal`              | TEXT (INTEGER, REAL)                                 |
+//!
+//! ### [`rust_decimal`](https://crates.io/crates/rust_decimal)
 //!
-//! # Non-feature: `NUMERIC` / `rust_decimal` / `bigdecimal` Support
-//! Support for mapping `rust_decimal::Decimal` and `bigdecimal::BigDecimal` to SQLite has been
-//! deliberately omitted because SQLite does not have native support for high-
-//! or arbitrary-precision decimal arithmetic, and to pretend so otherwise would be a
-//! significant misstep in API design.
+//! Requires the `rust_decimal` Cargo feature flag.
+//!
+//! | Rust type                             | Sqlite type(s)                                       |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `rust_decimal::Decimal`               | TEXT (INTEGER, REAL)                                 |
+//!
+//! ##### NOTE: decimal storage
+//! SQLite does not have native support for high- or arbitrary-precision decimal arithmetic,
+//! so these types are always encoded as `TEXT`. Decoding accepts `TEXT`, as well as `INTEGER`
+//! and `REAL` values such as those produced by arithmetic in SQL (which SQLite performs in
+//! floating point). Text which is not a valid decimal number is a decoding error.
 //!
 //! The in-tree [`decimal.c`] extension is unfortunately not included in the [amalgamation],
 //! which is used to build the bundled version of SQLite3 for `libsqlite3-sys` (which we have
@@ -141,15 +160,19 @@
 //! Thus, the `NUMERIC` type affinity is **unsuitable** for storage of high-precision decimal values
 //! and should be **avoided at all costs**.
 //!
-//! Support for `rust_decimal` and `bigdecimal` would only be a trap because users will naturally
-//! want to use the `NUMERIC` type affinity, and might otherwise encounter serious bugs caused by
-//! rounding errors that they were deliberately avoiding when they chose an arbitrary-precision type
-//! over a floating-point type in the first place.
+//! Support for `rust_decimal` and `bigdecimal` would be a trap if it followed the column's
+//! affinity, because users will naturally want to use the `NUMERIC` type affinity, and might
+//! otherwise encounter serious bugs caused by rounding errors that they were deliberately avoiding
+//! when they chose an arbitrary-precision type over a floating-point type in the first place.
 //!
 //! Instead, you should only use a type affinity that SQLite will not attempt to convert implicitly,
-//! such as `TEXT` or `BLOB`, and map values to/from SQLite as strings. You can do this easily
-//! using [the `Text` adapter].
+//! such as `TEXT` or `BLOB`, and map values to/from SQLite as strings. The impls above do exactly
+//! that; for other decimal types, you can do this easily using [the `Text` adapter].
 //!
+//! # Nullable
+//!
+//! In addition, `Option<T>` is supported where `T` implements `Type`. An `Option<T>` represents
+//! a potentially `NULL` value from SQLite.
 //!
 //! [`decimal.c`]: https://www.sqlite.org/floatingpoint.html#the_decimal_c_extension
 //! [amalgamation]: https://www.sqlite.org/amalgamation.html
@@ -158,14 +181,20 @@
 
 pub(crate) use sqlx_core::types::*;
 
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal;
 mod bool;
 mod bytes;
 #[cfg(feature = "chrono")]
 mod chrono;
+#[cfg(any(feature = "bigdecimal", feature = "rust_decimal"))]
+mod decimal;
 mod float;
 mod int;
 #[cfg(feature = "json")]
 mod json;
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal;
 mod str;
 mod text;
 #[cfg(feature = "time")]
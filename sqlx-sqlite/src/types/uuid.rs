@@ -3,6 +3,7 @@ use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
 use crate::type_info::DataType;
 use crate::types::Type;
+use crate::value::ValueRef;
 use crate::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef};
 use std::borrow::Cow;
 use uuid::{
@@ -32,8 +33,21 @@ impl<'q> Encode<'q, Sqlite> for Uuid {
 
 impl Decode<'_, Sqlite> for Uuid {
     fn decode(value: SqliteValueRef<'_>) -> Result<Self, BoxDynError> {
+        // accept UUIDs stored as text too, e.g. by `Hyphenated` or by hand
+        if let DataType::Text = value.type_info().0 {
+            return Uuid::parse_str(value.text()?).map_err(Into::into);
+        }
+
         // construct a Uuid from the returned bytes
-        Uuid::from_slice(value.blob()).map_err(Into::into)
+        let bytes = value.blob();
+
+        Uuid::from_slice(bytes).map_err(|_| {
+            format!(
+                "expected a 16-byte BLOB for a UUID, got {} bytes",
+                bytes.len()
+            )
+            .into()
+        })
     }
 }
 
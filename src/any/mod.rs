@@ -7,7 +7,8 @@ pub use sqlx_core::any::driver::install_drivers;
 
 pub use sqlx_core::any::{
     Any, AnyArguments, AnyConnectOptions, AnyExecutor, AnyKind, AnyPoolOptions, AnyQueryResult,
-    AnyRow, AnyStatement, AnyTransactionManager, AnyTypeInfo, AnyValue, AnyValueRef,
+    AnyRow, AnyStatement, AnyTransactionManager, AnyTypeInfo, AnyTypeInfoKind, AnyValue,
+    AnyValueRef,
 };
 
 pub(crate) mod reexports {
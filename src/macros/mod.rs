@@ -110,6 +110,25 @@
 /// * Postgres: `$N` where `N` is the 1-based positional argument index
 /// * MySQL/SQLite: `?` which matches arguments in order that it appears in the query
 ///
+/// ### Named Arguments
+/// Arguments may instead be given as `name = expr` and referenced in the query as `:name`,
+/// which is rewritten to the placeholder syntax of the database at compile time:
+///
+/// ```rust,ignore
+/// let accounts = sqlx::query!(
+///         "select * from accounts where owner_id = :user_id or created_by = :user_id",
+///         user_id = user.id,
+///     )
+///     .fetch_all(&mut conn)
+///     .await?;
+/// ```
+///
+/// Every named parameter in the query must be given, and every argument must be used.
+/// Named and positional arguments cannot be mixed in the same invocation.
+///
+/// On Postgres and SQLite a name used more than once is bound only once. MySQL has no numbered
+/// placeholders, so there the expression is evaluated once but its value is bound for each use.
+///
 /// ## Nullability: Bind Parameters
 /// For a given expected type `T`, both `T` and `Option<T>` are allowed (as well as either
 /// behind references). `Option::None` will be bound as `NULL`, so if binding a type behind `Option`
@@ -305,6 +324,25 @@
 ///
 /// See [the README for `sqlx-cli`](https://crates.io/crates/sqlx-cli) for more information.
 ///
+/// ## Multiple Databases
+/// By default, queries are checked against the database at `DATABASE_URL`. If a project talks to
+/// more than one database, any of the query macros can instead be pointed at a different
+/// environment variable by passing `db = "VAR_NAME"` as the first argument:
+///
+/// ```rust,ignore
+/// let events = sqlx::query!(
+///         db = "ANALYTICS_DATABASE_URL",
+///         "select kind, count(*) from events group by kind"
+///     )
+///     .fetch_all(&analytics_pool)
+///     .await?;
+/// ```
+///
+/// The variable is read the same way as `DATABASE_URL`, including from `.env`. In offline mode,
+/// the cached data for such queries is stored as `.sqlx/query-VAR_NAME-<hash>.json`, so the same
+/// query may be cached for more than one database. `cargo sqlx prepare` picks these up like any
+/// other query, as long as each variable is set when it runs.
+///
 /// ## See Also
 /// * [query_as!] if you want to use a struct you can name,
 /// * [query_file!] if you want to define the SQL query out-of-line,
@@ -312,6 +350,12 @@
 #[macro_export]
 #[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
 macro_rules! query (
+    (db = $db:literal, $query:expr) => ({
+        $crate::sqlx_macros::expand_query!(db = $db, source = $query)
+    });
+    (db = $db:literal, $query:expr, $($args:tt)*) => ({
+        $crate::sqlx_macros::expand_query!(db = $db, source = $query, args = [$($args)*])
+    });
     // in Rust 1.45 we can now invoke proc macros in expression position
     ($query:expr) => ({
         $crate::sqlx_macros::expand_query!(source = $query)
@@ -334,6 +378,12 @@ macro_rules! query (
 #[macro_export]
 #[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
 macro_rules! query_unchecked (
+    (db = $db:literal, $query:expr) => ({
+        $crate::sqlx_macros::expand_query!(db = $db, source = $query, checked = false)
+    });
+    (db = $db:literal, $query:expr, $($args:tt)*) => ({
+        $crate::sqlx_macros::expand_query!(db = $db, source = $query, args = [$($args)*], checked = false)
+    });
     ($query:expr) => ({
         $crate::sqlx_macros::expand_query!(source = $query, checked = false)
     });
@@ -387,6 +437,12 @@ macro_rules! query_unchecked (
 #[macro_export]
 #[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
 macro_rules! query_file (
+    (db = $db:literal, $path:literal) => ({
+        $crate::sqlx_macros::expand_query!(db = $db, source_file = $path)
+    });
+    (db = $db:literal, $path:literal, $($args:tt)*) => ({
+        $crate::sqlx_macros::expand_query!(db = $db, source_file = $path, args = [$($args)*])
+    });
     ($path:literal) => ({
         $crate::sqlx_macros::expand_query!(source_file = $path)
     });
@@ -400,6 +456,12 @@ macro_rules! query_file (
 #[macro_export]
 #[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
 macro_rules! query_file_unchecked (
+    (db = $db:literal, $path:literal) => ({
+        $crate::sqlx_macros::expand_query!(db = $db, source_file = $path, checked = false)
+    });
+    (db = $db:literal, $path:literal, $($args:tt)*) => ({
+        $crate::sqlx_macros::expand_query!(db = $db, source_file = $path, args = [$($args)*], checked = false)
+    });
     ($path:literal) => ({
         $crate::sqlx_macros::expand_query!(source_file = $path, checked = false)
     });
@@ -565,6 +627,12 @@ macro_rules! query_file_unchecked (
 #[macro_export]
 #[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
 macro_rules! query_as (
+    (db = $db:literal, $out_struct:path, $query:expr) => ( {
+        $crate::sqlx_macros::expand_query!(db = $db, record = $out_struct, source = $query)
+    });
+    (db = $db:literal, $out_struct:path, $query:expr, $($args:tt)*) => ( {
+        $crate::sqlx_macros::expand_query!(db = $db, record = $out_struct, source = $query, args = [$($args)*])
+    });
     ($out_struct:path, $query:expr) => ( {
         $crate::sqlx_macros::expand_query!(record = $out_struct, source = $query)
     });
@@ -609,6 +677,12 @@ macro_rules! query_as (
 #[macro_export]
 #[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
 macro_rules! query_file_as (
+    (db = $db:literal, $out_struct:path, $path:literal) => ( {
+        $crate::sqlx_macros::expand_query!(db = $db, record = $out_struct, source_file = $path)
+    });
+    (db = $db:literal, $out_struct:path, $path:literal, $($args:tt)*) => ( {
+        $crate::sqlx_macros::expand_query!(db = $db, record = $out_struct, source_file = $path, args = [$($args)*])
+    });
     ($out_struct:path, $path:literal) => ( {
         $crate::sqlx_macros::expand_query!(record = $out_struct, source_file = $path)
     });
@@ -622,6 +696,12 @@ macro_rules! query_file_as (
 #[macro_export]
 #[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
 macro_rules! query_as_unchecked (
+    (db = $db:literal, $out_struct:path, $query:expr) => ( {
+        $crate::sqlx_macros::expand_query!(db = $db, record = $out_struct, source = $query, checked = false)
+    });
+    (db = $db:literal, $out_struct:path, $query:expr, $($args:tt)*) => ( {
+        $crate::sqlx_macros::expand_query!(db = $db, record = $out_struct, source = $query, args = [$($args)*], checked = false)
+    });
     ($out_struct:path, $query:expr) => ( {
         $crate::sqlx_macros::expand_query!(record = $out_struct, source = $query, checked = false)
     });
@@ -637,6 +717,12 @@ macro_rules! query_as_unchecked (
 #[macro_export]
 #[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
 macro_rules! query_file_as_unchecked (
+    (db = $db:literal, $out_struct:path, $path:literal) => ( {
+        $crate::sqlx_macros::expand_query!(db = $db, record = $out_struct, source_file = $path, checked = false)
+    });
+    (db = $db:literal, $out_struct:path, $path:literal, $($args:tt)*) => ( {
+        $crate::sqlx_macros::expand_query!(db = $db, record = $out_struct, source_file = $path, args = [$($args)*], checked = false)
+    });
     ($out_struct:path, $path:literal) => ( {
         $crate::sqlx_macros::expand_query!(record = $out_struct, source_file = $path, checked = false)
     });
@@ -659,10 +745,26 @@ macro_rules! query_file_as_unchecked (
 /// Wildcard overrides like in [query_as!] are also allowed, in which case the output type
 /// is left up to inference.
 ///
+/// ### Fetching a Value That May Not Exist
+/// The output type follows the nullability of the column, as described for [query!]:
+///
+/// * If the column is known to be `NOT NULL` (or is overridden with `!`), the output type is `T`
+///   and `.fetch_optional()` returns `Option<T>`, which is `None` only if no row was returned.
+/// * Otherwise the output type is `Option<T>`, and `.fetch_optional()` returns
+///   `Option<Option<T>>` so that "no row" and "`NULL`" can be told apart.
+///   [`.fetch_optional_flatten()`][crate::query::QueryScalar::fetch_optional_flatten] instead
+///   returns `Option<T>`, which is `None` in either case.
+///
 /// See [query!] for more information.
 #[macro_export]
 #[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
 macro_rules! query_scalar (
+    (db = $db:literal, $query:expr) => (
+        $crate::sqlx_macros::expand_query!(db = $db, scalar = _, source = $query)
+    );
+    (db = $db:literal, $query:expr, $($args:tt)*) => (
+        $crate::sqlx_macros::expand_query!(db = $db, scalar = _, source = $query, args = [$($args)*])
+    );
     ($query:expr) => (
         $crate::sqlx_macros::expand_query!(scalar = _, source = $query)
     );
@@ -671,10 +773,63 @@ macro_rules! query_scalar (
     )
 );
 
+/// Define a struct for the output columns of a query, so it does not have to be kept in sync
+/// with the query by hand.
+///
+/// The struct has one field per column, named and typed the same way as the fields of the
+/// anonymous record returned by [query!], including any type and nullability overrides.
+/// Fields have the same visibility as the struct. Wildcard overrides are not allowed.
+///
+/// This macro is used in item position, and emits:
+///
+/// * the struct itself, deriving `Debug`;
+/// * an associated `QUERY` constant holding the SQL;
+/// * an implementation of [`FromRow`][crate::FromRow] for the struct.
+///
+/// ```rust,ignore
+/// sqlx::query_struct!(pub Account, "select id, name, is_active from accounts where id = ?");
+///
+/// let account = sqlx::query_as::<_, Account>(Account::QUERY)
+///     .bind(1i32)
+///     .fetch_one(&mut conn)
+///     .await?;
+///
+/// // or, with checked bind parameters:
+/// let account = sqlx::query_as!(
+///         Account,
+///         "select id, name, is_active from accounts where id = ?",
+///         1i32
+///     )
+///     .fetch_one(&mut conn)
+///     .await?;
+/// ```
+///
+/// The query may have bind parameters, but their values are not given here. Like any other item,
+/// the struct name must not already be in use in the same scope.
+///
+/// Like the other query macros, this accepts `db = "VAR_NAME"` as the first argument; see
+/// [query!] for details.
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+macro_rules! query_struct (
+    (db = $db:literal, $vis:vis $name:ident, $query:expr $(,)?) => {
+        $crate::sqlx_macros::expand_query! { db = $db, define = $vis $name, source = $query }
+    };
+    ($vis:vis $name:ident, $query:expr $(,)?) => {
+        $crate::sqlx_macros::expand_query! { define = $vis $name, source = $query }
+    };
+);
+
 /// A variant of [query_scalar!] which takes a file path like [query_file!].
 #[macro_export]
 #[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
 macro_rules! query_file_scalar (
+    (db = $db:literal, $path:literal) => (
+        $crate::sqlx_macros::expand_query!(db = $db, scalar = _, source_file = $path)
+    );
+    (db = $db:literal, $path:literal, $($args:tt)*) => (
+        $crate::sqlx_macros::expand_query!(db = $db, scalar = _, source_file = $path, args = [$($args)*])
+    );
     ($path:literal) => (
         $crate::sqlx_macros::expand_query!(scalar = _, source_file = $path)
     );
@@ -692,6 +847,12 @@ macro_rules! query_file_scalar (
 #[macro_export]
 #[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
 macro_rules! query_scalar_unchecked (
+    (db = $db:literal, $query:expr) => (
+        $crate::sqlx_macros::expand_query!(db = $db, scalar = _, source = $query, checked = false)
+    );
+    (db = $db:literal, $query:expr, $($args:tt)*) => (
+        $crate::sqlx_macros::expand_query!(db = $db, scalar = _, source = $query, args = [$($args)*], checked = false)
+    );
     ($query:expr) => (
         $crate::sqlx_macros::expand_query!(scalar = _, source = $query, checked = false)
     );
@@ -709,6 +870,12 @@ macro_rules! query_scalar_unchecked (
 #[macro_export]
 #[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
 macro_rules! query_file_scalar_unchecked (
+    (db = $db:literal, $path:literal) => (
+        $crate::sqlx_macros::expand_query!(db = $db, scalar = _, source_file = $path, checked = false)
+    );
+    (db = $db:literal, $path:literal, $($args:tt)*) => (
+        $crate::sqlx_macros::expand_query!(db = $db, scalar = _, source_file = $path, args = [$($args)*], checked = false)
+    );
     ($path:literal) => (
         $crate::sqlx_macros::expand_query!(scalar = _, source_file = $path, checked = false)
     );
@@ -141,3 +141,187 @@ async fn it_can_fail_and_recover_with_pool() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_can_work_with_transactions() -> anyhow::Result<()> {
+    use sqlx::{IsolationLevel, TransactionOptions};
+
+    sqlx::any::install_default_drivers();
+
+    let mut conn = new::<Any>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE any_transactions (id INTEGER)")
+        .await?;
+
+    async fn count(conn: &mut sqlx::AnyConnection) -> sqlx::Result<i64> {
+        sqlx::query_scalar("SELECT COUNT(*) FROM any_transactions")
+            .fetch_one(conn)
+            .await
+    }
+
+    // a rolled back savepoint inside a committed transaction
+    let mut tx = conn.begin().await?;
+    tx.execute("INSERT INTO any_transactions (id) VALUES (1)")
+        .await?;
+
+    let mut savepoint = tx.begin().await?;
+    savepoint
+        .execute("INSERT INTO any_transactions (id) VALUES (2)")
+        .await?;
+    savepoint.rollback().await?;
+
+    // options can't be applied to a savepoint on any database
+    let options = TransactionOptions {
+        isolation: Some(IsolationLevel::Serializable),
+        ..Default::default()
+    };
+    assert!(tx.begin_with(options).await.is_err());
+
+    tx.commit().await?;
+    assert_eq!(count(&mut conn).await?, 1);
+
+    // a rolled back transaction
+    let mut tx = conn.begin().await?;
+    tx.execute("INSERT INTO any_transactions (id) VALUES (3)")
+        .await?;
+    tx.rollback().await?;
+    assert_eq!(count(&mut conn).await?, 1);
+
+    // a dropped transaction is rolled back
+    {
+        let mut tx = conn.begin().await?;
+        tx.execute("INSERT INTO any_transactions (id) VALUES (4)")
+            .await?;
+    }
+    assert_eq!(count(&mut conn).await?, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_connects_from_driver_options() -> anyhow::Result<()> {
+    use sqlx::any::AnyConnectOptions;
+    use sqlx::ConnectOptions;
+
+    sqlx::any::install_default_drivers();
+
+    let url = dotenvy::var("DATABASE_URL")?;
+
+    let options = match url.split(':').next() {
+        #[cfg(feature = "postgres")]
+        Some("postgres" | "postgresql") => {
+            let options: sqlx::postgres::PgConnectOptions = url.parse()?;
+            let options = AnyConnectOptions::from_options(options);
+            assert!(options
+                .downcast_ref::<sqlx::postgres::PgConnectOptions>()
+                .is_some());
+            options
+        }
+        #[cfg(feature = "mysql")]
+        Some("mysql" | "mariadb") => {
+            let options: sqlx::mysql::MySqlConnectOptions = url.parse()?;
+            let options = AnyConnectOptions::from_options(options);
+            assert!(options
+                .downcast_ref::<sqlx::mysql::MySqlConnectOptions>()
+                .is_some());
+            options
+        }
+        #[cfg(feature = "sqlite")]
+        Some("sqlite") => {
+            let options: sqlx::sqlite::SqliteConnectOptions = url.parse()?;
+            let options = AnyConnectOptions::from_options(options);
+            assert!(options
+                .downcast_ref::<sqlx::sqlite::SqliteConnectOptions>()
+                .is_some());
+            options
+        }
+        _ => return Ok(()),
+    };
+
+    let mut conn = options.connect().await?;
+
+    let value: i32 = sqlx::query_scalar("select 1 + 5")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(6, value);
+
+    conn.close().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_reports_portable_column_kinds() -> anyhow::Result<()> {
+    use sqlx::any::AnyTypeInfoKind;
+    use sqlx::{Column, TypeInfo};
+
+    sqlx::any::install_default_drivers();
+
+    let mut conn = new::<Any>().await?;
+
+    // SQLite only knows the type of a column selected from a table
+    conn.execute("CREATE TEMPORARY TABLE any_column_kinds (id INTEGER, name VARCHAR(255))")
+        .await?;
+    conn.execute("INSERT INTO any_column_kinds (id, name) VALUES (1, 'hello')")
+        .await?;
+
+    let row = sqlx::query("SELECT id, name FROM any_column_kinds")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let int = row.column(0).type_info();
+    assert!(int.kind_ref().is_integer(), "{int:?}");
+
+    let text = row.column(1).type_info();
+    assert_eq!(text.kind(), AnyTypeInfoKind::Text);
+    assert_eq!(text.name(), "TEXT");
+
+    conn.close().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_binds_typed_nulls() -> anyhow::Result<()> {
+    use sqlx::any::{AnyTypeInfoKind, AnyValue};
+
+    sqlx::any::install_default_drivers();
+
+    let mut conn = new::<Any>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE any_typed_nulls (name TEXT)")
+        .await?;
+
+    let insert = if conn.backend_name() == "PostgreSQL" {
+        "INSERT INTO any_typed_nulls (name) VALUES ($1)"
+    } else {
+        "INSERT INTO any_typed_nulls (name) VALUES (?)"
+    };
+
+    sqlx::query(insert)
+        .bind(Option::<String>::None)
+        .execute(&mut conn)
+        .await?;
+
+    sqlx::query(insert)
+        .bind(AnyValue::null_of(AnyTypeInfoKind::Text))
+        .execute(&mut conn)
+        .await?;
+
+    // left for the database to infer
+    sqlx::query(insert)
+        .bind(AnyValue::null_of(AnyTypeInfoKind::Null))
+        .execute(&mut conn)
+        .await?;
+
+    let nulls: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM any_typed_nulls WHERE name IS NULL")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(nulls, 3);
+
+    conn.close().await?;
+
+    Ok(())
+}
@@ -278,3 +278,225 @@ async fn test_connection_maintenance() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn test_acquire_many() -> anyhow::Result<()> {
+    sqlx::any::install_default_drivers();
+
+    let pool = AnyPoolOptions::new()
+        .max_connections(4)
+        .acquire_timeout(Duration::from_secs(1))
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    let conns = pool.acquire_many(3).await?;
+    assert_eq!(conns.len(), 3);
+
+    // Only one connection is left, so this has to wait until it times out
+    // instead of taking the last connection and waiting for the rest.
+    assert!(matches!(
+        pool.acquire_many(2).await,
+        Err(sqlx::Error::PoolTimedOut)
+    ));
+
+    let _conn = pool.acquire().await?;
+
+    drop(conns);
+    assert_eq!(pool.acquire_many(3).await?.len(), 3);
+
+    assert!(matches!(
+        pool.acquire_many(5).await,
+        Err(sqlx::Error::Configuration(_))
+    ));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_before_acquire_idle_for() -> anyhow::Result<()> {
+    sqlx::any::install_default_drivers();
+
+    let idle_for = Arc::new(Mutex::new(None));
+    let idle_for_ = idle_for.clone();
+    let pool = AnyPoolOptions::new()
+        .max_connections(1)
+        .before_acquire(move |_conn, meta| {
+            *idle_for_.lock().unwrap() = Some(meta.idle_for);
+            Box::pin(async { Ok(true) })
+        })
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    let mut conn = pool.acquire().await?;
+    conn.return_to_pool().await;
+    drop(conn);
+
+    sqlx_core::rt::sleep(Duration::from_millis(150)).await;
+
+    let _conn = pool.acquire().await?;
+    let idle_for = idle_for
+        .lock()
+        .unwrap()
+        .take()
+        .expect("expected a connection from the pool");
+    assert!(
+        idle_for >= Duration::from_millis(150),
+        "expected the connection to have been idle (idle_for {idle_for:?})"
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_acquire_with_priority() -> anyhow::Result<()> {
+    use sqlx::pool::AcquirePriority;
+
+    sqlx::any::install_default_drivers();
+
+    let pool = AnyPoolOptions::new()
+        .max_connections(1)
+        .acquire_timeout(Duration::from_secs(5))
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    let conn = pool.acquire().await?;
+
+    let order = Arc::new(Mutex::new(Vec::new()));
+
+    let mut tasks = Vec::new();
+    for priority in [AcquirePriority::Low, AcquirePriority::High] {
+        let pool = pool.clone();
+        let order = order.clone();
+        tasks.push(sqlx_core::rt::spawn(async move {
+            let conn = pool.acquire_with_priority(priority).await?;
+            order.lock().unwrap().push(priority);
+            conn.close().await?;
+            anyhow::Ok(())
+        }));
+
+        // Make sure the low priority task starts waiting first.
+        sqlx_core::rt::sleep(Duration::from_millis(50)).await;
+    }
+
+    drop(conn);
+
+    for task in tasks {
+        task.await?;
+    }
+
+    assert_eq!(
+        *order.lock().unwrap(),
+        [AcquirePriority::High, AcquirePriority::Low]
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_acquire_with_priority_keeps_queue_position() -> anyhow::Result<()> {
+    use sqlx::pool::AcquirePriority;
+
+    sqlx::any::install_default_drivers();
+
+    let pool = AnyPoolOptions::new()
+        .max_connections(1)
+        .acquire_timeout(Duration::from_secs(5))
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    let conn = pool.acquire().await?;
+
+    let order = Arc::new(Mutex::new(Vec::new()));
+
+    let mut tasks = Vec::new();
+    for (task, priority) in [
+        (0, AcquirePriority::Normal),
+        (1, AcquirePriority::High),
+        (2, AcquirePriority::Normal),
+    ] {
+        let pool = pool.clone();
+        let order = order.clone();
+        tasks.push(sqlx_core::rt::spawn(async move {
+            let conn = pool.acquire_with_priority(priority).await?;
+            order.lock().unwrap().push(task);
+            conn.close().await?;
+            anyhow::Ok(())
+        }));
+
+        // Make sure the tasks start waiting in order.
+        sqlx_core::rt::sleep(Duration::from_millis(50)).await;
+    }
+
+    drop(conn);
+
+    for task in tasks {
+        task.await?;
+    }
+
+    // The first task yields to the high priority task, but stays ahead of the last one.
+    assert_eq!(*order.lock().unwrap(), [1, 0, 2]);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_close_timeout() -> anyhow::Result<()> {
+    sqlx::any::install_default_drivers();
+
+    let pool = AnyPoolOptions::new()
+        .min_connections(2)
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    let conn = pool.acquire().await?;
+
+    // The checked-out connection would make `.close()` wait forever.
+    let dropped = sqlx_core::rt::timeout(
+        Duration::from_secs(5),
+        pool.close_timeout(Duration::from_millis(100)),
+    )
+    .await?;
+
+    // The idle connection is closed gracefully and the checked-out one can't be dropped.
+    assert_eq!(dropped, 0);
+    assert!(pool.is_closed());
+    assert_eq!(pool.size(), 1);
+
+    drop(conn);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_max_acquire_queue() -> anyhow::Result<()> {
+    sqlx::any::install_default_drivers();
+
+    let pool = AnyPoolOptions::new()
+        .max_connections(1)
+        .max_acquire_queue(1)
+        .acquire_timeout(Duration::from_secs(5))
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    let conn = pool.acquire().await?;
+
+    let waiting = sqlx_core::rt::spawn({
+        let pool = pool.clone();
+        async move { pool.acquire().await.map(drop) }
+    });
+
+    // Make sure the spawned task starts waiting.
+    sqlx_core::rt::sleep(Duration::from_millis(50)).await;
+
+    assert!(matches!(
+        pool.acquire().await,
+        Err(sqlx::Error::PoolQueueFull)
+    ));
+
+    drop(conn);
+    waiting.await?;
+
+    let _conn = pool.acquire().await?;
+
+    Ok(())
+}
@@ -18,6 +18,7 @@ async fn it_fails_with_unique_violation() -> anyhow::Result<()> {
     let err = err.into_database_error().unwrap();
 
     assert_eq!(err.kind(), ErrorKind::UniqueViolation);
+    assert_eq!(err.constraint(), Some("PRIMARY"));
 
     Ok(())
 }
@@ -36,6 +37,9 @@ async fn it_fails_with_foreign_key_violation() -> anyhow::Result<()> {
     let err = err.into_database_error().unwrap();
 
     assert_eq!(err.kind(), ErrorKind::ForeignKeyViolation);
+    assert_eq!(err.constraint(), Some("tweet_id_fk"));
+    assert_eq!(err.table(), Some("tweet_reply"));
+    assert_eq!(err.column(), Some("tweet_id"));
 
     Ok(())
 }
@@ -53,6 +57,7 @@ async fn it_fails_with_not_null_violation() -> anyhow::Result<()> {
     let err = err.into_database_error().unwrap();
 
     assert_eq!(err.kind(), ErrorKind::NotNullViolation);
+    assert_eq!(err.column(), Some("text"));
 
     Ok(())
 }
@@ -1,5 +1,7 @@
 use futures::TryStreamExt;
-use sqlx::mysql::{MySql, MySqlConnection, MySqlPool, MySqlPoolOptions, MySqlRow};
+use sqlx::mysql::{
+    MySql, MySqlConnectOptions, MySqlConnection, MySqlPool, MySqlPoolOptions, MySqlRow,
+};
 use sqlx::{Column, Connection, Executor, Row, Statement, TypeInfo};
 use sqlx_test::{new, setup_if_needed};
 use std::env;
@@ -77,11 +79,13 @@ CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY);
 
 #[sqlx_macros::test]
 async fn it_executes_with_pool() -> anyhow::Result<()> {
+    let options: MySqlConnectOptions = dotenvy::var("DATABASE_URL")?.parse()?;
+
     let pool: MySqlPool = MySqlPoolOptions::new()
         .min_connections(2)
         .max_connections(2)
         .test_before_acquire(false)
-        .connect(&dotenvy::var("DATABASE_URL")?)
+        .connect_with(options.multi_statements(true))
         .await?;
 
     let rows = pool.fetch_all("SELECT 1; SELECT 2").await?;
@@ -98,6 +102,88 @@ async fn it_executes_with_pool() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_can_enable_multi_statements() -> anyhow::Result<()> {
+    setup_if_needed();
+
+    let options: MySqlConnectOptions = env::var("DATABASE_URL")?.parse()?;
+
+    let mut conn = MySqlConnection::connect_with(&options).await?;
+
+    assert!(conn.execute("DO 1; DO 2").await.is_err());
+
+    // the connection is still usable
+    let row = conn.fetch_one("SELECT 1").await?;
+    assert_eq!(row.try_get::<i64, _>(0)?, 1);
+
+    let mut conn = MySqlConnection::connect_with(&options.multi_statements(true)).await?;
+
+    let results: Vec<_> = conn
+        .execute_many("DO 1; DO 2; SELECT 3")
+        .try_collect()
+        .await?;
+
+    assert_eq!(results.len(), 3);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_call_a_procedure_with_multiple_result_sets() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute("DROP PROCEDURE IF EXISTS _sqlx_multiple_result_sets")
+        .await?;
+
+    conn.execute(
+        r#"
+CREATE PROCEDURE _sqlx_multiple_result_sets(IN n INT)
+BEGIN
+    SELECT n AS first;
+    SELECT n + 1 AS second, n + 2 AS third;
+    SELECT n + 3 AS fourth, n + 4 AS fifth;
+END
+    "#,
+    )
+    .await?;
+
+    // prepared, the result sets are only described once they arrive
+    let rows = sqlx::query("CALL _sqlx_multiple_result_sets(?)")
+        .bind(1_i32)
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows[0].try_get::<i64, _>("first")?, 1);
+    assert_eq!(rows[1].try_get::<i64, _>("second")?, 2);
+    assert_eq!(rows[1].try_get::<i64, _>("third")?, 3);
+
+    // same column count as the previous result set, but different names
+    assert_eq!(rows[2].try_get::<i64, _>("fourth")?, 4);
+    assert_eq!(rows[2].try_get::<i64, _>("fifth")?, 5);
+
+    // each result set is terminated by a result, plus the trailing OK of the `CALL` itself
+    let results: Vec<_> = conn
+        .fetch_many("CALL _sqlx_multiple_result_sets(10)")
+        .try_collect()
+        .await?;
+
+    assert_eq!(results.iter().filter(|r| r.is_left()).count(), 4);
+    assert_eq!(results.iter().filter(|r| r.is_right()).count(), 3);
+
+    // dropping the stream early leaves the connection clean
+    let row = sqlx::query("CALL _sqlx_multiple_result_sets(?)")
+        .bind(20_i32)
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(row.try_get::<i64, _>("first")?, 20);
+
+    let row = conn.fetch_one("SELECT 1").await?;
+    assert_eq!(row.try_get::<i64, _>(0)?, 1);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_works_with_cache_disabled() -> anyhow::Result<()> {
     setup_if_needed();
@@ -356,6 +442,25 @@ INSERT INTO too_many_years ( the ) VALUES ( ? );
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_reports_info_and_warnings() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute("SET SESSION sql_mode = ''").await?;
+    conn.execute("CREATE TEMPORARY TABLE short_names (name VARCHAR(3))")
+        .await?;
+
+    let result = conn
+        .execute("INSERT INTO short_names (name) VALUES ('a'), ('bb'), ('too long')")
+        .await?;
+
+    assert_eq!(result.rows_affected(), 3);
+    assert_eq!(result.warnings(), 1);
+    assert!(result.info().starts_with("Records: 3"));
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_prepare_then_execute() -> anyhow::Result<()> {
     let mut conn = new::<MySql>().await?;
@@ -500,6 +605,56 @@ async fn it_can_work_with_transactions() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_can_work_with_nested_transactions() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+    sqlx::raw_sql("CREATE TEMPORARY TABLE nested_users (id INTEGER PRIMARY KEY);")
+        .execute(&mut conn)
+        .await?;
+
+    async fn count(conn: &mut MySqlConnection) -> sqlx::Result<i64> {
+        sqlx::query_scalar("SELECT COUNT(*) FROM nested_users")
+            .fetch_one(conn)
+            .await
+    }
+
+    let mut tx = conn.begin().await?; // transaction
+    sqlx::query("INSERT INTO nested_users (id) VALUES (1)")
+        .execute(&mut *tx)
+        .await?;
+
+    // rolling back a savepoint keeps the outer transaction
+    let mut savepoint = tx.begin().await?;
+    sqlx::query("INSERT INTO nested_users (id) VALUES (2)")
+        .execute(&mut *savepoint)
+        .await?;
+    assert_eq!(count(&mut *savepoint).await?, 2);
+    savepoint.rollback().await?;
+    assert_eq!(count(&mut *tx).await?, 1);
+
+    // committed savepoints are kept, also when nested
+    let mut savepoint = tx.begin().await?;
+    sqlx::query("INSERT INTO nested_users (id) VALUES (3)")
+        .execute(&mut *savepoint)
+        .await?;
+
+    {
+        let mut inner = savepoint.begin().await?;
+        sqlx::query("INSERT INTO nested_users (id) VALUES (4)")
+            .execute(&mut *inner)
+            .await?;
+        // dropped, so rolled back
+    }
+
+    savepoint.commit().await?;
+    assert_eq!(count(&mut *tx).await?, 2);
+
+    tx.commit().await?;
+    assert_eq!(count(&mut conn).await?, 2);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_handle_split_packets() -> anyhow::Result<()> {
     // This will only take effect on new connections
@@ -25,7 +25,82 @@ test_type!(i16(MySql, "21415" == 21415_i16));
 test_type!(u32(MySql, "CAST(2141512 AS UNSIGNED)" == 2141512_u32));
 test_type!(i32(MySql, "2141512" == 2141512_i32));
 
-test_type!(u64(MySql, "CAST(2141512 AS UNSIGNED)" == 2141512_u64));
+test_type!(u64(
+    MySql,
+    "CAST(2141512 AS UNSIGNED)" == 2141512_u64,
+    "CAST(0 AS UNSIGNED)" == 0_u64,
+    "CAST(9223372036854775808 AS UNSIGNED)" == 9223372036854775808_u64,
+    "CAST(18446744073709551615 AS UNSIGNED)" == u64::MAX
+));
+
+test_type!(u8_max<u8>(MySql, "CAST(255 AS UNSIGNED)" == u8::MAX));
+test_type!(u16_max<u16>(MySql, "CAST(65535 AS UNSIGNED)" == u16::MAX));
+test_type!(u32_max<u32>(MySql, "CAST(4294967295 AS UNSIGNED)" == u32::MAX));
+
+#[sqlx_macros::test]
+async fn test_unsigned_from_signed_column() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE signed_ids (
+    id BIGINT PRIMARY KEY,
+    small TINYINT NOT NULL
+);
+    "#,
+    )
+    .await?;
+
+    sqlx::query("INSERT INTO signed_ids (id, small) VALUES (?, ?), (?, ?)")
+        .bind(i64::MAX)
+        .bind(127_i8)
+        .bind(-1_i64)
+        .bind(-1_i8)
+        .execute(&mut conn)
+        .await?;
+
+    // BINARY
+    let (id, small): (u64, u8) = sqlx::query_as("SELECT id, small FROM signed_ids WHERE id > 0")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(id, i64::MAX as u64);
+    assert_eq!(small, 127);
+
+    // TEXT
+    let row = conn
+        .fetch_one("SELECT id, small FROM signed_ids WHERE id > 0")
+        .await?;
+
+    assert_eq!(row.try_get::<u64, _>(0)?, i64::MAX as u64);
+    assert_eq!(row.try_get::<u8, _>(1)?, 127);
+
+    // negative values are not reinterpreted
+    let err = sqlx::query_scalar::<_, u64>("SELECT id FROM signed_ids WHERE id < 0")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap_err();
+
+    assert!(
+        err.to_string()
+            .contains("out of range for an unsigned integer"),
+        "unexpected error: {err}"
+    );
+
+    // and values too large for the target type are not truncated
+    let err = sqlx::query_scalar::<_, u8>("SELECT CAST(256 AS UNSIGNED)")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap_err();
+
+    assert!(
+        err.to_string()
+            .contains("value 256 is out of range for `u8`"),
+        "unexpected error: {err}"
+    );
+
+    Ok(())
+}
 test_type!(i64(MySql, "2141512" == 2141512_i64));
 
 test_type!(f64(MySql, "3.14159265e0" == 3.14159265_f64));
@@ -95,6 +170,16 @@ mod chrono {
         "DATE '2050-11-23'" == NaiveDate::from_ymd_opt(2050, 11, 23).unwrap()
     ));
 
+    // the epoch boundary, leap days and the extremes of the supported range
+    test_type!(chrono_date_boundaries<NaiveDate>(MySql,
+        "DATE '1970-01-01'" == NaiveDate::from_ymd_opt(1970, 1, 1).unwrap(),
+        "DATE '1969-12-31'" == NaiveDate::from_ymd_opt(1969, 12, 31).unwrap(),
+        "DATE '2000-02-29'" == NaiveDate::from_ymd_opt(2000, 2, 29).unwrap(),
+        "DATE '2024-02-29'" == NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+        "DATE '0001-01-01'" == NaiveDate::from_ymd_opt(1, 1, 1).unwrap(),
+        "DATE '9999-12-31'" == NaiveDate::from_ymd_opt(9999, 12, 31).unwrap()
+    ));
+
     test_type!(chrono_time_zero<NaiveTime>(MySql,
         "TIME '00:00:00.000000'" == NaiveTime::from_hms_micro_opt(0, 0, 0, 0).unwrap()
     ));
@@ -298,6 +383,62 @@ mod json_tests {
         MySql,
         "\'{\"json_column\":[1,2]}\'" == Json(Customer { json_column: Json(vec![1, 2]) })
     ));
+
+    #[sqlx_macros::test]
+    async fn test_json_column() -> anyhow::Result<()> {
+        let mut conn = new::<MySql>().await?;
+
+        conn.execute("CREATE TEMPORARY TABLE friends (id INT PRIMARY KEY, friend JSON NOT NULL);")
+            .await?;
+
+        let joe = Friend {
+            name: "Joe".to_string(),
+            age: 33,
+        };
+
+        sqlx::query("INSERT INTO friends (id, friend) VALUES (?, ?)")
+            .bind(1)
+            .bind(Json(&joe))
+            .execute(&mut conn)
+            .await?;
+
+        // BINARY
+        let friend: Json<Friend> = sqlx::query_scalar("SELECT friend FROM friends WHERE id = ?")
+            .bind(1)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(friend.0, joe);
+
+        // TEXT
+        let row = conn.fetch_one("SELECT friend FROM friends").await?;
+        let friend: Json<Friend> = row.try_get(0)?;
+
+        assert_eq!(friend.0, joe);
+
+        // the server parsed the bound string as a JSON document
+        let text: String = sqlx::query_scalar("SELECT CAST(friend AS CHAR) FROM friends")
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(
+            serde_json::from_str::<JsonValue>(&text)?,
+            json!({ "name": "Joe", "age": 33 })
+        );
+
+        // a mismatched shape surfaces the serde error
+        let err = sqlx::query_scalar::<_, Json<Vec<i64>>>("SELECT friend FROM friends")
+            .fetch_one(&mut conn)
+            .await
+            .unwrap_err();
+
+        assert!(
+            err.to_string().contains("invalid type: map"),
+            "unexpected error: {err}"
+        );
+
+        Ok(())
+    }
 }
 
 #[sqlx_macros::test]
@@ -1,6 +1,6 @@
 use futures::TryStreamExt;
 use sqlx::postgres::types::PgRange;
-use sqlx::{Connection, Executor, FromRow, Postgres};
+use sqlx::{Connection, Executor, FromRow, Postgres, Row};
 use sqlx_test::{new, test_type};
 use std::fmt::Debug;
 use std::ops::Bound;
@@ -361,6 +361,72 @@ SELECT id, mood FROM people WHERE id = $1
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn test_enum_array_type() -> anyhow::Result<()> {
+    #[derive(PartialEq, Debug, sqlx::Type)]
+    #[sqlx(type_name = "weather", rename_all = "lowercase", pg_array)]
+    enum Weather {
+        Sunny,
+        Cloudy,
+        Rainy,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+DROP TYPE IF EXISTS weather CASCADE;
+
+CREATE TYPE weather AS ENUM ( 'sunny', 'cloudy', 'rainy' );
+    "#,
+    )
+    .await?;
+
+    // Drop and re-acquire the connection
+    conn.close().await?;
+    let mut conn = new::<Postgres>().await?;
+
+    let forecast = vec![Weather::Sunny, Weather::Rainy, Weather::Cloudy];
+
+    let rec: (bool, Vec<Weather>) = sqlx::query_as(
+        "
+    SELECT $1 = '{sunny,rainy,cloudy}'::weather[], $1
+            ",
+    )
+    .bind(&forecast)
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert!(rec.0);
+    assert_eq!(rec.1, forecast);
+
+    let empty: Vec<Weather> = sqlx::query_scalar("SELECT '{}'::weather[]")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(empty.is_empty());
+
+    let with_nulls: Vec<Option<Weather>> =
+        sqlx::query_scalar("SELECT '{sunny,NULL,rainy}'::weather[]")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(
+        with_nulls,
+        [Some(Weather::Sunny), None, Some(Weather::Rainy)]
+    );
+
+    // the text protocol must agree
+    let row = conn.fetch_one("SELECT '{cloudy,NULL}'::weather[]").await?;
+
+    assert_eq!(
+        row.try_get::<Vec<Option<Weather>>, _>(0)?,
+        [Some(Weather::Cloudy), None]
+    );
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn test_record_type() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
@@ -74,3 +74,48 @@ async fn it_fails_with_check_violation() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_fails_with_retryable_timeout() -> anyhow::Result<()> {
+    let mut conn1 = new::<Postgres>().await?;
+    let mut conn2 = new::<Postgres>().await?;
+
+    let mut tx1 = conn1.begin().await?;
+    sqlx::query("SELECT pg_advisory_xact_lock(4242)")
+        .execute(&mut *tx1)
+        .await?;
+
+    let mut tx2 = conn2.begin().await?;
+    sqlx::query("SET LOCAL lock_timeout = 10")
+        .execute(&mut *tx2)
+        .await?;
+
+    let res: Result<_, sqlx::Error> = sqlx::query("SELECT pg_advisory_xact_lock(4242)")
+        .execute(&mut *tx2)
+        .await;
+    let err = res.unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::Timeout);
+    assert!(err.is_retryable());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fails_with_canceled_statement() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    let mut tx = conn.begin().await?;
+
+    sqlx::query("SET LOCAL statement_timeout = 10")
+        .execute(&mut *tx)
+        .await?;
+
+    let res: Result<_, sqlx::Error> = sqlx::query("SELECT pg_sleep(1)").execute(&mut *tx).await;
+    let err = res.unwrap_err();
+
+    // the statement may have been canceled on purpose, so it's not a retryable timeout
+    assert_eq!(err.kind(), ErrorKind::Other);
+    assert!(!err.is_retryable());
+
+    Ok(())
+}
@@ -1,9 +1,10 @@
-use sqlx::migrate::Migrator;
+use sqlx::migrate::{MigrateError, Migrator};
 use sqlx::pool::PoolConnection;
-use sqlx::postgres::{PgConnection, Postgres};
+use sqlx::postgres::{PgConnection, PgPool, Postgres};
 use sqlx::Executor;
 use sqlx::Row;
 use std::path::Path;
+use std::time::Duration;
 
 #[sqlx::test(migrations = false)]
 async fn simple(mut conn: PoolConnection<Postgres>) -> anyhow::Result<()> {
@@ -66,6 +67,63 @@ async fn reversible(mut conn: PoolConnection<Postgres>) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx::test(migrations = false)]
+async fn lock_options(pool: PgPool) -> anyhow::Result<()> {
+    clean_up(&mut *pool.acquire().await?).await?;
+
+    let mut migrator = Migrator::new(Path::new("tests/postgres/migrations_simple")).await?;
+    migrator.set_lock_key(0x5eed);
+    migrator.set_lock_timeout(Duration::from_millis(200));
+
+    // hold the lock from another session
+    let mut holder = pool.acquire().await?;
+    sqlx::query("SELECT pg_advisory_lock($1)")
+        .bind(0x5eed_i64)
+        .execute(&mut *holder)
+        .await?;
+
+    assert!(matches!(
+        migrator.run(&pool).await,
+        Err(MigrateError::LockTimeout)
+    ));
+
+    sqlx::query("SELECT pg_advisory_unlock($1)")
+        .bind(0x5eed_i64)
+        .execute(&mut *holder)
+        .await?;
+
+    migrator.run(&pool).await?;
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = false)]
+async fn no_transaction(mut conn: PoolConnection<Postgres>) -> anyhow::Result<()> {
+    clean_up(&mut conn).await?;
+    conn.execute("DROP TABLE IF EXISTS no_tx_test").await?;
+
+    // a string of several statements is run in an implicit transaction,
+    // so the statements have to be executed one at a time
+    let no_tx: &[(&str, &str)] = &[(
+        "1_index.sql",
+        "-- sqlx:no-transaction\n\
+         CREATE TABLE no_tx_test (id INT);\n\
+         CREATE INDEX CONCURRENTLY no_tx_test_id ON no_tx_test (id);",
+    )];
+    let migrator = Migrator::new(no_tx).await?;
+    assert!(migrator.iter().all(|m| m.no_tx()));
+
+    migrator.run(&mut conn).await?;
+
+    let indexes: i64 =
+        sqlx::query_scalar("SELECT count(*) FROM pg_indexes WHERE indexname = 'no_tx_test_id'")
+            .fetch_one(&mut *conn)
+            .await?;
+    assert_eq!(indexes, 1);
+
+    Ok(())
+}
+
 /// Ensure that we have a clean initial state.
 async fn clean_up(conn: &mut PgConnection) -> anyhow::Result<()> {
     conn.execute("DROP TABLE migrations_simple_test").await.ok();
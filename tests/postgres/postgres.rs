@@ -1,10 +1,13 @@
 use futures::{StreamExt, TryStreamExt};
 use sqlx::postgres::types::Oid;
 use sqlx::postgres::{
-    PgAdvisoryLock, PgConnectOptions, PgConnection, PgDatabaseError, PgErrorPosition, PgListener,
-    PgPoolOptions, PgRow, PgSeverity, Postgres,
+    PgAdvisoryLock, PgBindIterExt, PgConnectOptions, PgConnection, PgDatabaseError,
+    PgErrorPosition, PgListener, PgPoolOptions, PgRow, PgSeverity, Postgres,
+};
+use sqlx::{
+    Column, Connection, Executor, IsolationLevel, Row, Statement, TransactionOptions,
+    TransactionStatus, TypeInfo,
 };
-use sqlx::{Column, Connection, Executor, Row, Statement, TypeInfo};
 use sqlx_test::{new, pool, setup_if_needed};
 use std::env;
 use std::sync::Arc;
@@ -24,6 +27,78 @@ async fn it_connects() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_times_out_connecting() -> anyhow::Result<()> {
+    use sqlx::ConnectOptions;
+
+    // Accept the TCP connection but never respond to the startup message.
+    let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+    let port = listener.local_addr()?.port();
+
+    let res = PgConnectOptions::new()
+        .host("127.0.0.1")
+        .port(port)
+        .connect_timeout(Duration::from_millis(100))
+        .connect()
+        .await;
+
+    match res {
+        Err(sqlx::Error::Io(e)) => assert_eq!(e.kind(), std::io::ErrorKind::TimedOut),
+        other => panic!("expected a timeout, got {other:?}"),
+    }
+
+    drop(listener);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_reports_every_failed_host() -> anyhow::Result<()> {
+    // Nothing should be listening on either of these ports.
+    let res = PgConnection::connect("postgres://127.0.0.1:1,127.0.0.1:2/db").await;
+
+    match res {
+        Err(sqlx::Error::AllHostsFailed(errors)) => {
+            let hosts: Vec<_> = errors.iter().map(|(host, _)| host.as_str()).collect();
+            assert_eq!(hosts, ["127.0.0.1:1", "127.0.0.1:2"]);
+        }
+        other => panic!("expected every host to fail, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_uses_a_custom_resolver() -> anyhow::Result<()> {
+    use sqlx::ConnectOptions;
+
+    let res = PgConnectOptions::new()
+        .host("db.service.invalid")
+        .resolver(|host| {
+            let host = host.to_owned();
+            Box::pin(async move {
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    format!("custom resolver called for {host}"),
+                ))
+            })
+        })
+        .connect()
+        .await;
+
+    match res {
+        Err(sqlx::Error::Io(e)) => {
+            assert_eq!(
+                e.to_string(),
+                "custom resolver called for db.service.invalid"
+            )
+        }
+        other => panic!("expected the resolver's error, got {other:?}"),
+    }
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_select_void() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
@@ -361,6 +436,37 @@ async fn it_can_query_scalar() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_binds_arrays_for_any() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let ids: Vec<i32> = (1..=1000).collect();
+
+    let count: i64 = sqlx::query_scalar(
+        "SELECT count(*) FROM generate_series(1, 2000) AS id WHERE id = ANY($1)",
+    )
+    .bind(&ids[..])
+    .fetch_one(&mut conn)
+    .await?;
+    assert_eq!(count, 1000);
+
+    let count: i64 = sqlx::query_scalar(
+        "SELECT count(*) FROM generate_series(1, 2000) AS id WHERE id = ANY($1)",
+    )
+    .bind(ids.iter().filter(|id| *id % 2 == 0).copied().bind_iter())
+    .fetch_one(&mut conn)
+    .await?;
+    assert_eq!(count, 500);
+
+    let count: i32 = sqlx::query_scalar("SELECT cardinality($1::text[])")
+        .bind(std::iter::empty::<String>().bind_iter())
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(count, 0);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 /// This is separate from `it_can_query_scalar` because while implementing it I ran into a
 /// bug which that prevented `Vec<i32>` from compiling but allowed Vec<Option<i32>>.
@@ -497,6 +603,69 @@ async fn it_can_work_with_nested_transactions() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_reports_transaction_status() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let mut tx = conn.begin().await?;
+    assert!(tx.is_open());
+    assert_eq!(tx.status(), TransactionStatus::Active);
+
+    let mut savepoint = tx.begin().await?;
+    assert!(sqlx::query("SELECT 1 / 0")
+        .execute(&mut *savepoint)
+        .await
+        .is_err());
+    assert_eq!(savepoint.status(), TransactionStatus::Aborted);
+
+    // rolling back to the savepoint recovers the outer transaction
+    savepoint.rollback().await?;
+    assert_eq!(tx.status(), TransactionStatus::Active);
+
+    assert!(sqlx::query("SELECT 1 / 0").execute(&mut *tx).await.is_err());
+    assert_eq!(tx.status(), TransactionStatus::Aborted);
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_begins_with_transaction_options() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let mut tx = conn
+        .begin_with(TransactionOptions {
+            isolation: Some(IsolationLevel::Serializable),
+            read_only: true,
+            deferrable: false,
+        })
+        .await?;
+
+    let isolation: String = sqlx::query_scalar("SHOW transaction_isolation")
+        .fetch_one(&mut *tx)
+        .await?;
+    let read_only: String = sqlx::query_scalar("SHOW transaction_read_only")
+        .fetch_one(&mut *tx)
+        .await?;
+
+    assert_eq!(isolation, "serializable");
+    assert_eq!(read_only, "on");
+
+    // options can't be applied to a savepoint
+    assert!(tx
+        .begin_with(TransactionOptions {
+            read_only: true,
+            ..Default::default()
+        })
+        .await
+        .is_err());
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_drop_multiple_transactions() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
@@ -721,6 +890,40 @@ async fn it_caches_statements() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_reports_statement_cache_hits() -> anyhow::Result<()> {
+    use sqlx::ConnectOptions;
+    use std::sync::Mutex;
+
+    sqlx_test::setup_if_needed();
+
+    let cache_hits = Arc::new(Mutex::new(Vec::new()));
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse()?;
+    let options = options.on_query_executed({
+        let cache_hits = cache_hits.clone();
+        move |query| {
+            if query.sql == "SELECT $1::int4" {
+                cache_hits.lock().unwrap().push(query.cache_hit);
+            }
+        }
+    });
+
+    let mut conn = PgConnection::connect_with(&options).await?;
+
+    for persistent in [true, true, false] {
+        sqlx::query("SELECT $1::int4")
+            .bind(1_i32)
+            .persistent(persistent)
+            .fetch_one(&mut conn)
+            .await?;
+    }
+
+    // a statement which is not persistent is not looked up in the cache
+    assert_eq!(*cache_hits.lock().unwrap(), [Some(false), Some(true), None]);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_closes_statement_from_cache_issue_470() -> anyhow::Result<()> {
     sqlx_test::setup_if_needed();
@@ -748,6 +951,157 @@ async fn it_closes_statement_from_cache_issue_470() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_deallocates_uncached_statements() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let mut options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+    options = options.statement_cache_capacity(0);
+
+    let mut conn = PgConnection::connect_with(&options).await?;
+
+    for i in 0..5 {
+        let val: i32 = sqlx::query_scalar("SELECT $1::int4")
+            .bind(i)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(i, val);
+    }
+
+    let _ = conn.prepare("SELECT 1").await?;
+
+    assert_eq!(0, conn.cached_statements_size());
+
+    // a simple query, which is not prepared
+    let row = conn
+        .fetch_one("SELECT count(*) FROM pg_prepared_statements")
+        .await?;
+
+    assert_eq!(0, row.get::<i64, _>(0));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_calls_the_notice_handler() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let notices = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+    conn.set_notice_handler(Arc::new({
+        let notices = notices.clone();
+        move |notice: PgDatabaseError| {
+            notices.lock().unwrap().push((
+                notice.severity(),
+                notice.message().to_owned(),
+                notice.hint().map(str::to_owned),
+            ))
+        }
+    }));
+
+    conn.execute(
+        r#"
+DO $$
+BEGIN
+    RAISE NOTICE 'hello %', 1;
+    RAISE WARNING 'careful' USING HINT = 'look both ways';
+END
+$$;
+    "#,
+    )
+    .await?;
+
+    {
+        let notices = notices.lock().unwrap();
+
+        assert_eq!(notices.len(), 2);
+        assert!(matches!(notices[0].0, PgSeverity::Notice));
+        assert_eq!(notices[0].1, "hello 1");
+        assert!(matches!(notices[1].0, PgSeverity::Warning));
+        assert_eq!(notices[1].2.as_deref(), Some("look both ways"));
+    }
+
+    conn.clear_notice_handler();
+    conn.execute("DO $$ BEGIN RAISE NOTICE 'logged'; END $$")
+        .await?;
+
+    assert_eq!(notices.lock().unwrap().len(), 2);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_execute_simple_scripts() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let results: Vec<_> = conn
+        .execute_simple(
+            r#"
+CREATE TEMPORARY TABLE script_test (id INTEGER PRIMARY KEY);
+INSERT INTO script_test (id) VALUES (1), (2), (3);
+DELETE FROM script_test WHERE id > 1;
+            "#,
+        )
+        .try_collect()
+        .await?;
+
+    let rows_affected: Vec<u64> = results.iter().map(|r| r.rows_affected()).collect();
+    assert_eq!(rows_affected, [0, 3, 2]);
+
+    // the failing statement rolls back the implicit transaction of the script
+    let results: Vec<_> = conn
+        .execute_simple(
+            "INSERT INTO script_test (id) VALUES (4); INSERT INTO script_test (id) VALUES (1);",
+        )
+        .collect()
+        .await;
+
+    assert!(results.last().unwrap().is_err());
+
+    let count: i64 = sqlx::query_scalar("SELECT count(*) FROM script_test")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_request_text_results() -> anyhow::Result<()> {
+    use sqlx::postgres::PgValueFormat;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let row = sqlx::query("SELECT $1::int4 AS val, 'a'::text AS name")
+        .bind(5_i32)
+        .text_results(true)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.try_get_raw("val")?.format(), PgValueFormat::Text);
+    assert_eq!(row.try_get::<i32, _>("val")?, 5);
+    assert_eq!(row.try_get::<String, _>("name")?, "a");
+
+    // binary is still the default for the same (cached) statement
+    let row = sqlx::query("SELECT $1::int4 AS val, 'a'::text AS name")
+        .bind(5_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.try_get_raw("val")?.format(), PgValueFormat::Binary);
+
+    let val: i64 = sqlx::query_scalar("SELECT 42::int8")
+        .text_results(true)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(val, 42);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_sets_application_name() -> anyhow::Result<()> {
     sqlx_test::setup_if_needed();
@@ -992,6 +1346,84 @@ async fn test_listener_cleanup() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn test_listener_reconnects() -> anyhow::Result<()> {
+    use sqlx::postgres::{PgListener, PgListenerEvent};
+
+    let mut notify_conn = new::<Postgres>().await?;
+
+    let mut listener = PgListener::connect(&env::var("DATABASE_URL")?).await?;
+    listener.max_reconnect_retries(Some(3));
+    listener.listen("test_reconnect").await?;
+
+    let pid: i32 = sqlx::query_scalar("SELECT pg_backend_pid()")
+        .fetch_one(&mut listener)
+        .await?;
+
+    sqlx::query("SELECT pg_terminate_backend($1)")
+        .bind(pid)
+        .execute(&mut notify_conn)
+        .await?;
+
+    assert!(matches!(
+        listener.recv_event().await?,
+        PgListenerEvent::Reconnected
+    ));
+
+    // the channel is subscribed to again
+    notify_conn
+        .execute("NOTIFY test_reconnect, 'hello'")
+        .await?;
+
+    match listener.recv_event().await? {
+        PgListenerEvent::Notification(notification) => {
+            assert_eq!(notification.channel(), "test_reconnect");
+            assert_eq!(notification.payload(), "hello");
+        }
+        event => panic!("expected a notification, got {event:?}"),
+    }
+
+    // the reconnect is reported even if the connection was lost outside of `recv_event()`
+    let pid: i32 = sqlx::query_scalar("SELECT pg_backend_pid()")
+        .fetch_one(&mut listener)
+        .await?;
+
+    sqlx::query("SELECT pg_terminate_backend($1)")
+        .bind(pid)
+        .execute(&mut notify_conn)
+        .await?;
+
+    assert!(listener.try_recv().await?.is_none());
+
+    assert!(matches!(
+        listener.recv_event().await?,
+        PgListenerEvent::Reconnected
+    ));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_listener_connection() -> anyhow::Result<()> {
+    use sqlx::postgres::PgListener;
+
+    let mut listener = PgListener::connect(&env::var("DATABASE_URL")?).await?;
+    listener.listen("test_listener_connection").await?;
+
+    // a notification sent while a query runs on the listener's connection is buffered
+    let conn = listener.connection().await?;
+    conn.execute("NOTIFY test_listener_connection, 'first'")
+        .await?;
+    let value: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&mut *conn).await?;
+    assert_eq!(value, 1);
+
+    let notification = listener.recv().await?;
+    assert_eq!(notification.channel(), "test_listener_connection");
+    assert_eq!(notification.payload(), "first");
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn test_pg_listener_allows_pool_to_close() -> anyhow::Result<()> {
     let pool = pool::<Postgres>().await?;
@@ -1484,6 +1916,99 @@ async fn it_can_copy_in() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_can_copy_in_binary_rows() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    conn.execute(
+        r#"
+        CREATE TEMPORARY TABLE users (id INTEGER NOT NULL, name TEXT, tags TEXT[] NOT NULL);
+    "#,
+    )
+    .await?;
+
+    let mut copy = conn
+        .copy_in_raw("COPY users (id, name, tags) FROM STDIN WITH (FORMAT BINARY)")
+        .await?;
+
+    copy.write_row(&[&1_i32, &"alice", &vec!["admin"]]).await?;
+    copy.write_row(&[&2_i32, &None::<String>, &Vec::<String>::new()])
+        .await?;
+
+    let rows = copy.finish().await?;
+    assert_eq!(rows, 2);
+
+    let users: Vec<(i32, Option<String>, Vec<String>)> =
+        sqlx::query_as("SELECT id, name, tags FROM users ORDER BY id")
+            .fetch_all(&mut conn)
+            .await?;
+
+    assert_eq!(
+        users,
+        [
+            (1, Some("alice".to_owned()), vec!["admin".to_owned()]),
+            (2, None, vec![]),
+        ]
+    );
+
+    // an empty binary copy still has to send the header and trailer
+    let copy = conn
+        .copy_in_raw("COPY users (id, name, tags) FROM STDIN WITH (FORMAT BINARY)")
+        .await?;
+
+    assert_eq!(copy.finish().await?, 0);
+
+    // the row has to match the columns of the statement
+    let mut copy = conn
+        .copy_in_raw("COPY users (id, name) FROM STDIN WITH (FORMAT BINARY)")
+        .await?;
+
+    assert!(copy.write_row(&[&3_i32]).await.is_err());
+    copy.abort("this is only a test").await?;
+
+    // text formats can't take binary rows
+    let mut copy = conn
+        .copy_in_raw("COPY users (id) FROM STDIN WITH (FORMAT CSV)")
+        .await?;
+
+    assert!(copy.write_row(&[&3_i32]).await.is_err());
+    copy.abort("this is only a test").await?;
+
+    Ok(())
+}
+
+#[cfg(feature = "json")]
+#[sqlx_macros::test]
+async fn it_can_copy_in_binary_json() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    conn.execute(
+        r#"
+        CREATE TEMPORARY TABLE json_stuff (obj json, obj2 jsonb);
+    "#,
+    )
+    .await?;
+
+    let value = sqlx::types::Json(serde_json::json!({ "a": "a" }));
+
+    // `Json` is encoded for `jsonb`, so it has to be re-encoded for the `json` column
+    let mut copy = conn
+        .copy_in_raw("COPY json_stuff (obj, obj2) FROM STDIN WITH (FORMAT BINARY)")
+        .await?;
+
+    copy.write_row(&[&value, &value]).await?;
+    assert_eq!(copy.finish().await?, 1);
+
+    let (obj, obj2): (String, String) =
+        sqlx::query_as("SELECT obj::text, obj2::text FROM json_stuff")
+            .fetch_one(&mut conn)
+            .await?;
+
+    // `json` keeps its input as-is, including the space that replaced the JSONB version byte
+    assert_eq!(obj, r#" {"a":"a"}"#);
+    assert_eq!(obj2, r#"{"a": "a"}"#);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_abort_copy_in() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
@@ -1547,6 +2072,49 @@ async fn it_can_copy_out() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_can_copy_out_after_error() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    {
+        // division by zero on the third row
+        let mut copy = conn
+            .copy_out_raw("COPY (SELECT 1 / (3 - generate_series(1, 5))) TO STDOUT")
+            .await?;
+
+        let mut error = None;
+
+        while let Some(chunk) = copy.next().await {
+            if let Err(e) = chunk {
+                error = Some(e);
+                break;
+            }
+        }
+
+        let error = error.expect("expected COPY to fail");
+        assert_eq!(error.as_database_error().unwrap().code().unwrap(), "22012");
+    }
+
+    {
+        // stream dropped before reading to completion
+        let mut copy = conn
+            .copy_out_raw("COPY (SELECT generate_series(1, 1000)) TO STDOUT")
+            .await?;
+
+        assert_eq!(copy.next().await.unwrap()?, "1\n");
+    }
+
+    // conn is safe for reuse
+    let value = sqlx::query("select 1 + 1")
+        .try_map(|row: PgRow| row.try_get::<i32, _>(0))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(2i32, value);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_encodes_custom_array_issue_1504() -> anyhow::Result<()> {
     use sqlx::encode::IsNull;
@@ -1705,6 +2273,60 @@ CREATE TABLE issue_1254 (id INT4 PRIMARY KEY, pairs PAIR[]);
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_round_trips_arrays_of_composites() -> anyhow::Result<()> {
+    #[derive(sqlx::Type, Debug, PartialEq)]
+    #[sqlx(type_name = "labeled_point", pg_array)]
+    struct LabeledPoint {
+        x: f64,
+        y: f64,
+        label: Option<String>,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+    conn.execute(
+        "
+DROP TYPE IF EXISTS labeled_point;
+CREATE TYPE labeled_point AS (x FLOAT8, y FLOAT8, label TEXT);
+",
+    )
+    .await?;
+
+    let points = vec![
+        LabeledPoint {
+            x: 1.0,
+            y: 2.0,
+            label: Some("a".into()),
+        },
+        LabeledPoint {
+            x: 3.0,
+            y: 4.0,
+            label: None,
+        },
+    ];
+
+    let decoded: Vec<LabeledPoint> = sqlx::query_scalar("SELECT $1::labeled_point[]")
+        .bind(&points)
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(decoded, points);
+
+    // the struct has fewer fields than the type
+    #[derive(sqlx::Type, Debug)]
+    #[sqlx(type_name = "labeled_point")]
+    struct Point {
+        x: f64,
+        y: f64,
+    }
+
+    let res = sqlx::query_scalar::<_, Point>("SELECT ROW(1, 2, 'a')::labeled_point")
+        .fetch_one(&mut conn)
+        .await;
+    assert!(res.is_err());
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn test_advisory_locks() -> anyhow::Result<()> {
     let pool = PgPoolOptions::new()
@@ -3,7 +3,9 @@ extern crate time_ as time;
 use std::net::SocketAddr;
 use std::ops::Bound;
 
-use sqlx::postgres::types::{Oid, PgCiText, PgInterval, PgMoney, PgRange};
+use sqlx::postgres::types::{
+    Oid, PgBox, PgCiText, PgInterval, PgMoney, PgPoint, PgRange, PgRangeValue,
+};
 use sqlx::postgres::Postgres;
 use sqlx_test::{new, test_decode_type, test_prepared_type, test_type};
 
@@ -199,6 +201,30 @@ test_type!(ipnetwork<sqlx::types::ipnetwork::IpNetwork>(Postgres,
             .unwrap(),
 ));
 
+// geometric types have no `=` operator, but `~=` ("same as") compares them
+test_type!(point<PgPoint>(Postgres,
+    "SELECT ({0} ~= $1)::int4, {0}, $2",
+    "point(1.5, -2.25)" == PgPoint { x: 1.5, y: -2.25 },
+    "'(0,0)'::point" == PgPoint { x: 0.0, y: 0.0 },
+));
+
+test_decode_type!(point_vec<Vec<PgPoint>>(Postgres,
+    "array['(1,2)', '(3,4)']::point[]" == vec![PgPoint { x: 1.0, y: 2.0 }, PgPoint { x: 3.0, y: 4.0 }],
+));
+
+// Postgres puts the upper right corner first
+test_type!(pg_box<PgBox>(Postgres,
+    "SELECT ({0} ~= $1)::int4, {0}, $2",
+    "'((-1,2),(3,4.5))'::box"
+        == PgBox { upper_right_x: 3.0, upper_right_y: 4.5, lower_left_x: -1.0, lower_left_y: 2.0 },
+));
+
+test_type!(ipaddr<std::net::IpAddr>(Postgres,
+    "'127.0.0.1'::inet" == "127.0.0.1".parse::<std::net::IpAddr>().unwrap(),
+    "'::ffff:1.2.3.0/128'::inet" == "::ffff:1.2.3.0".parse::<std::net::IpAddr>().unwrap(),
+    "'10.0.0.1/32'::cidr" == "10.0.0.1".parse::<std::net::IpAddr>().unwrap(),
+));
+
 #[cfg(feature = "mac_address")]
 test_type!(mac_address<sqlx::types::mac_address::MacAddress>(Postgres,
     "'00:01:02:03:04:05'::macaddr"
@@ -264,6 +290,16 @@ mod chrono {
         "DATE '2050-11-23'" == NaiveDate::from_ymd(2050, 11, 23)
     ));
 
+    // the epoch boundary, leap days and the extremes of the supported range
+    test_type!(chrono_date_boundaries<NaiveDate>(Postgres,
+        "DATE '1970-01-01'" == NaiveDate::from_ymd(1970, 1, 1),
+        "DATE '1969-12-31'" == NaiveDate::from_ymd(1969, 12, 31),
+        "DATE '2000-02-29'" == NaiveDate::from_ymd(2000, 2, 29),
+        "DATE '2024-02-29'" == NaiveDate::from_ymd(2024, 2, 29),
+        "DATE '0001-01-01'" == NaiveDate::from_ymd(1, 1, 1),
+        "DATE '9999-12-31'" == NaiveDate::from_ymd(9999, 12, 31)
+    ));
+
     test_type!(chrono_time<NaiveTime>(Postgres,
         "TIME '05:10:20.115100'" == NaiveTime::from_hms_micro(5, 10, 20, 115100)
     ));
@@ -498,6 +534,36 @@ test_type!(numrange_decimal<PgRange<sqlx::types::Decimal>>(Postgres,
          Bound::Excluded(sqlx::types::Decimal::from_str("2.4").unwrap()))),
 ));
 
+#[cfg(feature = "rust_decimal")]
+test_type!(numeric_value_decimal<sqlx::postgres::types::PgNumericValue<sqlx::types::Decimal>>(Postgres,
+    "'NaN'::numeric" == sqlx::postgres::types::PgNumericValue::<sqlx::types::Decimal>::NotANumber,
+    "1.5::numeric" == sqlx::postgres::types::PgNumericValue::Number(sqlx::types::Decimal::from_str("1.5").unwrap()),
+));
+
+#[cfg(feature = "bigdecimal")]
+test_type!(numeric_value_bigdecimal<sqlx::postgres::types::PgNumericValue<sqlx::types::BigDecimal>>(Postgres,
+    "'NaN'::numeric" == sqlx::postgres::types::PgNumericValue::<sqlx::types::BigDecimal>::NotANumber,
+    "1.5::numeric" == sqlx::postgres::types::PgNumericValue::Number("1.5".parse::<sqlx::types::BigDecimal>().unwrap()),
+));
+
+#[cfg(feature = "rust_decimal")]
+#[sqlx_macros::test]
+async fn test_decimal_nan_error() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let err = sqlx::query_scalar::<_, sqlx::types::Decimal>("SELECT 'NaN'::numeric")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap_err();
+
+    assert!(
+        err.to_string().contains("numeric value is NaN"),
+        "unexpected error: {err}"
+    );
+
+    Ok(())
+}
+
 const EXC2: Bound<i32> = Bound::Excluded(2);
 const EXC3: Bound<i32> = Bound::Excluded(3);
 const INC1: Bound<i32> = Bound::Included(1);
@@ -522,6 +588,12 @@ test_type!(int4range<PgRange<i32>>(Postgres,
     "'[1,2]'::int4range" == PgRange::from((INC1, EXC3)),
 ));
 
+test_type!(int4range_value<PgRangeValue<i32>>(Postgres,
+    "'empty'::int4range" == PgRangeValue::<i32>::Empty,
+    "'(1,2)'::int4range" == PgRangeValue::<i32>::Empty,
+    "'[1,2)'::int4range" == PgRangeValue::from(PgRange::from((INC1, EXC2))),
+));
+
 test_prepared_type!(interval<PgInterval>(
     Postgres,
     "INTERVAL '1h'"
@@ -550,6 +622,15 @@ test_prepared_type!(interval<PgInterval>(
         },
 ));
 
+test_prepared_type!(interval_std<std::time::Duration>(
+    Postgres,
+    "INTERVAL '1h'" == std::time::Duration::from_secs(3_600),
+    "INTERVAL '2 days 03:10:20.116100'"
+        == std::time::Duration::from_micros(
+            ((2 * 24 + 3) * 3_600 + 10 * 60 + 20) * 1_000_000 + 116100
+        ),
+));
+
 test_prepared_type!(money<PgMoney>(Postgres, "123.45::money" == PgMoney(12345)));
 
 test_prepared_type!(money_vec<Vec<PgMoney>>(Postgres,
@@ -564,6 +645,16 @@ test_prepared_type!(citext_array<Vec<PgCiText>>(Postgres,
     ],
 ));
 
+test_type!(hstore<std::collections::HashMap<String, Option<String>>>(Postgres,
+    "'a=>1, b=>NULL, \"c d\"=>\"\"'::hstore" == [
+        ("a".to_owned(), Some("1".to_owned())),
+        ("b".to_owned(), None),
+        ("c d".to_owned(), Some(String::new())),
+    ]
+    .into_iter()
+    .collect::<std::collections::HashMap<_, _>>(),
+));
+
 // FIXME: needed to disable `ltree` tests in version that don't have a binary format for it
 // but `PgLTree` should just fall back to text format
 #[cfg(any(postgres_14, postgres_15))]
@@ -1,4 +1,4 @@
-use sqlx::Sqlite;
+use sqlx::{Connection, Sqlite, SqliteConnection};
 use sqlx_test::test_type;
 
 #[derive(Debug, PartialEq, sqlx::Type)]
@@ -12,3 +12,115 @@ test_type!(origin_enum<Origin>(Sqlite,
     "1" == Origin::Foo,
     "2" == Origin::Bar,
 ));
+
+#[derive(Debug, PartialEq, sqlx::FromRow)]
+#[sqlx(discriminant = "kind", rename_all = "snake_case")]
+enum Shape {
+    Circle {
+        radius: i64,
+    },
+    #[sqlx(rename = "rect")]
+    Rectangle {
+        width: i64,
+        #[sqlx(rename = "h")]
+        height: i64,
+    },
+    Point,
+}
+
+#[sqlx_macros::test]
+async fn test_from_row_enum_discriminant() -> anyhow::Result<()> {
+    let mut conn = SqliteConnection::connect(":memory:").await?;
+
+    let shapes: Vec<Shape> = sqlx::query_as(
+        "SELECT 'circle' AS kind, 2 AS radius, NULL AS width, NULL AS h \
+         UNION ALL SELECT 'rect', NULL, 3, 4 \
+         UNION ALL SELECT 'point', NULL, NULL, NULL",
+    )
+    .fetch_all(&mut conn)
+    .await?;
+
+    assert_eq!(
+        shapes,
+        [
+            Shape::Circle { radius: 2 },
+            Shape::Rectangle {
+                width: 3,
+                height: 4
+            },
+            Shape::Point,
+        ]
+    );
+
+    let err = sqlx::query_as::<_, Shape>("SELECT 'triangle' AS kind")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "error occurred while decoding column \"kind\": unknown discriminant value \"triangle\""
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_from_row_default_on_null() -> anyhow::Result<()> {
+    #[derive(Debug, PartialEq, sqlx::FromRow)]
+    struct Account {
+        id: i64,
+        #[sqlx(default_on_null)]
+        name: String,
+        #[sqlx(default, default_on_null)]
+        score: i64,
+    }
+
+    #[derive(Debug, PartialEq, sqlx::FromRow)]
+    #[sqlx(default_on_null)]
+    struct Settings {
+        theme: String,
+        font_size: i64,
+    }
+
+    let mut conn = SqliteConnection::connect(":memory:").await?;
+
+    // `name` and `score` are NULL.
+    let account: Account = sqlx::query_as("SELECT 1 AS id, NULL AS name, NULL AS score")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(
+        account,
+        Account {
+            id: 1,
+            name: String::new(),
+            score: 0
+        }
+    );
+
+    // `score` is missing, which is only allowed by `default`.
+    let account: Account = sqlx::query_as("SELECT 2 AS id, 'foo' AS name")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(account.name, "foo");
+    assert_eq!(account.score, 0);
+
+    // `name` is missing, which `default_on_null` alone does not allow.
+    let res = sqlx::query_as::<_, Account>("SELECT 3 AS id, 4 AS score")
+        .fetch_one(&mut conn)
+        .await;
+    assert!(matches!(res, Err(sqlx::Error::ColumnNotFound(_))));
+
+    let settings: Settings = sqlx::query_as("SELECT NULL AS theme, 12 AS font_size")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(
+        settings,
+        Settings {
+            theme: String::new(),
+            font_size: 12
+        }
+    );
+
+    Ok(())
+}
@@ -14,6 +14,8 @@ async fn it_fails_with_unique_violation() -> anyhow::Result<()> {
     let err = err.into_database_error().unwrap();
 
     assert_eq!(err.kind(), ErrorKind::UniqueViolation);
+    assert_eq!(err.table(), Some("tweet"));
+    assert_eq!(err.column(), Some("id"));
 
     Ok(())
 }
@@ -49,6 +51,7 @@ async fn it_fails_with_not_null_violation() -> anyhow::Result<()> {
     let err = err.into_database_error().unwrap();
 
     assert_eq!(err.kind(), ErrorKind::NotNullViolation);
+    assert_eq!(err.table(), Some("tweet"));
 
     Ok(())
 }
@@ -67,6 +70,39 @@ async fn it_fails_with_check_violation() -> anyhow::Result<()> {
     let err = err.into_database_error().unwrap();
 
     assert_eq!(err.kind(), ErrorKind::CheckViolation);
+    assert_eq!(err.constraint(), Some("price_greater_than_zero"));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fails_with_retryable_timeout() -> anyhow::Result<()> {
+    use sqlx::sqlite::SqliteConnectOptions;
+    use sqlx::ConnectOptions;
+    use std::time::Duration;
+
+    let dir = tempfile::tempdir()?;
+
+    let options = SqliteConnectOptions::new()
+        .filename(dir.path().join("busy.db"))
+        .create_if_missing(true)
+        .busy_timeout(Duration::ZERO);
+
+    let mut conn1 = options.connect().await?;
+    let mut conn2 = options.connect().await?;
+
+    conn1.execute("CREATE TABLE busy (id INTEGER)").await?;
+    conn1.execute("BEGIN IMMEDIATE").await?;
+
+    let err = conn2
+        .execute("INSERT INTO busy (id) VALUES (1)")
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::Timeout);
+    assert!(err.is_retryable());
+
+    conn1.execute("ROLLBACK").await?;
 
     Ok(())
 }
@@ -88,6 +88,73 @@ async fn macro_select_bind() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn macro_select_named_bind() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let account = sqlx::query!(
+        "select id, name, is_active from accounts where id = :id and (:id > 0 or :name = name)",
+        id = 1i32,
+        name = "Herp Derpinson",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(1, account.id);
+    assert_eq!("Herp Derpinson", account.name);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn macro_select_with_database_url_var() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let account = sqlx::query!(
+        db = "DATABASE_URL",
+        "select id, name from accounts where id = ?",
+        1i32
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(1, account.id);
+    assert_eq!("Herp Derpinson", account.name);
+
+    Ok(())
+}
+
+sqlx::query_struct!(
+    pub(crate) AccountRow,
+    "select id, name, is_active from accounts where id = ?"
+);
+
+#[sqlx_macros::test]
+async fn test_query_struct() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let account = sqlx::query_as::<_, AccountRow>(AccountRow::QUERY)
+        .bind(1i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(account.id, 1);
+    assert_eq!(account.name, "Herp Derpinson");
+    assert_eq!(account.is_active, Some(true));
+
+    let account = sqlx::query_as!(
+        AccountRow,
+        "select id, name, is_active from accounts where id = ?",
+        1i32
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(account.id, 1);
+
+    Ok(())
+}
+
 #[derive(Debug)]
 struct RawAccount {
     id: i64,
@@ -175,6 +242,28 @@ async fn test_query_scalar() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn test_query_scalar_fetch_optional_flatten() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let is_active = sqlx::query_scalar!("select is_active from accounts where id = 1")
+        .fetch_optional_flatten(&mut conn)
+        .await?;
+    assert_eq!(is_active, Some(true));
+
+    let is_active = sqlx::query_scalar!("select is_active from accounts where id = -1")
+        .fetch_optional_flatten(&mut conn)
+        .await?;
+    assert_eq!(is_active, None);
+
+    let is_active = sqlx::query_scalar!(r#"select null as "is_active: bool""#)
+        .fetch_optional_flatten(&mut conn)
+        .await?;
+    assert_eq!(is_active, None);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn macro_select_from_view() -> anyhow::Result<()> {
     let mut conn = new::<Sqlite>().await?;
@@ -1,6 +1,8 @@
-use sqlx::migrate::Migrator;
+use sqlx::migrate::{ChecksumPolicy, MigrateError, Migration, Migrator};
 use sqlx::pool::PoolConnection;
 use sqlx::sqlite::{Sqlite, SqliteConnection};
+#[cfg(feature = "time")]
+use sqlx::types::time::OffsetDateTime;
 use sqlx::Executor;
 use sqlx::Row;
 use std::path::Path;
@@ -66,6 +68,279 @@ async fn reversible(mut conn: PoolConnection<Sqlite>) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx::test(migrations = false)]
+async fn undo_stops_at_first_error(mut conn: PoolConnection<Sqlite>) -> anyhow::Result<()> {
+    clean_up(&mut conn).await?;
+
+    let files: &[(&str, &str)] = &[
+        (
+            "1_first.up.sql",
+            "CREATE TABLE migrations_undo_test (id INT);",
+        ),
+        ("1_first.down.sql", "DROP TABLE migrations_undo_test;"),
+        (
+            "2_second.up.sql",
+            "INSERT INTO migrations_undo_test VALUES (2);",
+        ),
+        ("2_second.down.sql", "DELETE FROM no_such_table;"),
+        (
+            "3_third.up.sql",
+            "INSERT INTO migrations_undo_test VALUES (3);",
+        ),
+        (
+            "3_third.down.sql",
+            "DELETE FROM migrations_undo_test WHERE id = 3;",
+        ),
+    ];
+
+    let migrator = Migrator::new(files).await?;
+    migrator.run(&mut conn).await?;
+
+    assert!(migrator.undo(&mut conn, 0).await.is_err());
+
+    // the third migration was reverted, the second failed and the first was never attempted
+    let ids: Vec<i32> = sqlx::query_scalar("SELECT id FROM migrations_undo_test")
+        .fetch_all(&mut *conn)
+        .await?;
+    assert_eq!(ids, [2]);
+
+    let versions: Vec<i64> =
+        sqlx::query_scalar("SELECT version FROM _sqlx_migrations ORDER BY version")
+            .fetch_all(&mut *conn)
+            .await?;
+    assert_eq!(versions, [1, 2]);
+
+    conn.execute("DROP TABLE migrations_undo_test").await?;
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = false)]
+async fn checksum_policy(mut conn: PoolConnection<Sqlite>) -> anyhow::Result<()> {
+    clean_up(&mut conn).await?;
+
+    let original: &[(&str, &str)] = &[(
+        "1_create.sql",
+        "CREATE TABLE migrations_checksum_test (id INT);",
+    )];
+    let edited: &[(&str, &str)] = &[(
+        "1_create.sql",
+        "-- a harmless comment\nCREATE TABLE migrations_checksum_test (id INT);",
+    )];
+
+    Migrator::new(original).await?.run(&mut conn).await?;
+
+    let stored_checksum = || {
+        sqlx::query_scalar::<_, Vec<u8>>("SELECT checksum FROM _sqlx_migrations WHERE version = 1")
+    };
+    let original_checksum = stored_checksum().fetch_one(&mut *conn).await?;
+
+    let mut migrator = Migrator::new(edited).await?;
+
+    // `Strict` is the default
+    assert!(matches!(
+        migrator.run(&mut conn).await,
+        Err(MigrateError::VersionMismatch(1))
+    ));
+
+    migrator.set_checksum_policy(ChecksumPolicy::WarnAndContinue);
+    migrator.run(&mut conn).await?;
+    assert_eq!(
+        stored_checksum().fetch_one(&mut *conn).await?,
+        original_checksum
+    );
+
+    // the migration is not run again, which would fail as the table exists
+    migrator.set_checksum_policy(ChecksumPolicy::Rehash);
+    migrator.run(&mut conn).await?;
+
+    let checksum = stored_checksum().fetch_one(&mut *conn).await?;
+    assert_eq!(checksum, &*migrator.iter().next().unwrap().checksum);
+
+    migrator.set_checksum_policy(ChecksumPolicy::Strict);
+    migrator.run(&mut conn).await?;
+
+    conn.execute("DROP TABLE migrations_checksum_test").await?;
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = false)]
+async fn out_of_order(mut conn: PoolConnection<Sqlite>) -> anyhow::Result<()> {
+    clean_up(&mut conn).await?;
+
+    let mut files = vec![
+        (
+            "1_create.sql",
+            "CREATE TABLE migrations_order_test (id INT);",
+        ),
+        (
+            "3_third.sql",
+            "INSERT INTO migrations_order_test VALUES (3);",
+        ),
+    ];
+
+    Migrator::new(&files[..]).await?.run(&mut conn).await?;
+
+    // a migration from another branch with an older version than the latest applied one
+    files.push((
+        "2_second.sql",
+        "INSERT INTO migrations_order_test VALUES (2);",
+    ));
+
+    Migrator::new(&files[..]).await?.run(&mut conn).await?;
+
+    let ids: Vec<i32> = sqlx::query_scalar("SELECT id FROM migrations_order_test ORDER BY rowid")
+        .fetch_all(&mut *conn)
+        .await?;
+    assert_eq!(ids, [3, 2]);
+
+    conn.execute("DROP TABLE migrations_order_test").await?;
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = false)]
+async fn pending(mut conn: PoolConnection<Sqlite>) -> anyhow::Result<()> {
+    clean_up(&mut conn).await?;
+
+    let migrator = Migrator::new(Path::new("tests/sqlite/migrations_reversible")).await?;
+
+    let versions =
+        |pending: Vec<&Migration>| -> Vec<i64> { pending.iter().map(|m| m.version).collect() };
+
+    // listing pending migrations doesn't create the migrations table
+    assert_eq!(
+        versions(migrator.pending(&mut conn).await?),
+        [20220721124650, 20220721125033]
+    );
+
+    let tables: i64 =
+        sqlx::query_scalar("SELECT count(*) FROM sqlite_master WHERE name = '_sqlx_migrations'")
+            .fetch_one(&mut *conn)
+            .await?;
+    assert_eq!(tables, 0);
+
+    migrator.run(&mut conn).await?;
+    assert!(migrator.pending(&mut conn).await?.is_empty());
+
+    migrator.undo(&mut conn, 20220721124650).await?;
+    assert_eq!(
+        versions(migrator.pending(&mut conn).await?),
+        [20220721125033]
+    );
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = false)]
+async fn code_migration(mut conn: PoolConnection<Sqlite>) -> anyhow::Result<()> {
+    clean_up(&mut conn).await?;
+
+    let files: &[(&str, &str)] = &[
+        (
+            "1_create.sql",
+            "CREATE TABLE migrations_code_test (name TEXT);",
+        ),
+        (
+            "3_insert.sql",
+            "INSERT INTO migrations_code_test VALUES ('third');",
+        ),
+    ];
+
+    let mut migrator = Migrator::new(files).await?;
+    migrator.add_migration(Migration::from_fn::<Sqlite, _>(
+        2,
+        "insert from code",
+        "v1",
+        |conn| {
+            Box::pin(async move {
+                for name in ["a", "b"] {
+                    sqlx::query("INSERT INTO migrations_code_test VALUES (?)")
+                        .bind(format!("second {name}"))
+                        .execute(&mut *conn)
+                        .await?;
+                }
+
+                Ok(())
+            })
+        },
+    ));
+
+    migrator.run(&mut conn).await?;
+
+    let names: Vec<String> =
+        sqlx::query_scalar("SELECT name FROM migrations_code_test ORDER BY rowid")
+            .fetch_all(&mut *conn)
+            .await?;
+    assert_eq!(names, ["second a", "second b", "third"]);
+
+    let recorded: (String, Vec<u8>) =
+        sqlx::query_as("SELECT description, checksum FROM _sqlx_migrations WHERE version = 2")
+            .fetch_one(&mut *conn)
+            .await?;
+    assert_eq!(recorded, ("insert from code".to_owned(), b"v1".to_vec()));
+
+    // already applied, so it is not run again
+    migrator.run(&mut conn).await?;
+
+    conn.execute("DROP TABLE migrations_code_test").await?;
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = false)]
+async fn applied(mut conn: PoolConnection<Sqlite>) -> anyhow::Result<()> {
+    clean_up(&mut conn).await?;
+
+    let migrator = Migrator::new(Path::new("tests/sqlite/migrations_simple")).await?;
+    assert!(migrator.applied(&mut conn).await?.is_empty());
+
+    #[cfg(feature = "time")]
+    let before = OffsetDateTime::now_utc() - std::time::Duration::from_secs(1);
+    migrator.run(&mut conn).await?;
+
+    let applied = migrator.applied(&mut conn).await?;
+
+    assert_eq!(applied.len(), 2);
+
+    for (applied, migration) in applied.iter().zip(migrator.iter()) {
+        assert_eq!(applied.version, migration.version);
+        assert_eq!(applied.description, migration.description);
+        assert_eq!(applied.checksum, migration.checksum);
+        assert!(applied.success);
+        assert!(applied.execution_time.is_some());
+
+        #[cfg(feature = "time")]
+        {
+            let installed_on = applied
+                .installed_on()
+                .expect("installed_on should be recorded");
+            assert!(installed_on >= before && installed_on <= OffsetDateTime::now_utc());
+        }
+    }
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = false)]
+async fn no_transaction(mut conn: PoolConnection<Sqlite>) -> anyhow::Result<()> {
+    clean_up(&mut conn).await?;
+
+    // `VACUUM` fails inside a transaction
+    let in_tx: &[(&str, &str)] = &[("1_vacuum.sql", "VACUUM;")];
+    assert!(Migrator::new(in_tx).await?.run(&mut conn).await.is_err());
+
+    let no_tx: &[(&str, &str)] = &[("1_vacuum.sql", "-- sqlx:no-transaction\nVACUUM;")];
+    let migrator = Migrator::new(no_tx).await?;
+    assert!(migrator.iter().all(|m| m.no_tx()));
+
+    migrator.run(&mut conn).await?;
+    assert_eq!(migrator.applied(&mut conn).await?.len(), 1);
+
+    Ok(())
+}
+
 /// Ensure that we have a clean initial state.
 async fn clean_up(conn: &mut SqliteConnection) -> anyhow::Result<()> {
     conn.execute("DROP TABLE migrations_simple_test").await.ok();
@@ -3,17 +3,303 @@ use rand::{Rng, SeedableRng};
 use rand_xoshiro::Xoshiro256PlusPlus;
 use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
 use sqlx::{
-    query, sqlite::Sqlite, sqlite::SqliteRow, Column, ConnectOptions, Connection, Executor, Row,
-    SqliteConnection, SqlitePool, Statement, TypeInfo,
+    query, sqlite::Sqlite, sqlite::SqliteRow, Column, ConnectOptions, Connection, Executor,
+    IsolationLevel, Row, SqliteConnection, SqlitePool, Statement, TransactionOptions,
+    TransactionRetry, TransactionStatus, TypeInfo,
 };
 use sqlx_test::new;
 use std::sync::Arc;
+use std::time::Duration;
 
 #[sqlx_macros::test]
 async fn it_connects() -> anyhow::Result<()> {
     Ok(new::<Sqlite>().await?.ping().await?)
 }
 
+#[sqlx_macros::test]
+async fn it_pings_with_a_timeout() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+    conn.ping_timeout(Duration::from_secs(5)).await?;
+
+    let pool: SqlitePool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .ping_timeout(Duration::from_secs(5))
+        .connect(":memory:")
+        .await?;
+
+    assert_eq!(
+        pool.options().get_ping_timeout(),
+        Some(Duration::from_secs(5))
+    );
+
+    // the idle connection is pinged before it's acquired again
+    pool.acquire().await?;
+    pool.acquire()
+        .await?
+        .ping_timeout(Duration::from_secs(5))
+        .await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_abandons_a_hanging_close_after_the_timeout() -> anyhow::Result<()> {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::Instant;
+
+    // `PRAGMA optimize` runs when the connection is closed, where the progress handler stalls it
+    let options: SqliteConnectOptions = "sqlite::memory:".parse()?;
+    let pool: SqlitePool = SqlitePoolOptions::new()
+        .max_connections(2)
+        .connect_with(options.optimize_on_close(true, None))
+        .await?;
+
+    let hang = Arc::new(AtomicBool::new(false));
+
+    // still checked out when the timeout elapses, so it is not counted as dropped
+    let checked_out = {
+        let mut conn = pool.acquire().await?;
+        let checked_out = pool.acquire().await?;
+        let hang = hang.clone();
+
+        conn.lock_handle().await?.set_progress_handler(1, move || {
+            if hang.swap(false, Ordering::SeqCst) {
+                std::thread::sleep(Duration::from_secs(2));
+            }
+
+            true
+        });
+
+        checked_out
+    };
+
+    // wait for the connection to be returned to the pool
+    while pool.num_idle() == 0 {
+        sqlx_core::rt::sleep(Duration::from_millis(10)).await;
+    }
+
+    hang.store(true, Ordering::SeqCst);
+
+    let start = Instant::now();
+    let dropped = pool.close_timeout(Duration::from_millis(100)).await;
+
+    assert!(
+        start.elapsed() < Duration::from_secs(1),
+        "{:?}",
+        start.elapsed()
+    );
+    assert_eq!(dropped, 1);
+    assert!(pool.is_closed());
+    assert_eq!(pool.size(), 1);
+
+    drop(checked_out);
+
+    while pool.size() > 0 {
+        sqlx_core::rt::sleep(Duration::from_millis(10)).await;
+    }
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_shares_a_named_in_memory_database() -> anyhow::Result<()> {
+    let pool: SqlitePool = SqlitePoolOptions::new()
+        .min_connections(1)
+        .max_connections(2)
+        .connect("sqlite:file:sqlx-shared-memory-test?mode=memory&cache=shared")
+        .await?;
+
+    let mut first = pool.acquire().await?;
+    first
+        .execute("CREATE TABLE shared (id INTEGER); INSERT INTO shared VALUES (1);")
+        .await?;
+
+    // a different connection sees the same database
+    let mut second = pool.acquire().await?;
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM shared")
+        .fetch_one(&mut *second)
+        .await?;
+    assert_eq!(count, 1);
+
+    // as does one opened outside of the pool
+    let mut conn =
+        SqliteConnection::connect("sqlite:file:sqlx-shared-memory-test?mode=memory&cache=shared")
+            .await?;
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM shared")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(count, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_call_user_defined_functions() -> anyhow::Result<()> {
+    use sqlx::Value;
+
+    let pool: SqlitePool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect_with(
+            "sqlite::memory:"
+                .parse::<SqliteConnectOptions>()?
+                .scalar_function("reverse", 1, |args| {
+                    let s: Option<String> = args[0].try_decode()?;
+                    Ok(s.map(|s| s.chars().rev().collect::<String>()))
+                })
+                .scalar_function("fail", 0, |_| Err::<i64, _>("boom".into()))
+                .aggregate_function(
+                    "product",
+                    1,
+                    || 1_i64,
+                    |product, args| {
+                        *product *= args[0].try_decode::<i64>()?;
+                        Ok(())
+                    },
+                    Ok,
+                ),
+        )
+        .await?;
+
+    let reversed: String = sqlx::query_scalar("SELECT reverse(?)")
+        .bind("sqlx")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(reversed, "xlqs");
+
+    let reversed: Option<String> = sqlx::query_scalar("SELECT reverse(NULL)")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(reversed, None);
+
+    let product: i64 = sqlx::query_scalar(
+        "SELECT product(n) FROM (SELECT 2 AS n UNION ALL SELECT 3 UNION ALL SELECT 7)",
+    )
+    .fetch_one(&pool)
+    .await?;
+    assert_eq!(product, 42);
+
+    let product: i64 = sqlx::query_scalar("SELECT product(n) FROM (SELECT 1 AS n) WHERE n > 1")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(product, 1);
+
+    let err = sqlx::query_scalar::<_, i64>("SELECT fail()")
+        .fetch_one(&pool)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("boom"), "{err}");
+
+    // functions are registered again on new connections
+    pool.acquire().await?.detach();
+    let reversed: String = sqlx::query_scalar("SELECT reverse('abc')")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(reversed, "cba");
+
+    // or directly on an open connection
+    let mut conn = new::<Sqlite>().await?;
+    conn.create_scalar_function("add_one", 1, |args| Ok(args[0].try_decode::<i64>()? + 1))
+        .await?;
+    conn.create_aggregate_function(
+        "product",
+        1,
+        || 1_i64,
+        |product, args| {
+            *product *= args[0].try_decode::<i64>()?;
+            Ok(())
+        },
+        Ok,
+    )
+    .await?;
+
+    let two: i64 = sqlx::query_scalar("SELECT add_one(1)")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(two, 2);
+
+    let product: i64 =
+        sqlx::query_scalar("SELECT product(value) FROM (SELECT 2 AS value UNION ALL SELECT 3)")
+            .fetch_one(&mut conn)
+            .await?;
+    assert_eq!(product, 6);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_backup_a_database() -> anyhow::Result<()> {
+    let mut source = SqliteConnection::connect("sqlite::memory:").await?;
+
+    source
+        .execute(
+            "CREATE TABLE numbers (n INTEGER, padding BLOB); \
+             WITH RECURSIVE seq(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM seq WHERE n < 1000) \
+             INSERT INTO numbers SELECT n, zeroblob(512) FROM seq;",
+        )
+        .await?;
+
+    let mut dest = SqliteConnection::connect("sqlite::memory:").await?;
+    let mut steps = Vec::new();
+
+    source
+        .backup_to_with_progress(&mut dest, |remaining, total| steps.push((remaining, total)))
+        .await?;
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM numbers")
+        .fetch_one(&mut dest)
+        .await?;
+    assert_eq!(count, 1000);
+
+    assert!(steps.len() > 1);
+    assert_eq!(steps.last().unwrap().0, 0);
+
+    let path = std::env::temp_dir().join(format!("sqlx-backup-{}.db", std::process::id()));
+    source.backup_to_file(&path).await?;
+
+    let mut file = SqliteConnection::connect(&format!("sqlite://{}", path.display())).await?;
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM numbers")
+        .fetch_one(&mut file)
+        .await?;
+    assert_eq!(count, 1000);
+
+    file.close().await?;
+    std::fs::remove_file(&path)?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_applies_pragmas_on_connect() -> anyhow::Result<()> {
+    let mut conn = "sqlite::memory:"
+        .parse::<SqliteConnectOptions>()?
+        .page_size(8192)
+        .cache_size(-4096)
+        .foreign_keys(false)
+        .pragma("temp_store", "MEMORY")
+        .connect()
+        .await?;
+
+    let page_size: i64 = sqlx::query_scalar("PRAGMA page_size")
+        .fetch_one(&mut conn)
+        .await?;
+    let cache_size: i64 = sqlx::query_scalar("PRAGMA cache_size")
+        .fetch_one(&mut conn)
+        .await?;
+    let foreign_keys: i64 = sqlx::query_scalar("PRAGMA foreign_keys")
+        .fetch_one(&mut conn)
+        .await?;
+    let temp_store: i64 = sqlx::query_scalar("PRAGMA temp_store")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(page_size, 8192);
+    assert_eq!(cache_size, -4096);
+    assert_eq!(foreign_keys, 0);
+    assert_eq!(temp_store, 2);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_fetches_and_inflates_row() -> anyhow::Result<()> {
     let mut conn = new::<Sqlite>().await?;
@@ -220,6 +506,23 @@ async fn it_opens_with_extension() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_fails_to_open_with_a_missing_extension() -> anyhow::Result<()> {
+    let opts = SqliteConnectOptions::new()
+        .filename(":memory:")
+        .extension("sqlx_missing_extension");
+
+    let err = SqliteConnection::connect_with(&opts).await.unwrap_err();
+
+    assert!(
+        err.to_string()
+            .contains("error loading extension \"sqlx_missing_extension\""),
+        "{err}"
+    );
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_opens_in_memory() -> anyhow::Result<()> {
     // If the filename is ":memory:", then a private, temporary in-memory database
@@ -240,6 +543,30 @@ async fn it_opens_temp_on_disk() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_executes_after_connect_sql() -> anyhow::Result<()> {
+    use std::str::FromStr;
+
+    let mut conn = SqliteConnectOptions::from_str(":memory:")?
+        .after_connect_sql("CREATE TEMPORARY TABLE startup (id INTEGER)")
+        .after_connect_sql("INSERT INTO startup (id) VALUES (42)")
+        .connect()
+        .await?;
+
+    let id: i64 = sqlx::query_scalar("SELECT id FROM startup")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(id, 42);
+
+    let res = SqliteConnectOptions::from_str(":memory:")?
+        .after_connect_sql("SELECT * FROM not_a_table")
+        .connect()
+        .await;
+    assert!(res.is_err());
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_fails_to_parse() -> anyhow::Result<()> {
     let mut conn = new::<Sqlite>().await?;
@@ -257,6 +584,58 @@ async fn it_fails_to_parse() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_executes_all_statements_in_a_script() -> anyhow::Result<()> {
+    let mut conn = SqliteConnection::connect(":memory:").await?;
+
+    let results: Vec<_> = sqlx::raw_sql(
+        "CREATE TABLE script (text TEXT NOT NULL);
+         -- a comment; with a semicolon
+         INSERT INTO script (text) VALUES ('a;b'), ('c');
+         DELETE FROM script WHERE text = 'c';",
+    )
+    .execute_all(&mut conn)
+    .try_collect()
+    .await?;
+
+    let rows_affected: Vec<_> = results.iter().map(|r| r.rows_affected()).collect();
+    assert_eq!(rows_affected, [0, 2, 1]);
+
+    let text: String = sqlx::query_scalar("SELECT text FROM script")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(text, "a;b");
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_executes_a_script_with_a_trigger() -> anyhow::Result<()> {
+    let mut conn = SqliteConnection::connect(":memory:").await?;
+
+    let results: Vec<_> = sqlx::raw_sql(
+        r"CREATE TABLE script (text TEXT NOT NULL);
+         CREATE TABLE script_log (text TEXT NOT NULL);
+         CREATE TRIGGER script_insert AFTER INSERT ON script BEGIN
+             INSERT INTO script_log (text) VALUES (new.text);
+             INSERT INTO script_log (text) VALUES ('\');
+         END;
+         INSERT INTO script (text) VALUES ('a');",
+    )
+    .execute_all(&mut conn)
+    .try_collect()
+    .await?;
+
+    assert_eq!(results.len(), 4);
+
+    let log: Vec<String> = sqlx::query_scalar("SELECT text FROM script_log ORDER BY rowid")
+        .fetch_all(&mut conn)
+        .await?;
+    assert_eq!(log, ["a", "\\"]);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_handles_empty_queries() -> anyhow::Result<()> {
     let mut conn = new::<Sqlite>().await?;
@@ -794,3 +1173,266 @@ async fn test_multiple_set_progress_handler_calls_drop_old_handler() -> anyhow::
     assert_eq!(1, Arc::strong_count(&ref_counted_object));
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn test_query_with_update_hook() -> anyhow::Result<()> {
+    use sqlx::sqlite::SqliteOperation;
+
+    let mut conn = new::<Sqlite>().await?;
+    sqlx::raw_sql("CREATE TEMPORARY TABLE hooked (id INTEGER PRIMARY KEY, name TEXT);")
+        .execute(&mut conn)
+        .await?;
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    conn.lock_handle().await?.set_update_hook(move |result| {
+        tx.send((
+            result.operation,
+            result.database.to_owned(),
+            result.table.to_owned(),
+            result.rowid,
+        ))
+        .unwrap();
+    });
+
+    sqlx::raw_sql(
+        "INSERT INTO hooked (id, name) VALUES (1, 'a'), (2, 'b'); \
+         UPDATE hooked SET name = 'c' WHERE id = 2; \
+         DELETE FROM hooked WHERE id = 1;",
+    )
+    .execute(&mut conn)
+    .await?;
+
+    let events: Vec<_> = rx.try_iter().collect();
+    assert_eq!(
+        events,
+        [
+            (SqliteOperation::Insert, "temp".into(), "hooked".into(), 1),
+            (SqliteOperation::Insert, "temp".into(), "hooked".into(), 2),
+            (SqliteOperation::Update, "temp".into(), "hooked".into(), 2),
+            (SqliteOperation::Delete, "temp".into(), "hooked".into(), 1),
+        ]
+    );
+
+    conn.lock_handle().await?.remove_update_hook();
+
+    sqlx::query("INSERT INTO hooked (id, name) VALUES (3, 'd')")
+        .execute(&mut conn)
+        .await?;
+
+    // the sender was dropped with the hook
+    assert!(matches!(
+        rx.try_recv(),
+        Err(std::sync::mpsc::TryRecvError::Disconnected)
+    ));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_work_with_nested_transactions() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+    sqlx::raw_sql("CREATE TEMPORARY TABLE nested_users (id INTEGER PRIMARY KEY);")
+        .execute(&mut conn)
+        .await?;
+
+    async fn count(conn: &mut SqliteConnection) -> sqlx::Result<i64> {
+        sqlx::query_scalar("SELECT COUNT(*) FROM nested_users")
+            .fetch_one(conn)
+            .await
+    }
+
+    let mut tx = conn.begin().await?; // transaction
+    sqlx::query("INSERT INTO nested_users (id) VALUES (1)")
+        .execute(&mut *tx)
+        .await?;
+
+    // rolling back a savepoint keeps the outer transaction
+    let mut savepoint = tx.begin().await?;
+    sqlx::query("INSERT INTO nested_users (id) VALUES (2)")
+        .execute(&mut *savepoint)
+        .await?;
+    assert_eq!(count(&mut *savepoint).await?, 2);
+    savepoint.rollback().await?;
+    assert_eq!(count(&mut *tx).await?, 1);
+
+    // committed savepoints are kept, also when nested
+    let mut savepoint = tx.begin().await?;
+    sqlx::query("INSERT INTO nested_users (id) VALUES (3)")
+        .execute(&mut *savepoint)
+        .await?;
+
+    {
+        let mut inner = savepoint.begin().await?;
+        sqlx::query("INSERT INTO nested_users (id) VALUES (4)")
+            .execute(&mut *inner)
+            .await?;
+        // dropped, so rolled back
+    }
+
+    savepoint.commit().await?;
+    assert_eq!(count(&mut *tx).await?, 2);
+
+    tx.commit().await?;
+    assert_eq!(count(&mut conn).await?, 2);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_reports_transaction_status() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+    sqlx::raw_sql(
+        "CREATE TEMPORARY TABLE status_users (id INTEGER PRIMARY KEY);
+         CREATE TEMPORARY TRIGGER status_users_reject BEFORE INSERT ON status_users
+         WHEN NEW.id < 0 BEGIN SELECT RAISE(ROLLBACK, 'negative id'); END;",
+    )
+    .execute(&mut conn)
+    .await?;
+
+    let mut tx = conn.begin().await?;
+    assert!(tx.is_open());
+    assert_eq!(tx.status(), TransactionStatus::Active);
+
+    // a failed statement alone doesn't end the transaction
+    sqlx::query("INSERT INTO status_users (id) VALUES (1)")
+        .execute(&mut *tx)
+        .await?;
+    assert!(sqlx::query("INSERT INTO status_users (id) VALUES (1)")
+        .execute(&mut *tx)
+        .await
+        .is_err());
+    assert_eq!(tx.status(), TransactionStatus::Active);
+
+    // but `RAISE(ROLLBACK)` does
+    assert!(sqlx::query("INSERT INTO status_users (id) VALUES (-1)")
+        .execute(&mut *tx)
+        .await
+        .is_err());
+    assert_eq!(tx.status(), TransactionStatus::Aborted);
+    drop(tx);
+
+    // a new transaction starts out active again
+    let tx = conn.begin().await?;
+    assert_eq!(tx.status(), TransactionStatus::Active);
+    tx.commit().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_begins_with_transaction_options() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let tx = conn
+        .begin_with(TransactionOptions {
+            isolation: Some(IsolationLevel::Serializable),
+            ..Default::default()
+        })
+        .await?;
+    tx.rollback().await?;
+
+    for options in [
+        TransactionOptions {
+            isolation: Some(IsolationLevel::ReadCommitted),
+            ..Default::default()
+        },
+        TransactionOptions {
+            read_only: true,
+            ..Default::default()
+        },
+    ] {
+        assert!(conn.begin_with(options).await.is_err());
+    }
+
+    // nothing was sent to the database
+    let tx = conn.begin().await?;
+    tx.commit().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_retries_transactions() -> anyhow::Result<()> {
+    use futures::future::BoxFuture;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let pool: SqlitePool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await?;
+
+    sqlx::raw_sql(
+        "CREATE TABLE retry_users (id INTEGER PRIMARY KEY);
+         INSERT INTO retry_users (id) VALUES (1);",
+    )
+    .execute(&pool)
+    .await?;
+
+    // fails with a unique violation on the first attempt only
+    fn insert<'c>(
+        tx: &'c mut sqlx::Transaction<'_, Sqlite>,
+        attempts: &AtomicUsize,
+    ) -> BoxFuture<'c, sqlx::Result<usize>> {
+        let attempt = attempts.fetch_add(1, Ordering::SeqCst) + 1;
+
+        Box::pin(async move {
+            sqlx::query("INSERT INTO retry_users (id) VALUES (?)")
+                .bind(attempt as i64)
+                .execute(&mut **tx)
+                .await?;
+
+            Ok(attempt)
+        })
+    }
+
+    let attempts = AtomicUsize::new(0);
+
+    // not retryable by default
+    let res = pool.transaction_retry(3, |tx| insert(tx, &attempts)).await;
+    assert!(res.is_err());
+    assert_eq!(attempts.swap(0, Ordering::SeqCst), 1);
+
+    // SQLITE_CONSTRAINT_PRIMARYKEY
+    let retry = TransactionRetry::new(3)
+        .retryable_codes(["1555"])
+        .backoff(Duration::from_millis(1), Duration::from_millis(1));
+    assert_eq!(
+        pool.transaction_retry_with(retry, |tx| insert(tx, &attempts))
+            .await?,
+        2
+    );
+
+    let ids: Vec<i64> = sqlx::query_scalar("SELECT id FROM retry_users ORDER BY id")
+        .fetch_all(&pool)
+        .await?;
+    assert_eq!(ids, [1, 2]);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_tracks_writes_in_transactions() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+    sqlx::raw_sql("CREATE TEMPORARY TABLE dirty_users (id INTEGER PRIMARY KEY);")
+        .execute(&mut conn)
+        .await?;
+
+    let mut tx = conn.begin().await?;
+    sqlx::query("SELECT COUNT(*) FROM dirty_users")
+        .fetch_one(&mut *tx)
+        .await?;
+    assert!(!tx.was_dirty());
+
+    let mut savepoint = tx.begin().await?;
+    sqlx::query("INSERT INTO dirty_users (id) VALUES (1)")
+        .execute(&mut *savepoint)
+        .await?;
+    assert!(savepoint.was_dirty());
+    savepoint.rollback().await?;
+
+    // conservative: the rolled back write still counts
+    assert!(tx.was_dirty());
+    tx.commit().await?;
+
+    Ok(())
+}
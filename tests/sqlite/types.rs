@@ -98,6 +98,25 @@ mod json_tests {
 mod chrono {
     use super::*;
     use sqlx::types::chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc};
+    use sqlx_test::test_decode_type;
+
+    // the epoch boundary, leap days and the extremes of the supported range
+    test_type!(chrono_date_boundaries<NaiveDate>(Sqlite,
+        "'1970-01-01'" == NaiveDate::from_ymd(1970, 1, 1),
+        "'1969-12-31'" == NaiveDate::from_ymd(1969, 12, 31),
+        "'2000-02-29'" == NaiveDate::from_ymd(2000, 2, 29),
+        "'2024-02-29'" == NaiveDate::from_ymd(2024, 2, 29),
+        "'0001-01-01'" == NaiveDate::from_ymd(1, 1, 1),
+        "'9999-12-31'" == NaiveDate::from_ymd(9999, 12, 31)
+    ));
+
+    test_decode_type!(chrono_date_julian_day<NaiveDate>(Sqlite,
+        "julianday('1970-01-01')" == NaiveDate::from_ymd(1970, 1, 1),
+        "julianday('1969-12-31 23:59:59')" == NaiveDate::from_ymd(1969, 12, 31),
+        "julianday('2000-02-29 12:00:00')" == NaiveDate::from_ymd(2000, 2, 29),
+        "julianday('0001-01-01')" == NaiveDate::from_ymd(1, 1, 1),
+        "julianday('9999-12-31 18:00:00')" == NaiveDate::from_ymd(9999, 12, 31)
+    ));
 
     test_type!(chrono_naive_date_time<NaiveDateTime>(Sqlite, "SELECT datetime({0}) is datetime(?), {0}, ?",
         "'2019-01-02 05:10:20'" == NaiveDate::from_ymd(2019, 1, 2).and_hms(5, 10, 20)
@@ -192,6 +211,46 @@ test_type!(uuid<sqlx::types::Uuid>(Sqlite,
         == sqlx::types::Uuid::parse_str("00000000-0000-0000-0000-000000000000").unwrap()
 ));
 
+#[cfg(feature = "uuid")]
+#[sqlx_macros::test]
+async fn test_uuid_from_text_storage() -> anyhow::Result<()> {
+    use sqlx::types::Uuid;
+
+    let mut conn = new::<Sqlite>().await?;
+
+    let expected = Uuid::parse_str("b731678f-636f-4135-bc6f-19440c13bd19")?;
+
+    let (hyphenated, simple): (Uuid, Uuid) = sqlx::query_as(
+        "SELECT 'b731678f-636f-4135-bc6f-19440c13bd19', 'b731678f636f4135bc6f19440c13bd19'",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(hyphenated, expected);
+    assert_eq!(simple, expected);
+
+    // binding a `Uuid` stores it as a 16-byte BLOB
+    let (ty, len): (String, i64) = sqlx::query_as("SELECT typeof($1), length($1)")
+        .bind(expected)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!((&*ty, len), ("blob", 16));
+
+    let err = sqlx::query_scalar::<_, Uuid>("SELECT x'b731678f636f4135'")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap_err();
+
+    assert!(
+        err.to_string()
+            .contains("expected a 16-byte BLOB for a UUID, got 8 bytes"),
+        "unexpected error: {err}"
+    );
+
+    Ok(())
+}
+
 #[cfg(feature = "uuid")]
 test_type!(uuid_hyphenated<sqlx::types::uuid::fmt::Hyphenated>(Sqlite,
     "'b731678f-636f-4135-bc6f-19440c13bd19'"
@@ -208,6 +267,47 @@ test_type!(uuid_simple<sqlx::types::uuid::fmt::Simple>(Sqlite,
         == sqlx::types::Uuid::parse_str("00000000000000000000000000000000").unwrap().simple()
 ));
 
+#[cfg(feature = "rust_decimal")]
+test_type!(decimal<sqlx::types::Decimal>(Sqlite,
+    "'12345.6789'" == "12345.6789".parse::<sqlx::types::Decimal>().unwrap(),
+    "'-0.01'" == "-0.01".parse::<sqlx::types::Decimal>().unwrap(),
+    "'1.00'" == "1.00".parse::<sqlx::types::Decimal>().unwrap(),
+));
+
+#[cfg(feature = "bigdecimal")]
+test_type!(bigdecimal<sqlx::types::BigDecimal>(Sqlite,
+    "'12345.6789'" == "12345.6789".parse::<sqlx::types::BigDecimal>().unwrap(),
+    "'123456789012345678901234567890.123456789'"
+        == "123456789012345678901234567890.123456789".parse::<sqlx::types::BigDecimal>().unwrap(),
+));
+
+#[cfg(feature = "rust_decimal")]
+#[sqlx_macros::test]
+async fn test_decimal_from_numeric_storage() -> anyhow::Result<()> {
+    use sqlx::types::Decimal;
+
+    let mut conn = new::<Sqlite>().await?;
+
+    let (int, real): (Decimal, Decimal) = sqlx::query_as("SELECT 42, 0.1 + 0.25")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(int, Decimal::from(42));
+    assert_eq!(real, "0.35".parse::<Decimal>()?);
+
+    let err = sqlx::query_scalar::<_, Decimal>("SELECT 'twelve'")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap_err();
+
+    assert!(
+        err.to_string().contains("invalid decimal value \"twelve\""),
+        "unexpected error: {err}"
+    );
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn test_text_adapter() -> anyhow::Result<()> {
     #[derive(sqlx::FromRow, Debug, PartialEq, Eq)]